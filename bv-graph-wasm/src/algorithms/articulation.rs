@@ -7,8 +7,9 @@
 //! points - if those issues are blocked or deprioritized, they can
 //! disconnect groups of related work.
 
+use crate::csr::CsrGraph;
 use crate::graph::DiGraph;
-use std::collections::HashSet;
+use serde::Serialize;
 
 /// Find articulation points (cut vertices) using Tarjan's algorithm.
 ///
@@ -32,8 +33,8 @@ pub fn articulation_points(graph: &DiGraph) -> Vec<usize> {
         return Vec::new();
     }
 
-    // Build undirected adjacency
-    let neighbors = build_undirected_neighbors(graph);
+    // Undirected adjacency from the shared CSR view
+    let neighbors = graph.csr();
 
     // Tarjan's algorithm state
     let mut disc = vec![0usize; n];
@@ -48,7 +49,7 @@ pub fn articulation_points(graph: &DiGraph) -> Vec<usize> {
         if !visited[start] {
             tarjan_dfs(
                 start,
-                &neighbors,
+                neighbors,
                 &mut disc,
                 &mut low,
                 &mut parent,
@@ -68,9 +69,10 @@ pub fn articulation_points(graph: &DiGraph) -> Vec<usize> {
 }
 
 /// DFS for Tarjan's articulation point algorithm.
+#[allow(clippy::too_many_arguments)]
 fn tarjan_dfs(
     v: usize,
-    neighbors: &[Vec<usize>],
+    neighbors: &CsrGraph,
     disc: &mut [usize],
     low: &mut [usize],
     parent: &mut [usize],
@@ -84,7 +86,8 @@ fn tarjan_dfs(
     low[v] = *time;
     let mut children = 0;
 
-    for &u in &neighbors[v] {
+    for &u in neighbors.undirected_neighbors(v) {
+        let u = u as usize;
         if !visited[u] {
             children += 1;
             parent[u] = v;
@@ -112,25 +115,6 @@ fn tarjan_dfs(
     }
 }
 
-/// Build undirected neighbor lists from directed graph.
-fn build_undirected_neighbors(graph: &DiGraph) -> Vec<Vec<usize>> {
-    let n = graph.len();
-    let mut neighbors: Vec<HashSet<usize>> = vec![HashSet::new(); n];
-
-    for u in 0..n {
-        for &v in graph.successors_slice(u) {
-            if u != v {
-                // Skip self-loops
-                neighbors[u].insert(v);
-                neighbors[v].insert(u);
-            }
-        }
-    }
-
-    // Convert to Vec<Vec> for faster iteration
-    neighbors.into_iter().map(|s| s.into_iter().collect()).collect()
-}
-
 /// Count bridges (cut edges) in the graph.
 /// A bridge is an edge whose removal disconnects the graph.
 pub fn bridges(graph: &DiGraph) -> Vec<(usize, usize)> {
@@ -139,7 +123,7 @@ pub fn bridges(graph: &DiGraph) -> Vec<(usize, usize)> {
         return Vec::new();
     }
 
-    let neighbors = build_undirected_neighbors(graph);
+    let neighbors = graph.csr();
 
     let mut disc = vec![0usize; n];
     let mut low = vec![0usize; n];
@@ -152,7 +136,7 @@ pub fn bridges(graph: &DiGraph) -> Vec<(usize, usize)> {
         if !visited[start] {
             bridge_dfs(
                 start,
-                &neighbors,
+                neighbors,
                 &mut disc,
                 &mut low,
                 &mut parent,
@@ -167,9 +151,10 @@ pub fn bridges(graph: &DiGraph) -> Vec<(usize, usize)> {
 }
 
 /// DFS for bridge detection.
+#[allow(clippy::too_many_arguments)]
 fn bridge_dfs(
     v: usize,
-    neighbors: &[Vec<usize>],
+    neighbors: &CsrGraph,
     disc: &mut [usize],
     low: &mut [usize],
     parent: &mut [usize],
@@ -182,7 +167,8 @@ fn bridge_dfs(
     disc[v] = *time;
     low[v] = *time;
 
-    for &u in &neighbors[v] {
+    for &u in neighbors.undirected_neighbors(v) {
+        let u = u as usize;
         if !visited[u] {
             parent[u] = v;
             bridge_dfs(u, neighbors, disc, low, parent, visited, bridges, time);
@@ -199,6 +185,188 @@ fn bridge_dfs(
     }
 }
 
+/// What removing one articulation point does to its connected component.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CutVertexImpact {
+    pub node: usize,
+    pub node_id: Option<String>,
+    /// Sizes of the pieces left behind, largest first
+    pub parts: Vec<usize>,
+    /// Nodes cut off from the largest piece
+    pub disconnected: usize,
+}
+
+/// Biconnected components and the block-cut tree.
+///
+/// The tree has one vertex per block and one per articulation point, with an
+/// edge wherever an articulation point belongs to a block. It is a forest
+/// when the graph is disconnected.
+#[derive(Debug, Clone, Serialize)]
+pub struct BlockCutTree {
+    /// Members of each block, ascending; blocks are ordered by smallest
+    /// member. Isolated nodes form single-node blocks.
+    pub blocks: Vec<Vec<usize>>,
+    /// Articulation points, ascending, with their removal impact
+    pub cut_vertices: Vec<CutVertexImpact>,
+    /// Tree edges as (block index, articulation point)
+    pub edges: Vec<(usize, usize)>,
+}
+
+/// Find biconnected components (blocks) and build the block-cut tree.
+///
+/// Same DFS as `articulation_points`, with a vertex stack: whenever a child
+/// u of v has low[u] >= disc[v], the vertices above u on the stack plus v
+/// form a block. Subtree sizes give the pieces each cut vertex leaves
+/// behind: one per such child, plus whatever remains above v.
+pub fn block_cut_tree(graph: &DiGraph) -> BlockCutTree {
+    let n = graph.len();
+    let mut dfs = BlockDfs {
+        neighbors: graph.csr(),
+        disc: vec![0; n],
+        low: vec![0; n],
+        size: vec![0; n],
+        split: vec![Vec::new(); n],
+        stack: Vec::new(),
+        visited: Vec::new(),
+        blocks: Vec::new(),
+        time: 0,
+    };
+    let mut parts: Vec<Vec<usize>> = vec![Vec::new(); n];
+
+    for root in graph.live_nodes() {
+        if dfs.disc[root] != 0 {
+            continue;
+        }
+        dfs.visit(root);
+        dfs.stack.clear();
+        if dfs.split[root].is_empty() {
+            dfs.blocks.push(vec![root]);
+        }
+
+        // Pieces left by each articulation point of this component
+        let total = dfs.size[root];
+        for v in std::mem::take(&mut dfs.visited) {
+            let split = std::mem::take(&mut dfs.split[v]);
+            if v == root {
+                if split.len() > 1 {
+                    parts[v] = split;
+                }
+            } else if !split.is_empty() {
+                let rest = total - 1 - split.iter().sum::<usize>();
+                parts[v] = split;
+                parts[v].push(rest);
+            }
+        }
+    }
+
+    let mut blocks = dfs.blocks;
+    for block in &mut blocks {
+        block.sort_unstable();
+    }
+    blocks.sort_unstable();
+
+    let mut edges = Vec::new();
+    for (b, block) in blocks.iter().enumerate() {
+        for &v in block {
+            if !parts[v].is_empty() {
+                edges.push((b, v));
+            }
+        }
+    }
+
+    let cut_vertices = parts
+        .into_iter()
+        .enumerate()
+        .filter(|(_, p)| !p.is_empty())
+        .map(|(v, mut p)| {
+            p.sort_unstable_by(|a, b| b.cmp(a));
+            CutVertexImpact {
+                node: v,
+                node_id: graph.node_id(v),
+                disconnected: p.iter().skip(1).sum(),
+                parts: p,
+            }
+        })
+        .collect();
+
+    BlockCutTree {
+        blocks,
+        cut_vertices,
+        edges,
+    }
+}
+
+/// Biconnected components (blocks), each ascending, ordered by smallest member.
+pub fn biconnected_components(graph: &DiGraph) -> Vec<Vec<usize>> {
+    block_cut_tree(graph).blocks
+}
+
+/// DFS state for block extraction.
+struct BlockDfs<'a> {
+    neighbors: &'a CsrGraph,
+    disc: Vec<usize>,
+    low: Vec<usize>,
+    /// DFS subtree sizes
+    size: Vec<usize>,
+    /// Subtree sizes of the children a vertex separates
+    split: Vec<Vec<usize>>,
+    stack: Vec<usize>,
+    /// Vertices reached from the current root, in discovery order
+    visited: Vec<usize>,
+    blocks: Vec<Vec<usize>>,
+    time: usize,
+}
+
+impl BlockDfs<'_> {
+    fn enter(&mut self, v: usize) {
+        self.time += 1;
+        self.disc[v] = self.time;
+        self.low[v] = self.time;
+        self.size[v] = 1;
+        self.stack.push(v);
+        self.visited.push(v);
+    }
+
+    /// DFS from `root` over explicit (v, parent, next neighbor) frames, so
+    /// long paths do not exhaust the call stack.
+    fn visit(&mut self, root: usize) {
+        self.enter(root);
+        let mut frames: Vec<(usize, usize, usize)> = vec![(root, usize::MAX, 0)];
+        while let Some(&mut (v, parent, ref mut next)) = frames.last_mut() {
+            if let Some(&u) = self.neighbors.undirected_neighbors(v).get(*next) {
+                *next += 1;
+                let u = u as usize;
+                if self.disc[u] == 0 {
+                    self.enter(u);
+                    frames.push((u, v, 0));
+                } else if u != parent {
+                    self.low[v] = self.low[v].min(self.disc[u]);
+                }
+                continue;
+            }
+
+            // v is finished; fold it into its parent
+            frames.pop();
+            let Some(&(p, _, _)) = frames.last() else {
+                break;
+            };
+            self.size[p] += self.size[v];
+            self.low[p] = self.low[p].min(self.low[v]);
+            if self.low[v] >= self.disc[p] {
+                self.split[p].push(self.size[v]);
+                let mut block = vec![p];
+                while let Some(w) = self.stack.pop() {
+                    block.push(w);
+                    if w == v {
+                        break;
+                    }
+                }
+                self.blocks.push(block);
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -387,4 +555,65 @@ mod tests {
         let ap = articulation_points(&graph);
         assert!(ap.is_empty());
     }
+
+    #[test]
+    fn test_block_cut_tree_bowtie_with_tail() {
+        // Triangles 0-1-2 and 2-3-4 share 2; tail 4 - 5 - 6; 7 isolated
+        let mut graph = DiGraph::new();
+        let ids: Vec<usize> = (0..8).map(|i| graph.add_node(&format!("n{}", i))).collect();
+        for (a, b) in [(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2), (4, 5), (6, 5)] {
+            graph.add_edge(ids[a], ids[b]);
+        }
+
+        let tree = block_cut_tree(&graph);
+        assert_eq!(
+            tree.blocks,
+            vec![vec![0, 1, 2], vec![2, 3, 4], vec![4, 5], vec![5, 6], vec![7]]
+        );
+        let cuts: Vec<(usize, Vec<usize>, usize)> = tree
+            .cut_vertices
+            .iter()
+            .map(|c| (c.node, c.parts.clone(), c.disconnected))
+            .collect();
+        assert_eq!(
+            cuts,
+            vec![(2, vec![4, 2], 2), (4, vec![4, 2], 2), (5, vec![5, 1], 1)]
+        );
+        assert_eq!(tree.edges, vec![(0, 2), (1, 2), (1, 4), (2, 4), (2, 5), (3, 5)]);
+        assert_eq!(tree.cut_vertices[0].node_id.as_deref(), Some("n2"));
+
+        let mut ap: Vec<usize> = tree.cut_vertices.iter().map(|c| c.node).collect();
+        ap.sort_unstable();
+        assert_eq!(ap, articulation_points(&graph));
+    }
+
+    #[test]
+    fn test_block_cut_tree_star_root() {
+        // Hub 0 with three leaves: DFS starts at the cut vertex itself
+        let mut graph = DiGraph::new();
+        let ids: Vec<usize> = (0..4).map(|i| graph.add_node(&format!("n{}", i))).collect();
+        for leaf in 1..4 {
+            graph.add_edge(ids[0], ids[leaf]);
+        }
+        let tree = block_cut_tree(&graph);
+        assert_eq!(biconnected_components(&graph), vec![vec![0, 1], vec![0, 2], vec![0, 3]]);
+        assert_eq!(tree.cut_vertices.len(), 1);
+        assert_eq!(tree.cut_vertices[0].parts, vec![1, 1, 1]);
+        assert_eq!(tree.cut_vertices[0].disconnected, 2);
+    }
+
+    #[test]
+    fn test_block_cut_tree_long_path() {
+        // Deeper than any call stack allows for a recursive DFS
+        let n = 200_000;
+        let ids: Vec<String> = (0..n).map(|i| format!("n{}", i)).collect();
+        let from: Vec<u32> = (0..n as u32 - 1).collect();
+        let to: Vec<u32> = (1..n as u32).collect();
+        let graph = DiGraph::try_from_arrays(&ids, &from, &to).unwrap();
+        let tree = block_cut_tree(&graph);
+        assert_eq!(tree.blocks.len(), n - 1);
+        assert_eq!(tree.blocks[n - 2], vec![n - 2, n - 1]);
+        assert_eq!(tree.cut_vertices.len(), n - 2);
+        assert!(tree.cut_vertices.iter().all(|c| c.parts.len() == 2));
+    }
 }
@@ -22,8 +22,8 @@ pub fn betweenness(graph: &DiGraph) -> Vec<f64> {
 
     let mut bc = vec![0.0; n];
 
-    // Run single-source betweenness from each node
-    for s in 0..n {
+    // Run single-source betweenness from each live node
+    for s in graph.live_nodes() {
         single_source_betweenness(graph, s, &mut bc);
     }
 
@@ -53,14 +53,19 @@ pub fn betweenness_approx(graph: &DiGraph, sample_size: usize, seed: Option<u64>
     }
 
     // For small graphs or when sample size >= node count, use exact algorithm
-    if sample_size >= n {
+    let live = graph.node_count();
+    if sample_size >= live {
         return betweenness(graph);
     }
 
     let mut bc = vec![0.0; n];
 
-    // Sample k random pivot nodes
-    let pivots = sample_nodes(n, sample_size, seed);
+    // Sample k random pivot nodes among live nodes
+    let live_nodes: Vec<usize> = graph.live_nodes().collect();
+    let pivots: Vec<usize> = sample_nodes(live, sample_size, seed)
+        .into_iter()
+        .map(|i| live_nodes[i])
+        .collect();
 
     // Compute partial betweenness from sampled pivots only
     for &pivot in &pivots {
@@ -69,7 +74,7 @@ pub fn betweenness_approx(graph: &DiGraph, sample_size: usize, seed: Option<u64>
 
     // Scale up: BC_approx = BC_partial * (n / k)
     // This extrapolates from the sample to the full graph
-    let scale = n as f64 / sample_size as f64;
+    let scale = live as f64 / sample_size as f64;
     for score in &mut bc {
         *score *= scale;
     }
@@ -83,6 +88,7 @@ pub fn betweenness_approx(graph: &DiGraph, sample_size: usize, seed: Option<u64>
 /// in a reverse topological order traversal.
 fn single_source_betweenness(graph: &DiGraph, source: usize, bc: &mut [f64]) {
     let n = graph.len();
+    let csr = graph.csr();
 
     // BFS data structures
     let mut stack: Vec<usize> = Vec::with_capacity(n);
@@ -102,7 +108,8 @@ fn single_source_betweenness(graph: &DiGraph, source: usize, bc: &mut [f64]) {
     while let Some(v) = queue.pop_front() {
         stack.push(v);
 
-        for &w in graph.successors_slice(v) {
+        for &w in csr.successors(v) {
+            let w = w as usize;
             // Path discovery: first visit to w
             if dist[w] < 0 {
                 dist[w] = dist[v] + 1;
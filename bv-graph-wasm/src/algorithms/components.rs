@@ -0,0 +1,153 @@
+//! Weakly connected components ("work islands").
+//!
+//! A beads database often holds several unrelated projects. Nodes linked by
+//! any edge, in either direction and of any kind, share a component; the
+//! components are independent, so each can be analysed on its own (see
+//! `DiGraph::componentSubgraph`).
+
+use crate::algorithms::critical_path::critical_path_heights_condensed;
+use crate::graph::DiGraph;
+use serde::Serialize;
+
+/// Summary of one component.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ComponentSummary {
+    pub id: usize,
+    /// Member count
+    pub size: usize,
+    /// Edges between members (every kind)
+    pub edges: usize,
+    pub open: usize,
+    pub closed: usize,
+    /// Longest blocking chain in nodes (a cycle counts as one step)
+    pub depth: usize,
+    /// True if the component contains a blocking cycle
+    pub has_cycle: bool,
+}
+
+/// Component labelling of a graph.
+#[derive(Debug, Clone, Serialize)]
+pub struct ComponentsResult {
+    /// Component of each slot (-1 for removed slots)
+    pub component: Vec<i32>,
+    /// One summary per component, by id; ids are numbered by smallest member
+    pub components: Vec<ComponentSummary>,
+}
+
+impl ComponentsResult {
+    /// Members of component `id`, ascending.
+    pub fn members(&self, id: usize) -> Vec<usize> {
+        self.component
+            .iter()
+            .enumerate()
+            .filter(|&(_, &c)| c == id as i32)
+            .map(|(v, _)| v)
+            .collect()
+    }
+}
+
+/// Label weakly connected components and summarise them.
+/// `closed_set[v]` marks closed (or otherwise resolved) nodes.
+pub fn connected_components(graph: &DiGraph, closed_set: &[bool]) -> ComponentsResult {
+    let n = graph.len();
+    let csr = graph.csr();
+    let mut component = vec![-1i32; n];
+    let mut components: Vec<ComponentSummary> = Vec::new();
+    let mut stack = Vec::new();
+
+    for start in graph.live_nodes() {
+        if component[start] >= 0 {
+            continue;
+        }
+        let id = components.len();
+        component[start] = id as i32;
+        stack.push(start);
+        while let Some(v) = stack.pop() {
+            for &w in csr.undirected_neighbors(v) {
+                let w = w as usize;
+                if component[w] < 0 {
+                    component[w] = id as i32;
+                    stack.push(w);
+                }
+            }
+        }
+        components.push(ComponentSummary {
+            id,
+            size: 0,
+            edges: 0,
+            open: 0,
+            closed: 0,
+            depth: 0,
+            has_cycle: false,
+        });
+    }
+
+    let heights = critical_path_heights_condensed(graph);
+    let mut cyclic = vec![false; n];
+    for &v in &heights.cyclic_nodes {
+        cyclic[v] = true;
+    }
+
+    for v in graph.live_nodes() {
+        let summary = &mut components[component[v] as usize];
+        summary.size += 1;
+        summary.edges += csr.successors(v).len();
+        if closed_set.get(v).copied().unwrap_or(false) {
+            summary.closed += 1;
+        } else {
+            summary.open += 1;
+        }
+        summary.depth = summary.depth.max(heights.values[v] as usize);
+        summary.has_cycle |= cyclic[v];
+    }
+
+    ComponentsResult {
+        component,
+        components,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::EdgeKind;
+
+    #[test]
+    fn test_islands() {
+        // Island 0: a -> b -> c, d -related-> c
+        // Island 1: e <-> f (cycle); g alone; h removed
+        let mut g = DiGraph::new();
+        let ids: Vec<usize> = ["a", "b", "c", "d", "e", "f", "g", "h"]
+            .iter()
+            .map(|id| g.add_node(id))
+            .collect();
+        g.add_edge(ids[0], ids[1]);
+        g.add_edge(ids[1], ids[2]);
+        g.add_edge_kind(ids[3], ids[2], EdgeKind::Related);
+        g.add_edge(ids[4], ids[5]);
+        g.add_edge(ids[5], ids[4]);
+        g.add_edge(ids[7], ids[6]);
+        g.remove_node(ids[7]);
+
+        let mut closed = vec![false; 8];
+        closed[0] = true;
+        let result = connected_components(&g, &closed);
+        assert_eq!(result.component, vec![0, 0, 0, 0, 1, 1, 2, -1]);
+        assert_eq!(
+            result.components[0],
+            ComponentSummary {
+                id: 0,
+                size: 4,
+                edges: 3,
+                open: 3,
+                closed: 1,
+                depth: 3,
+                has_cycle: false,
+            }
+        );
+        assert!(result.components[1].has_cycle);
+        assert_eq!(result.components[1].depth, 1);
+        assert_eq!(result.components[2].size, 1);
+        assert_eq!(result.members(1), vec![4, 5]);
+    }
+}
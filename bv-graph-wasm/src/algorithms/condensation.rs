@@ -0,0 +1,181 @@
+//! SCC condensation of the blocking graph.
+//!
+//! Collapses every strongly connected component (from `cycles::tarjan_scc`)
+//! into a single super-node. The result is always a DAG, so the DAG-only
+//! algorithms (topological sort, critical path heights, slack, k critical
+//! paths) can run on it and map their results back to the member nodes
+//! instead of giving up on the first cycle. Each super-node counts as one
+//! step; members of a cyclic component share its result and are flagged.
+
+use crate::algorithms::cycles::tarjan_scc;
+use crate::graph::DiGraph;
+use serde::Serialize;
+
+/// Condensed view of a graph's blocking structure.
+#[derive(Serialize)]
+pub struct Condensation {
+    /// Component of each slot (`None` for removed slots)
+    pub component: Vec<Option<usize>>,
+    /// Member nodes of each component, ascending
+    pub members: Vec<Vec<usize>>,
+    /// Condensed edges between distinct components
+    pub edges: Vec<(usize, usize)>,
+    /// Whether each component contains a cycle (several members or a self-loop)
+    pub cyclic: Vec<bool>,
+    /// The condensed DAG; node `c` is component `c`
+    #[serde(skip)]
+    pub dag: DiGraph,
+}
+
+impl Condensation {
+    /// Number of components.
+    pub fn len(&self) -> usize {
+        self.members.len()
+    }
+
+    /// True if the graph had no live nodes.
+    pub fn is_empty(&self) -> bool {
+        self.members.is_empty()
+    }
+
+    /// Nodes that lie on a blocking cycle, ascending.
+    pub fn cyclic_nodes(&self) -> Vec<usize> {
+        let mut nodes: Vec<usize> = self
+            .members
+            .iter()
+            .zip(&self.cyclic)
+            .filter(|&(_, &cyclic)| cyclic)
+            .flat_map(|(members, _)| members.iter().copied())
+            .collect();
+        nodes.sort_unstable();
+        nodes
+    }
+
+    /// Spread per-component values back onto node slots (removed slots get `default`).
+    pub fn expand<T: Copy>(&self, values: &[T], default: T) -> Vec<T> {
+        self.component
+            .iter()
+            .map(|c| c.map_or(default, |c| values[c]))
+            .collect()
+    }
+}
+
+/// Collapse the blocking graph's SCCs into a DAG.
+///
+/// Components are numbered by their smallest member, so on an acyclic graph
+/// component order matches node order and condensed results equal the plain
+/// DAG results.
+pub fn condense(graph: &DiGraph) -> Condensation {
+    let mut members = tarjan_scc(graph).components;
+    for m in &mut members {
+        m.sort_unstable();
+    }
+    members.sort_unstable_by_key(|m| m[0]);
+
+    let mut component = vec![None; graph.len()];
+    for (c, m) in members.iter().enumerate() {
+        for &v in m {
+            component[v] = Some(c);
+        }
+    }
+
+    let mut cyclic = vec![false; members.len()];
+    let mut edges = Vec::new();
+    for (c, m) in members.iter().enumerate() {
+        cyclic[c] = m.len() > 1;
+        for &v in m {
+            for w in graph.blocking_successors(v) {
+                match component[w] {
+                    Some(d) if d == c => cyclic[c] = true,
+                    Some(d) => edges.push((c, d)),
+                    None => {}
+                }
+            }
+        }
+    }
+    edges.sort_unstable();
+    edges.dedup();
+
+    let mut dag = DiGraph::with_capacity(members.len(), edges.len());
+    for c in 0..members.len() {
+        dag.add_node(&format!("scc-{}", c));
+    }
+    for &(c, d) in &edges {
+        dag.add_edge(c, d);
+    }
+
+    Condensation {
+        component,
+        members,
+        edges,
+        cyclic,
+        dag,
+    }
+}
+
+/// Per-node values computed on the condensation.
+#[derive(Debug, Clone, Serialize)]
+pub struct CondensedValues {
+    /// One value per slot; members of a component share its value
+    pub values: Vec<f64>,
+    /// Nodes whose value comes from a cyclic component, ascending
+    pub cyclic_nodes: Vec<usize>,
+}
+
+/// Node order computed on the condensation.
+#[derive(Debug, Clone, Serialize)]
+pub struct CondensedOrder {
+    /// Live nodes in topological order of their components; members of one
+    /// component are adjacent and ascending
+    pub order: Vec<usize>,
+    /// Nodes that lie on a cycle, ascending
+    pub cyclic_nodes: Vec<usize>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util;
+
+    #[test]
+    fn test_condense_cycle_into_super_node() {
+        // 0 -> 1 -> 2 -> 1, 2 -> 3, 4 self-loop
+        let g = test_util::graph(5, &[(0, 1), (1, 2), (2, 1), (2, 3), (4, 4)]);
+        let c = condense(&g);
+        assert_eq!(c.members, vec![vec![0], vec![1, 2], vec![3], vec![4]]);
+        assert_eq!(c.component, vec![Some(0), Some(1), Some(1), Some(2), Some(3)]);
+        assert_eq!(c.edges, vec![(0, 1), (1, 2)]);
+        assert_eq!(c.cyclic, vec![false, true, false, true]);
+        assert_eq!(c.cyclic_nodes(), vec![1, 2, 4]);
+        assert!(crate::algorithms::topo::is_dag(&c.dag));
+    }
+
+    #[test]
+    fn test_condense_deep_chain() {
+        // Deeper than any call stack allows for a recursive SCC pass
+        let n = 200_000;
+        let ids: Vec<String> = (0..n).map(|i| format!("n{}", i)).collect();
+        let from: Vec<u32> = (0..n as u32 - 1).collect();
+        let to: Vec<u32> = (1..n as u32).collect();
+        let mut g = DiGraph::try_from_arrays(&ids, &from, &to).unwrap();
+        let c = condense(&g);
+        assert_eq!(c.len(), n);
+        assert!(c.cyclic.iter().all(|&x| !x));
+
+        // Closing the chain folds it into one component
+        g.add_edge(n - 1, 0);
+        let c = condense(&g);
+        assert_eq!(c.len(), 1);
+        assert_eq!(c.members[0].len(), n);
+    }
+
+    #[test]
+    fn test_condense_skips_removed_slots() {
+        let mut g = test_util::graph(3, &[(0, 2)]);
+        g.remove_node(1);
+        let c = condense(&g);
+        assert_eq!(c.len(), 2);
+        assert_eq!(c.component[1], None);
+        assert_eq!(c.expand(&[5, 7], 0), vec![5, 0, 7]);
+    }
+}
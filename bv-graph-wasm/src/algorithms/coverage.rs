@@ -43,10 +43,10 @@ pub struct CoverageResult {
 /// # Returns
 /// A CoverageResult containing the selected nodes and coverage statistics.
 pub fn coverage_set(graph: &DiGraph, limit: usize) -> CoverageResult {
-    let n = graph.node_count();
+    let n = graph.len();
     let total_edges = graph.edge_count();
 
-    if n == 0 || total_edges == 0 {
+    if graph.node_count() == 0 || total_edges == 0 {
         return CoverageResult {
             items: Vec::new(),
             edges_covered: 0,
@@ -57,6 +57,7 @@ pub fn coverage_set(graph: &DiGraph, limit: usize) -> CoverageResult {
 
     // Track covered edges using a HashSet for O(E) memory
     // Instead of O(V^2) with a matrix
+    let csr = graph.csr();
     let mut covered: HashSet<(usize, usize)> = HashSet::new();
     let mut selected: Vec<CoverageItem> = Vec::with_capacity(limit.min(n));
     let mut edges_covered = 0;
@@ -70,14 +71,16 @@ pub fn coverage_set(graph: &DiGraph, limit: usize) -> CoverageResult {
             let mut count = 0;
 
             // Count uncovered outgoing edges (v -> w)
-            for &w in graph.successors_slice(v) {
+            for &w in csr.successors(v) {
+                let w = w as usize;
                 if !covered.contains(&(v, w)) {
                     count += 1;
                 }
             }
 
             // Count uncovered incoming edges (u -> v)
-            for &u in graph.predecessors_slice(v) {
+            for &u in csr.predecessors(v) {
+                let u = u as usize;
                 if !covered.contains(&(u, v)) {
                     count += 1;
                 }
@@ -92,11 +95,13 @@ pub fn coverage_set(graph: &DiGraph, limit: usize) -> CoverageResult {
         match best_node {
             Some(node) if best_count > 0 => {
                 // Mark edges as covered - outgoing
-                for &w in graph.successors_slice(node) {
+                for &w in csr.successors(node) {
+                    let w = w as usize;
                     covered.insert((node, w));
                 }
                 // Mark edges as covered - incoming
-                for &u in graph.predecessors_slice(node) {
+                for &u in csr.predecessors(node) {
+                    let u = u as usize;
                     covered.insert((u, node));
                 }
 
@@ -0,0 +1,170 @@
+//! Duration-weighted critical path method (CPM).
+//!
+//! `slack` and `critical_path_heights` count every issue as one unit of
+//! work. CPM weights each issue by its duration instead: a forward pass over
+//! the blocking DAG gives the earliest start and finish of every issue, a
+//! backward pass from the makespan gives the latest start and finish that
+//! still meet it.
+//!
+//! - Total float (LS - ES): delay an issue can absorb without moving the makespan.
+//! - Free float: delay it can absorb without moving any dependent's early start.
+//!
+//! Issues with zero total float form the critical chain.
+
+use crate::algorithms::topo::try_topological_sort;
+use crate::error::{GraphError, GraphResult};
+use crate::graph::DiGraph;
+use crate::reachability::NodePath;
+use serde::Serialize;
+
+/// Tolerance when comparing sums of durations.
+const EPS: f64 = 1e-9;
+
+/// CPM schedule, one value per slot (0 for removed slots).
+#[derive(Debug, Clone, Serialize)]
+pub struct CpmResult {
+    pub early_start: Vec<f64>,
+    pub early_finish: Vec<f64>,
+    pub late_start: Vec<f64>,
+    pub late_finish: Vec<f64>,
+    pub total_float: Vec<f64>,
+    pub free_float: Vec<f64>,
+    /// Earliest finish of the whole graph
+    pub makespan: f64,
+    /// Zero-float chain from a start to an issue finishing at the makespan
+    pub critical_chain: NodePath,
+}
+
+/// Durations from `estimated_minutes`, with `default_minutes` for issues
+/// that have no estimate.
+pub fn estimated_durations(graph: &DiGraph, default_minutes: f64) -> Vec<f64> {
+    (0..graph.len())
+        .map(|v| graph.attrs().estimated_minutes(v).map_or(default_minutes, f64::from))
+        .collect()
+}
+
+/// Run CPM with one duration per slot. Negative and NaN durations count as
+/// 0. Fails with `CyclicGraph` on a blocking cycle and `LengthMismatch` if
+/// `durations` is not one per slot.
+pub fn cpm(graph: &DiGraph, durations: &[f64]) -> GraphResult<CpmResult> {
+    let n = graph.len();
+    if durations.len() != n {
+        return Err(GraphError::LengthMismatch {
+            what: "durations",
+            expected: n,
+            actual: durations.len(),
+        });
+    }
+    let order = try_topological_sort(graph)?;
+    let duration: Vec<f64> = durations.iter().map(|&d| d.max(0.0)).collect();
+
+    // Forward pass
+    let mut early_start = vec![0.0; n];
+    let mut early_finish = vec![0.0; n];
+    for &v in &order {
+        early_start[v] = graph
+            .blocking_predecessors(v)
+            .map(|u| early_finish[u])
+            .fold(0.0, f64::max);
+        early_finish[v] = early_start[v] + duration[v];
+    }
+    let makespan = order.iter().map(|&v| early_finish[v]).fold(0.0, f64::max);
+
+    // Backward pass
+    let mut late_start = vec![0.0; n];
+    let mut late_finish = vec![0.0; n];
+    for &v in order.iter().rev() {
+        late_finish[v] = graph
+            .blocking_successors(v)
+            .map(|w| late_start[w])
+            .fold(makespan, f64::min);
+        late_start[v] = late_finish[v] - duration[v];
+    }
+
+    let mut total_float = vec![0.0; n];
+    let mut free_float = vec![0.0; n];
+    for &v in &order {
+        total_float[v] = (late_start[v] - early_start[v]).max(0.0);
+        let next_start = graph
+            .blocking_successors(v)
+            .map(|w| early_start[w])
+            .fold(makespan, f64::min);
+        free_float[v] = (next_start - early_finish[v]).max(0.0);
+    }
+
+    // Walk back from the lowest-index issue finishing at the makespan,
+    // always through a predecessor whose finish fixes the early start.
+    let mut chain = Vec::new();
+    let mut current = order
+        .iter()
+        .copied()
+        .filter(|&v| (early_finish[v] - makespan).abs() <= EPS)
+        .min();
+    while let Some(v) = current {
+        chain.push(v);
+        current = graph
+            .blocking_predecessors(v)
+            .filter(|&u| (early_finish[u] - early_start[v]).abs() <= EPS)
+            .min();
+    }
+    chain.reverse();
+
+    Ok(CpmResult {
+        early_start,
+        early_finish,
+        late_start,
+        late_finish,
+        total_float,
+        free_float,
+        makespan,
+        critical_chain: NodePath::new(graph, chain),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util;
+
+    #[test]
+    fn test_diamond_schedule() {
+        // 0 -> {1, 2} -> 3, plus 4 on its own; 2 is the long branch
+        let g = test_util::graph(5, &[(0, 1), (0, 2), (1, 3), (2, 3)]);
+        let result = cpm(&g, &[2.0, 3.0, 10.0, 1.0, 4.0]).unwrap();
+
+        assert_eq!(result.makespan, 13.0);
+        assert_eq!(result.early_start, vec![0.0, 2.0, 2.0, 12.0, 0.0]);
+        assert_eq!(result.early_finish, vec![2.0, 5.0, 12.0, 13.0, 4.0]);
+        assert_eq!(result.late_start, vec![0.0, 9.0, 2.0, 12.0, 9.0]);
+        assert_eq!(result.late_finish, vec![2.0, 12.0, 12.0, 13.0, 13.0]);
+        assert_eq!(result.total_float, vec![0.0, 7.0, 0.0, 0.0, 9.0]);
+        assert_eq!(result.free_float, vec![0.0, 7.0, 0.0, 0.0, 9.0]);
+        assert_eq!(result.critical_chain.nodes, vec![0, 2, 3]);
+        assert_eq!(result.critical_chain.ids, vec!["n0", "n2", "n3"]);
+    }
+
+    #[test]
+    fn test_free_float_differs_from_total() {
+        // 0 -> 1 -> 3 and 2 -> 3; 0 can slip only as far as 1 lets it
+        let g = test_util::graph(4, &[(0, 1), (1, 3), (2, 3)]);
+        let result = cpm(&g, &[1.0, 1.0, 5.0, 1.0]).unwrap();
+        assert_eq!(result.total_float[0], 3.0);
+        assert_eq!(result.free_float[0], 0.0);
+        assert_eq!(result.free_float[1], 3.0);
+        assert_eq!(result.critical_chain.nodes, vec![2, 3]);
+    }
+
+    #[test]
+    fn test_estimates_and_errors() {
+        let mut g = test_util::graph(3, &[(0, 1), (1, 2)]);
+        g.set_estimated_minutes(0, 30);
+        g.set_estimated_minutes(2, 90);
+        let durations = estimated_durations(&g, 60.0);
+        assert_eq!(durations, vec![30.0, 60.0, 90.0]);
+        assert_eq!(cpm(&g, &durations).unwrap().makespan, 180.0);
+
+        assert_eq!(cpm(&g, &[1.0]).unwrap_err().code(), "LENGTH_MISMATCH");
+        g.add_edge(2, 0);
+        assert_eq!(cpm(&g, &durations).unwrap_err().code(), "CYCLIC_GRAPH");
+    }
+}
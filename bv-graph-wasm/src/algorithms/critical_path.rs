@@ -3,45 +3,61 @@
 //! Computes the longest dependency chain from roots to each node.
 //! Nodes with high heights are deep in the dependency tree.
 
-use crate::algorithms::topo::topological_sort;
+use crate::algorithms::condensation::{condense, CondensedValues};
+use crate::algorithms::topo::try_topological_sort;
+use crate::error::GraphResult;
 use crate::graph::DiGraph;
 
 /// Compute critical path heights (depth in DAG).
 ///
-/// Height[v] = 1 + max(height of predecessors)
-/// Roots (no predecessors) have height 1.
+/// Height[v] = 1 + max(height of blocking predecessors)
+/// Roots (no blocking predecessors) have height 1.
 ///
 /// # Arguments
 /// * `graph` - The directed graph
 ///
 /// # Returns
-/// Vector of heights, indexed by node. Returns zeros for cyclic graphs.
+/// Vector of heights, indexed by node. Returns zeros for cyclic graphs;
+/// use `try_critical_path_heights` to detect that case.
 pub fn critical_path_heights(graph: &DiGraph) -> Vec<f64> {
+    try_critical_path_heights(graph).unwrap_or_else(|_| vec![0.0; graph.len()])
+}
+
+/// Compute critical path heights, failing with `GraphError::CyclicGraph`
+/// if the blocking graph has a cycle.
+pub fn try_critical_path_heights(graph: &DiGraph) -> GraphResult<Vec<f64>> {
     let n = graph.len();
     if n == 0 {
-        return Vec::new();
+        return Ok(Vec::new());
     }
 
-    // Topological order (returns None if cyclic)
-    let order = match topological_sort(graph) {
-        Some(o) => o,
-        None => return vec![0.0; n], // Return zeros for cyclic graphs
-    };
+    let order = try_topological_sort(graph)?;
 
     let mut heights = vec![0.0; n];
 
     // Process in topological order
     for &v in &order {
         let max_pred_height = graph
-            .predecessors_slice(v)
-            .iter()
-            .map(|&u| heights[u])
+            .blocking_predecessors(v)
+            .map(|u| heights[u])
             .fold(0.0, f64::max);
 
         heights[v] = 1.0 + max_pred_height;
     }
 
-    heights
+    Ok(heights)
+}
+
+/// Critical path heights on the SCC condensation, so cyclic graphs still
+/// get heights. A cycle counts as one step and all its members share the
+/// component's height. On a DAG this equals `critical_path_heights`.
+pub fn critical_path_heights_condensed(graph: &DiGraph) -> CondensedValues {
+    let c = condense(graph);
+    let heights = critical_path_heights(&c.dag);
+    CondensedValues {
+        values: c.expand(&heights, 0.0),
+        cyclic_nodes: c.cyclic_nodes(),
+    }
 }
 
 /// Get nodes on the critical path (those with maximum height).
@@ -56,7 +72,7 @@ pub fn critical_path_nodes(graph: &DiGraph) -> Vec<usize> {
     heights
         .iter()
         .enumerate()
-        .filter(|(_, &h)| (h - max_height).abs() < 0.001)
+        .filter(|&(i, &h)| (h - max_height).abs() < 0.001 && graph.is_live(i))
         .map(|(i, _)| i)
         .collect()
 }
@@ -71,6 +87,7 @@ pub fn critical_path_length(graph: &DiGraph) -> f64 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::graph::EdgeKind;
 
     #[test]
     fn test_empty_graph() {
@@ -165,6 +182,23 @@ mod tests {
         let heights = critical_path_heights(&g);
         // Should return zeros for cyclic graphs
         assert_eq!(heights, vec![0.0, 0.0, 0.0]);
+
+        let err = try_critical_path_heights(&g).unwrap_err();
+        assert_eq!(err.code(), "CYCLIC_GRAPH");
+    }
+
+    #[test]
+    fn test_non_blocking_edges_ignored() {
+        // a -blocks-> b -parent-child-> c
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        g.add_edge(a, b);
+        g.add_edge_kind(b, c, EdgeKind::ParentChild);
+
+        let heights = critical_path_heights(&g);
+        assert_eq!(heights, vec![1.0, 2.0, 1.0]);
     }
 
     #[test]
@@ -223,4 +257,23 @@ mod tests {
         assert_eq!(heights[d], 2.0);
         assert_eq!(heights[e], 2.0);
     }
+
+    #[test]
+    fn test_condensed_heights_with_cycle() {
+        // a -> b <-> c -> d
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        let d = g.add_node("d");
+        g.add_edge(a, b);
+        g.add_edge(b, c);
+        g.add_edge(c, b);
+        g.add_edge(c, d);
+        assert_eq!(critical_path_heights(&g), vec![0.0; 4]);
+
+        let result = critical_path_heights_condensed(&g);
+        assert_eq!(result.values, vec![1.0, 2.0, 2.0, 3.0]);
+        assert_eq!(result.cyclic_nodes, vec![b, c]);
+    }
 }
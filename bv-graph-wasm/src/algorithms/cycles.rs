@@ -3,7 +3,11 @@
 //! Provides:
 //! - Tarjan's SCC algorithm for fast cycle presence check
 //! - Johnson's algorithm for full cycle enumeration
+//!
+//! Cycles are detected over blocking edges only, so mutual `related` links
+//! are not reported as dependency cycles.
 
+use crate::error::{GraphError, GraphResult};
 use crate::graph::DiGraph;
 use serde::Serialize;
 use std::collections::HashSet;
@@ -22,6 +26,7 @@ pub struct SCCResult {
 /// Tarjan's algorithm for finding strongly connected components.
 ///
 /// An SCC with more than one node indicates a cycle.
+/// Iterative, so chain depth is not limited by the stack.
 /// Complexity: O(V + E)
 pub fn tarjan_scc(graph: &DiGraph) -> SCCResult {
     let n = graph.len();
@@ -40,60 +45,56 @@ pub fn tarjan_scc(graph: &DiGraph) -> SCCResult {
     let mut stack: Vec<usize> = Vec::new();
     let mut components: Vec<Vec<usize>> = Vec::new();
 
-    fn strongconnect(
-        v: usize,
-        graph: &DiGraph,
-        index: &mut usize,
-        indices: &mut [usize],
-        lowlink: &mut [usize],
-        on_stack: &mut [bool],
-        stack: &mut Vec<usize>,
-        components: &mut Vec<Vec<usize>>,
-    ) {
-        indices[v] = *index;
-        lowlink[v] = *index;
-        *index += 1;
-        stack.push(v);
-        on_stack[v] = true;
-
-        for &w in graph.successors_slice(v) {
-            if indices[w] == usize::MAX {
-                // Not visited
-                strongconnect(w, graph, index, indices, lowlink, on_stack, stack, components);
-                lowlink[v] = lowlink[v].min(lowlink[w]);
-            } else if on_stack[w] {
-                // On stack = in current SCC
-                lowlink[v] = lowlink[v].min(indices[w]);
-            }
+    // Explicit (node, next successor) frames keep deep chains off the call stack
+    let csr = graph.csr();
+    let mut frames: Vec<(usize, usize)> = Vec::new();
+    for root in graph.live_nodes() {
+        if indices[root] != usize::MAX {
+            continue;
         }
-
-        // If v is a root node, pop the stack to get SCC
-        if lowlink[v] == indices[v] {
-            let mut component = Vec::new();
-            loop {
-                let w = stack.pop().unwrap();
-                on_stack[w] = false;
-                component.push(w);
-                if w == v {
-                    break;
+        indices[root] = index;
+        lowlink[root] = index;
+        index += 1;
+        stack.push(root);
+        on_stack[root] = true;
+        frames.push((root, 0));
+
+        while let Some(&mut (v, ref mut next)) = frames.last_mut() {
+            if let Some(&w) = csr.blocking_successors(v).get(*next) {
+                *next += 1;
+                let w = w as usize;
+                if indices[w] == usize::MAX {
+                    // Not visited
+                    indices[w] = index;
+                    lowlink[w] = index;
+                    index += 1;
+                    stack.push(w);
+                    on_stack[w] = true;
+                    frames.push((w, 0));
+                } else if on_stack[w] {
+                    // On stack = in current SCC
+                    lowlink[v] = lowlink[v].min(indices[w]);
                 }
+                continue;
             }
-            components.push(component);
-        }
-    }
 
-    for v in 0..n {
-        if indices[v] == usize::MAX {
-            strongconnect(
-                v,
-                graph,
-                &mut index,
-                &mut indices,
-                &mut lowlink,
-                &mut on_stack,
-                &mut stack,
-                &mut components,
-            );
+            frames.pop();
+            // If v is a root node, pop the stack to get SCC
+            if lowlink[v] == indices[v] {
+                let mut component = Vec::new();
+                loop {
+                    let w = stack.pop().unwrap();
+                    on_stack[w] = false;
+                    component.push(w);
+                    if w == v {
+                        break;
+                    }
+                }
+                components.push(component);
+            }
+            if let Some(&(u, _)) = frames.last() {
+                lowlink[u] = lowlink[u].min(lowlink[v]);
+            }
         }
     }
 
@@ -111,6 +112,26 @@ pub fn has_cycles(graph: &DiGraph) -> bool {
     tarjan_scc(graph).has_cycles
 }
 
+/// Nodes lying on a blocking cycle: members of non-trivial SCCs plus
+/// nodes with a blocking self-loop. Sorted ascending.
+pub fn cyclic_nodes(graph: &DiGraph) -> Vec<usize> {
+    let mut nodes: Vec<usize> = tarjan_scc(graph)
+        .components
+        .into_iter()
+        .filter(|c| c.len() > 1 || graph.blocking_successors(c[0]).any(|w| w == c[0]))
+        .flatten()
+        .collect();
+    nodes.sort_unstable();
+    nodes
+}
+
+/// Error describing the blocking cycles of `graph`, for algorithms that need a DAG.
+pub(crate) fn cyclic_graph_error(graph: &DiGraph) -> GraphError {
+    GraphError::CyclicGraph {
+        nodes: cyclic_nodes(graph),
+    }
+}
+
 /// Enumerate elementary cycles using Johnson's algorithm.
 ///
 /// Reference: Donald B. Johnson, "Finding All the Elementary Circuits of a Directed Graph"
@@ -145,6 +166,7 @@ pub fn enumerate_cycles(graph: &DiGraph, max_cycles: usize) -> Vec<Vec<usize>> {
     }
 
     // Circuit search from start vertex
+    #[allow(clippy::too_many_arguments)]
     fn circuit(
         v: usize,
         start: usize,
@@ -164,7 +186,7 @@ pub fn enumerate_cycles(graph: &DiGraph, max_cycles: usize) -> Vec<Vec<usize>> {
         stack.push(v);
         blocked[v] = true;
 
-        for &w in graph.successors_slice(v) {
+        for w in graph.blocking_successors(v) {
             // Only consider nodes >= min_node (Johnson's optimization)
             if w < min_node {
                 continue;
@@ -178,8 +200,8 @@ pub fn enumerate_cycles(graph: &DiGraph, max_cycles: usize) -> Vec<Vec<usize>> {
                     stack.pop();
                     return found;
                 }
-            } else if !blocked[w] {
-                if circuit(
+            } else if !blocked[w]
+                && circuit(
                     w,
                     start,
                     graph,
@@ -189,16 +211,16 @@ pub fn enumerate_cycles(graph: &DiGraph, max_cycles: usize) -> Vec<Vec<usize>> {
                     cycles,
                     max_cycles,
                     min_node,
-                ) {
-                    found = true;
-                }
+                )
+            {
+                found = true;
             }
         }
 
         if found {
             unblock(v, blocked, blocked_map);
         } else {
-            for &w in graph.successors_slice(v) {
+            for w in graph.blocking_successors(v) {
                 if w >= min_node {
                     blocked_map[w].insert(v);
                 }
@@ -216,9 +238,7 @@ pub fn enumerate_cycles(graph: &DiGraph, max_cycles: usize) -> Vec<Vec<usize>> {
         }
 
         // Reset blocked state
-        for b in &mut blocked {
-            *b = false;
-        }
+        blocked.fill(false);
         for s in &mut blocked_map {
             s.clear();
         }
@@ -250,6 +270,9 @@ pub struct CycleEnumerationResult {
     pub count: usize,
 }
 
+/// Largest `max_cycles` accepted by `try_enumerate_cycles_with_info`.
+pub const MAX_ENUMERATED_CYCLES: usize = 100_000;
+
 /// Enumerate cycles with metadata about truncation.
 pub fn enumerate_cycles_with_info(graph: &DiGraph, max_cycles: usize) -> CycleEnumerationResult {
     let cycles = enumerate_cycles(graph, max_cycles);
@@ -261,6 +284,21 @@ pub fn enumerate_cycles_with_info(graph: &DiGraph, max_cycles: usize) -> CycleEn
     }
 }
 
+/// Like `enumerate_cycles_with_info`, but rejects limits above `MAX_ENUMERATED_CYCLES`.
+pub fn try_enumerate_cycles_with_info(
+    graph: &DiGraph,
+    max_cycles: usize,
+) -> GraphResult<CycleEnumerationResult> {
+    if max_cycles > MAX_ENUMERATED_CYCLES {
+        return Err(GraphError::LimitExceeded {
+            what: "max_cycles",
+            requested: max_cycles,
+            max: MAX_ENUMERATED_CYCLES,
+        });
+    }
+    Ok(enumerate_cycles_with_info(graph, max_cycles))
+}
+
 // ============================================================================
 // Cycle Break Suggestions
 // ============================================================================
@@ -352,10 +390,10 @@ pub fn cycle_break_suggestions(
     let mut suggestions: Vec<CycleBreakItem> = Vec::new();
 
     for &from in &cycle_nodes {
-        for &to in graph.successors_slice(from) {
+        for to in graph.blocking_successors(from) {
             if cycle_nodes.contains(&to) {
                 let cycles_broken = edge_cycle_count.get(&(from, to)).copied().unwrap_or(0);
-                let collateral = graph.successors_slice(from).len() + graph.predecessors_slice(to).len();
+                let collateral = graph.blocking_successors(from).count() + graph.blocking_in_degree(to);
 
                 suggestions.push(CycleBreakItem {
                     from,
@@ -407,10 +445,10 @@ pub fn quick_cycle_break_edges(graph: &DiGraph, limit: usize) -> Vec<CycleBreakI
     let mut suggestions: Vec<CycleBreakItem> = Vec::new();
 
     for &from in &cycle_nodes {
-        for &to in graph.successors_slice(from) {
+        for to in graph.blocking_successors(from) {
             if cycle_nodes.contains(&to) {
                 // Heuristic: edges with low total degree are better to remove
-                let collateral = graph.successors_slice(from).len() + graph.predecessors_slice(to).len();
+                let collateral = graph.blocking_successors(from).count() + graph.blocking_in_degree(to);
 
                 suggestions.push(CycleBreakItem {
                     from,
@@ -518,6 +556,31 @@ mod tests {
         assert_eq!(result.cycle_count, 2);
     }
 
+    #[test]
+    fn test_cyclic_nodes() {
+        // a -> b -> a, c -> c, d isolated
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_node("d");
+        graph.add_edge(a, b);
+        graph.add_edge(b, a);
+        graph.add_edge(c, c);
+
+        assert_eq!(cyclic_nodes(&graph), vec![a, b, c]);
+    }
+
+    #[test]
+    fn test_enumerate_limit_exceeded() {
+        let graph = DiGraph::new();
+        let err = try_enumerate_cycles_with_info(&graph, MAX_ENUMERATED_CYCLES + 1)
+            .err()
+            .unwrap();
+        assert_eq!(err.code(), "LIMIT_EXCEEDED");
+        assert!(try_enumerate_cycles_with_info(&graph, 10).is_ok());
+    }
+
     #[test]
     fn test_enumerate_empty() {
         let graph = DiGraph::new();
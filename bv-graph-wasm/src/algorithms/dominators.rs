@@ -0,0 +1,293 @@
+//! Dominator and post-dominator trees (Lengauer–Tarjan).
+//!
+//! Articulation points answer an undirected question. For blocking work the
+//! directional one matters: which issues does *every* blocking chain into an
+//! epic pass through? Node `d` dominates `v` if every blocking path from a
+//! starting point to `v` contains `d`; `d` post-dominates `v` if every path
+//! from `v` to an end point contains `d`.
+//!
+//! Starting points are the members of source SCCs (components without
+//! blockers from outside, i.e. plain sources plus cycles nobody feeds),
+//! joined under a virtual root so the whole graph forms one flow graph. End
+//! points are the members of sink SCCs, joined under a virtual exit.
+//! Runs in O(m log n) with path compression.
+
+use crate::algorithms::condensation::condense;
+use crate::graph::DiGraph;
+
+const NONE: usize = usize::MAX;
+
+/// Immediate (post-)dominator of every node.
+#[derive(Debug, Clone)]
+pub struct DominatorTree {
+    /// Immediate dominator per slot; `None` below the virtual root and for
+    /// removed slots
+    pub idom: Vec<Option<usize>>,
+    /// Depth below the virtual root (1 for its children, 0 for removed slots)
+    pub depth: Vec<usize>,
+}
+
+impl DominatorTree {
+    /// Strict dominators of `node` from the top of the tree down, so the
+    /// immediate dominator comes last.
+    pub fn chain(&self, node: usize) -> Vec<usize> {
+        let mut chain = Vec::new();
+        let mut v = self.idom.get(node).copied().flatten();
+        while let Some(d) = v {
+            chain.push(d);
+            v = self.idom[d];
+        }
+        chain.reverse();
+        chain
+    }
+
+    /// Nearest common dominator of a set of live nodes (`None` = only the
+    /// virtual root dominates them all).
+    pub fn common_dominator(&self, nodes: &[usize]) -> Option<usize> {
+        let mut nodes = nodes.iter().copied();
+        let mut lca = nodes.next()?;
+        for mut v in nodes {
+            let mut a = lca;
+            while self.depth[a] > self.depth[v] {
+                a = self.idom[a]?;
+            }
+            while self.depth[v] > self.depth[a] {
+                v = self.idom[v]?;
+            }
+            while a != v {
+                a = self.idom[a]?;
+                v = self.idom[v]?;
+            }
+            lca = a;
+        }
+        Some(lca)
+    }
+}
+
+/// Dominator tree of the blocking graph.
+pub fn dominator_tree(graph: &DiGraph) -> DominatorTree {
+    build(graph, false)
+}
+
+/// Post-dominator tree of the blocking graph (dominators of the reverse graph).
+pub fn post_dominator_tree(graph: &DiGraph) -> DominatorTree {
+    build(graph, true)
+}
+
+/// Issues every blocking chain into `node` passes through, in blocking order.
+pub fn dominators(graph: &DiGraph, node: usize) -> Vec<usize> {
+    dominator_tree(graph).chain(node)
+}
+
+/// Issues every blocking chain out of `node` passes through, in blocking order.
+pub fn post_dominators(graph: &DiGraph, node: usize) -> Vec<usize> {
+    let mut chain = post_dominator_tree(graph).chain(node);
+    chain.reverse();
+    chain
+}
+
+/// Issues outside `targets` that dominate every target: completing the
+/// target set is impossible without them. In blocking order.
+pub fn gatekeepers(graph: &DiGraph, targets: &[usize]) -> Vec<usize> {
+    let mut live: Vec<usize> = targets.iter().copied().filter(|&t| graph.is_live(t)).collect();
+    live.sort_unstable();
+    live.dedup();
+
+    let tree = dominator_tree(graph);
+    let Some(lca) = tree.common_dominator(&live) else {
+        return Vec::new();
+    };
+    let mut gates = tree.chain(lca);
+    gates.push(lca);
+    gates.retain(|v| live.binary_search(v).is_err());
+    gates
+}
+
+/// Lengauer–Tarjan on the blocking graph (or its reverse) plus a virtual
+/// root `n` linked to every member of a source (sink) SCC.
+fn build(graph: &DiGraph, reverse: bool) -> DominatorTree {
+    let n = graph.len();
+    let root = n;
+
+    // Augmented adjacency
+    let cond = condense(graph);
+    let mut has_outside_in = vec![false; cond.len()];
+    for &(c, d) in &cond.edges {
+        has_outside_in[if reverse { c } else { d }] = true;
+    }
+    let mut succ: Vec<Vec<usize>> = vec![Vec::new(); n + 1];
+    let mut pred: Vec<Vec<usize>> = vec![Vec::new(); n + 1];
+    for v in graph.live_nodes() {
+        let next: Vec<usize> = if reverse {
+            graph.blocking_predecessors(v).collect()
+        } else {
+            graph.blocking_successors(v).collect()
+        };
+        for w in next {
+            succ[v].push(w);
+            pred[w].push(v);
+        }
+    }
+    for (c, members) in cond.members.iter().enumerate() {
+        if !has_outside_in[c] {
+            for &v in members {
+                succ[root].push(v);
+                pred[v].push(root);
+            }
+        }
+    }
+
+    // DFS numbering from the virtual root
+    let mut dfnum = vec![NONE; n + 1];
+    let mut vertex = Vec::with_capacity(n + 1);
+    let mut parent = vec![NONE; n + 1];
+    let mut stack: Vec<(usize, usize)> = vec![(root, 0)];
+    dfnum[root] = 0;
+    vertex.push(root);
+    while let Some(&mut (v, ref mut next)) = stack.last_mut() {
+        if let Some(&w) = succ[v].get(*next) {
+            *next += 1;
+            if dfnum[w] == NONE {
+                dfnum[w] = vertex.len();
+                vertex.push(w);
+                parent[w] = v;
+                stack.push((w, 0));
+            }
+        } else {
+            stack.pop();
+        }
+    }
+
+    // semi[v] holds a DFS number; ancestor/label form the link-eval forest
+    let mut semi: Vec<usize> = dfnum.clone();
+    let mut idom = vec![NONE; n + 1];
+    let mut ancestor = vec![NONE; n + 1];
+    let mut label: Vec<usize> = (0..=n).collect();
+    let mut bucket: Vec<Vec<usize>> = vec![Vec::new(); n + 1];
+
+    for i in (1..vertex.len()).rev() {
+        let w = vertex[i];
+        for &v in &pred[w] {
+            if dfnum[v] == NONE {
+                continue;
+            }
+            let u = eval(v, &mut ancestor, &mut label, &semi);
+            if semi[u] < semi[w] {
+                semi[w] = semi[u];
+            }
+        }
+        bucket[vertex[semi[w]]].push(w);
+        let p = parent[w];
+        ancestor[w] = p;
+        for v in std::mem::take(&mut bucket[p]) {
+            let u = eval(v, &mut ancestor, &mut label, &semi);
+            idom[v] = if semi[u] < semi[v] { u } else { p };
+        }
+    }
+    for &w in vertex.iter().skip(1) {
+        if idom[w] != vertex[semi[w]] {
+            idom[w] = idom[idom[w]];
+        }
+    }
+
+    // Depths in DFS order (parents in the dominator tree come first)
+    let mut depth = vec![0; n];
+    for &w in vertex.iter().skip(1) {
+        depth[w] = if idom[w] == root { 1 } else { depth[idom[w]] + 1 };
+    }
+
+    DominatorTree {
+        idom: (0..n)
+            .map(|v| (idom[v] != NONE && idom[v] != root).then_some(idom[v]))
+            .collect(),
+        depth,
+    }
+}
+
+/// Vertex with minimal semi-dominator on the forest path above `v`.
+fn eval(v: usize, ancestor: &mut [usize], label: &mut [usize], semi: &[usize]) -> usize {
+    if ancestor[v] == NONE {
+        return v;
+    }
+    // Iterative path compression, applied from the top of the path down
+    let mut path = Vec::new();
+    let mut x = v;
+    while ancestor[ancestor[x]] != NONE {
+        path.push(x);
+        x = ancestor[x];
+    }
+    for &x in path.iter().rev() {
+        let a = ancestor[x];
+        if semi[label[a]] < semi[label[x]] {
+            label[x] = label[a];
+        }
+        ancestor[x] = ancestor[a];
+    }
+    label[v]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util;
+    use crate::reachability::reachable_from;
+
+    #[test]
+    fn test_diamond_dominators() {
+        // 0 -> {1, 2} -> 3 -> 4
+        let g = test_util::graph(5, &[(0, 1), (0, 2), (1, 3), (2, 3), (3, 4)]);
+        assert_eq!(dominators(&g, 4), vec![0, 3]);
+        assert_eq!(dominators(&g, 3), vec![0]);
+        assert!(dominators(&g, 0).is_empty());
+        assert_eq!(post_dominators(&g, 0), vec![3, 4]);
+        assert_eq!(post_dominators(&g, 1), vec![3, 4]);
+        assert!(post_dominators(&g, 4).is_empty());
+    }
+
+    #[test]
+    fn test_multiple_sources_and_cycles() {
+        // 0 -> 2, 1 -> 2, 2 -> 3 <-> 4 -> 5; 6 <-> 7 is a cycle nobody feeds, 7 -> 5
+        let g = test_util::graph(8, &[(0, 2), (1, 2), (2, 3), (3, 4), (4, 3), (4, 5), (6, 7), (7, 6), (7, 5)]);
+        assert!(dominators(&g, 2).is_empty());
+        assert_eq!(dominators(&g, 4), vec![2, 3]);
+        assert!(dominators(&g, 5).is_empty());
+        assert!(dominators(&g, 7).is_empty());
+        assert_eq!(gatekeepers(&g, &[3, 4]), vec![2]);
+        assert_eq!(gatekeepers(&g, &[4]), vec![2, 3]);
+        assert!(gatekeepers(&g, &[4, 5]).is_empty());
+    }
+
+    #[test]
+    fn test_matches_brute_force() {
+        // d dominates v iff v cannot be reached from the entries once d is removed
+        let mut edges = Vec::new();
+        let mut seed = 11usize;
+        for _ in 0..40 {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345) % (1 << 31);
+            edges.push((seed % 14, (seed / 14) % 14));
+        }
+        let g = test_util::graph(14, &edges);
+        let tree = dominator_tree(&g);
+        let cond = condense(&g);
+        let entries: Vec<usize> = (0..14)
+            .filter(|&v| {
+                let c = cond.component[v].unwrap();
+                !cond.edges.iter().any(|&(_, d)| d == c)
+            })
+            .collect();
+
+        for d in 0..14 {
+            let mut h = test_util::graph(14, &edges);
+            h.remove_node(d);
+            let mut reached = [false; 14];
+            for &e in entries.iter().filter(|&&e| e != d) {
+                for v in reachable_from(&h, e) {
+                    reached[v] = true;
+                }
+            }
+            for v in (0..14).filter(|&v| v != d) {
+                assert_eq!(tree.chain(v).contains(&d), !reached[v], "{} dom {}", d, v);
+            }
+        }
+    }
+}
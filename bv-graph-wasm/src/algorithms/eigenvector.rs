@@ -30,26 +30,29 @@ impl Default for EigenvectorConfig {
 /// Returns vector of scores in node index order, normalized to unit length.
 pub fn eigenvector(graph: &DiGraph, config: &EigenvectorConfig) -> Vec<f64> {
     let n = graph.len();
-    if n == 0 {
-        return Vec::new();
+    let live = graph.node_count();
+    if live == 0 {
+        return vec![0.0; n];
     }
 
     // Initialize with uniform distribution
-    let init_val = 1.0 / (n as f64).sqrt();
-    let mut vec = vec![init_val; n];
+    let uniform: Vec<f64> = (0..n)
+        .map(|i| if graph.is_live(i) { 1.0 / (live as f64).sqrt() } else { 0.0 })
+        .collect();
+    let csr = graph.csr();
+    let mut vec = uniform.clone();
     let mut work = vec![0.0; n];
 
     for _ in 0..config.iterations {
         // Reset work vector
-        for w in &mut work {
-            *w = 0.0;
-        }
+        work.fill(0.0);
 
         // Multiply: work = A^T * vec (sum of predecessor scores)
         // A node's score = sum of scores of nodes that point to it
-        for v in 0..n {
-            for &u in graph.predecessors_slice(v) {
-                work[v] += vec[u];
+        for (v, w) in work.iter_mut().enumerate() {
+            for &u in csr.predecessors(v) {
+                let u = u as usize;
+                *w += vec[u];
             }
         }
 
@@ -57,8 +60,7 @@ pub fn eigenvector(graph: &DiGraph, config: &EigenvectorConfig) -> Vec<f64> {
         let norm: f64 = work.iter().map(|x| x * x).sum::<f64>().sqrt();
         if norm < 1e-10 {
             // Graph has no edges or is disconnected - return uniform
-            let uniform = 1.0 / (n as f64).sqrt();
-            return vec![uniform; n];
+            return uniform;
         }
 
         for w in &mut work {
@@ -0,0 +1,421 @@
+//! Minimum feedback arc set heuristic.
+//!
+//! Finds a small set of blocking edges whose removal makes the graph
+//! acyclic, without enumerating cycles. Each non-trivial SCC is solved on
+//! its own:
+//!
+//! 1. Eades–Lin–Smyth greedy ordering: peel sinks to the back and sources
+//!    to the front; otherwise move the vertex with the largest
+//!    out-degree minus in-degree to the front.
+//! 2. Local search: sift each vertex to the position that minimises the
+//!    backward edges touching it, until no move helps.
+//! 3. Backward edges of the order form the arc set; any arc that can be
+//!    restored without closing a cycle is put back, so the set is minimal.
+//!
+//! Steps 2 and 3 are skipped for SCCs above `LOCAL_SEARCH_MAX_NODES`; the
+//! result's `minimal` flag is false when that happened.
+//!
+//! Self-loops are always in the set. The result is re-checked for
+//! acyclicity before it is returned.
+
+use crate::algorithms::cycles::{tarjan_scc, CycleBreakItem};
+use crate::graph::DiGraph;
+use serde::Serialize;
+use std::collections::{HashSet, VecDeque};
+
+/// SCCs above this size skip local search and minimisation (quadratic cost).
+pub const LOCAL_SEARCH_MAX_NODES: usize = 2_000;
+
+/// Maximum sifting passes per SCC.
+const MAX_SIFT_PASSES: usize = 8;
+
+/// Result of the feedback arc set solver.
+#[derive(Debug, Clone, Serialize)]
+pub struct FeedbackArcSetResult {
+    /// Edges to remove, sorted by collateral asc, then (from, to).
+    /// `cycles_broken` is 1: cycles are not enumerated.
+    pub edges: Vec<CycleBreakItem>,
+    /// True once removing `edges` was verified to leave an acyclic graph
+    pub acyclic: bool,
+    /// True when every SCC fit `LOCAL_SEARCH_MAX_NODES`, so no edge in the
+    /// set could be restored on its own
+    pub minimal: bool,
+    /// Number of SCCs that contained a cycle
+    pub cyclic_components: usize,
+}
+
+/// Compute a feedback arc set of the blocking graph.
+pub fn feedback_arc_set(graph: &DiGraph) -> FeedbackArcSetResult {
+    let mut removed: Vec<(usize, usize)> = Vec::new();
+    let mut cyclic_components = 0;
+    let mut minimal = true;
+
+    for members in tarjan_scc(graph).components {
+        let self_loops: Vec<usize> = members
+            .iter()
+            .copied()
+            .filter(|&v| graph.blocking_successors(v).any(|w| w == v))
+            .collect();
+        if members.len() == 1 && self_loops.is_empty() {
+            continue;
+        }
+        cyclic_components += 1;
+        removed.extend(self_loops.iter().map(|&v| (v, v)));
+        if members.len() > 1 {
+            minimal &= members.len() <= LOCAL_SEARCH_MAX_NODES;
+            removed.extend(solve_component(graph, &members));
+        }
+    }
+
+    let removed_set: HashSet<(usize, usize)> = removed.iter().copied().collect();
+    let acyclic = is_acyclic_without(graph, &removed_set);
+
+    let mut edges: Vec<CycleBreakItem> = removed
+        .into_iter()
+        .map(|(from, to)| CycleBreakItem {
+            from,
+            to,
+            cycles_broken: 1,
+            collateral: graph.blocking_successors(from).count() + graph.blocking_in_degree(to),
+            from_id: graph.node_id(from),
+            to_id: graph.node_id(to),
+        })
+        .collect();
+    edges.sort_by_key(|e| (e.collateral, e.from, e.to));
+
+    FeedbackArcSetResult {
+        edges,
+        acyclic,
+        minimal,
+        cyclic_components,
+    }
+}
+
+/// Feedback arcs of one SCC (self-loops excluded), in graph indices.
+fn solve_component(graph: &DiGraph, members: &[usize]) -> Vec<(usize, usize)> {
+    let k = members.len();
+    let mut sorted = members.to_vec();
+    sorted.sort_unstable();
+    let local = |v: usize| sorted.binary_search(&v).ok();
+
+    // Local adjacency restricted to the SCC, without self-loops
+    let mut succ: Vec<Vec<usize>> = vec![Vec::new(); k];
+    let mut pred: Vec<Vec<usize>> = vec![Vec::new(); k];
+    for (i, &v) in sorted.iter().enumerate() {
+        for w in graph.blocking_successors(v) {
+            if let Some(j) = local(w).filter(|&j| j != i) {
+                succ[i].push(j);
+                pred[j].push(i);
+            }
+        }
+    }
+
+    let mut order = eades_lin_smyth(&succ, &pred);
+    let small = k <= LOCAL_SEARCH_MAX_NODES;
+    if small {
+        sift(&mut order, &succ, &pred);
+    }
+
+    let mut pos = vec![0; k];
+    for (p, &v) in order.iter().enumerate() {
+        pos[v] = p;
+    }
+    let mut backward: Vec<(usize, usize)> = (0..k)
+        .flat_map(|u| succ[u].iter().map(move |&v| (u, v)))
+        .filter(|&(u, v)| pos[u] > pos[v])
+        .collect();
+
+    if small {
+        backward = restore_redundant(&succ, backward);
+    }
+
+    backward
+        .into_iter()
+        .map(|(u, v)| (sorted[u], sorted[v]))
+        .collect()
+}
+
+/// Eades–Lin–Smyth vertex ordering in O(n + m).
+fn eades_lin_smyth(succ: &[Vec<usize>], pred: &[Vec<usize>]) -> Vec<usize> {
+    let k = succ.len();
+    let mut outdeg: Vec<usize> = succ.iter().map(Vec::len).collect();
+    let mut indeg: Vec<usize> = pred.iter().map(Vec::len).collect();
+    let mut done = vec![false; k];
+
+    // Buckets by delta = outdeg - indeg, offset to be non-negative; stale
+    // entries are skipped lazily.
+    let offset = k;
+    let delta = |v: usize, outdeg: &[usize], indeg: &[usize]| outdeg[v] + offset - indeg[v];
+    let mut buckets: Vec<Vec<usize>> = vec![Vec::new(); 2 * k + 1];
+    let mut top = 0;
+    for v in (0..k).rev() {
+        let d = delta(v, &outdeg, &indeg);
+        buckets[d].push(v);
+        top = top.max(d);
+    }
+
+    let mut sinks: Vec<usize> = (0..k).filter(|&v| outdeg[v] == 0).collect();
+    let mut sources: Vec<usize> = (0..k).filter(|&v| indeg[v] == 0 && outdeg[v] > 0).collect();
+
+    let mut front = Vec::with_capacity(k);
+    let mut back = Vec::new();
+    let mut remaining = k;
+
+    while remaining > 0 {
+        let v = if let Some(v) = sinks.pop() {
+            if done[v] {
+                continue;
+            }
+            back.push(v);
+            v
+        } else if let Some(v) = sources.pop() {
+            if done[v] {
+                continue;
+            }
+            front.push(v);
+            v
+        } else {
+            // Highest delta among remaining vertices
+            let v = loop {
+                match buckets[top].pop() {
+                    Some(v) if !done[v] && delta(v, &outdeg, &indeg) == top => break v,
+                    Some(_) => {}
+                    None => top -= 1,
+                }
+            };
+            front.push(v);
+            v
+        };
+
+        done[v] = true;
+        remaining -= 1;
+        for &u in &pred[v] {
+            if !done[u] {
+                outdeg[u] -= 1;
+                if outdeg[u] == 0 {
+                    sinks.push(u);
+                }
+                buckets[delta(u, &outdeg, &indeg)].push(u);
+            }
+        }
+        for &w in &succ[v] {
+            if !done[w] {
+                indeg[w] -= 1;
+                if indeg[w] == 0 && outdeg[w] > 0 {
+                    sources.push(w);
+                }
+                let d = delta(w, &outdeg, &indeg);
+                buckets[d].push(w);
+                top = top.max(d);
+            }
+        }
+    }
+
+    back.reverse();
+    front.extend(back);
+    front
+}
+
+/// Move each vertex to its best position while that lowers the number of
+/// backward edges.
+fn sift(order: &mut Vec<usize>, succ: &[Vec<usize>], pred: &[Vec<usize>]) {
+    let k = order.len();
+    // Bit 1: in-neighbor of the vertex being moved, bit 2: out-neighbor
+    let mut mark = vec![0u8; k];
+
+    for _ in 0..MAX_SIFT_PASSES {
+        let mut improved = false;
+        for v in order.clone() {
+            let at = order.iter().position(|&x| x == v).unwrap_or(0);
+            order.remove(at);
+            for &u in &pred[v] {
+                mark[u] |= 1;
+            }
+            for &w in &succ[v] {
+                mark[w] |= 2;
+            }
+
+            // Cost of inserting v before order[p]: in-neighbors at or after p
+            // plus out-neighbors before p.
+            let mut cost = pred[v].len();
+            let mut best = (cost, 0);
+            let mut current = None;
+            for (p, &x) in order.iter().enumerate() {
+                if p == at {
+                    current = Some(cost);
+                }
+                if mark[x] & 1 != 0 {
+                    cost -= 1;
+                }
+                if mark[x] & 2 != 0 {
+                    cost += 1;
+                }
+                if cost < best.0 {
+                    best = (cost, p + 1);
+                }
+            }
+            let current = current.unwrap_or(cost);
+
+            for &u in &pred[v] {
+                mark[u] = 0;
+            }
+            for &w in &succ[v] {
+                mark[w] = 0;
+            }
+
+            if best.0 < current {
+                order.insert(best.1, v);
+                improved = true;
+            } else {
+                order.insert(at, v);
+            }
+        }
+        if !improved {
+            break;
+        }
+    }
+}
+
+/// Drop arcs from `arcs` whose restoration keeps the component acyclic.
+fn restore_redundant(succ: &[Vec<usize>], arcs: Vec<(usize, usize)>) -> Vec<(usize, usize)> {
+    let mut cut: HashSet<(usize, usize)> = arcs.iter().copied().collect();
+    let mut kept = Vec::new();
+    for (u, v) in arcs {
+        cut.remove(&(u, v));
+        if reaches(succ, &cut, v, u) {
+            cut.insert((u, v));
+            kept.push((u, v));
+        }
+    }
+    kept
+}
+
+/// BFS from `from` to `to` over `succ`, skipping edges in `cut`.
+fn reaches(succ: &[Vec<usize>], cut: &HashSet<(usize, usize)>, from: usize, to: usize) -> bool {
+    let mut seen = vec![false; succ.len()];
+    let mut queue = VecDeque::from([from]);
+    seen[from] = true;
+    while let Some(x) = queue.pop_front() {
+        if x == to {
+            return true;
+        }
+        for &y in &succ[x] {
+            if !seen[y] && !cut.contains(&(x, y)) {
+                seen[y] = true;
+                queue.push_back(y);
+            }
+        }
+    }
+    false
+}
+
+/// Kahn's check that the blocking graph minus `cut` has no cycle.
+fn is_acyclic_without(graph: &DiGraph, cut: &HashSet<(usize, usize)>) -> bool {
+    let n = graph.len();
+    let mut indeg = vec![0usize; n];
+    for v in graph.live_nodes() {
+        for w in graph.blocking_successors(v) {
+            if !cut.contains(&(v, w)) {
+                indeg[w] += 1;
+            }
+        }
+    }
+    let mut queue: VecDeque<usize> = graph.live_nodes().filter(|&v| indeg[v] == 0).collect();
+    let mut seen = 0;
+    while let Some(v) = queue.pop_front() {
+        seen += 1;
+        for w in graph.blocking_successors(v) {
+            if !cut.contains(&(v, w)) {
+                indeg[w] -= 1;
+                if indeg[w] == 0 {
+                    queue.push_back(w);
+                }
+            }
+        }
+    }
+    seen == graph.node_count()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util;
+    use crate::algorithms::topo::is_dag;
+
+    fn apply(g: &mut DiGraph, result: &FeedbackArcSetResult) {
+        for e in &result.edges {
+            g.remove_edge(e.from, e.to);
+        }
+    }
+
+    #[test]
+    fn test_dag_needs_nothing() {
+        let g = test_util::graph(3, &[(0, 1), (1, 2)]);
+        let result = feedback_arc_set(&g);
+        assert!(result.edges.is_empty());
+        assert!(result.acyclic);
+        assert_eq!(result.cyclic_components, 0);
+    }
+
+    #[test]
+    fn test_simple_cycle_and_self_loop() {
+        let mut g = test_util::graph(4, &[(0, 1), (1, 2), (2, 0), (3, 3)]);
+        let result = feedback_arc_set(&g);
+        assert_eq!(result.edges.len(), 2);
+        assert!(result.edges.iter().any(|e| e.from == 3 && e.to == 3));
+        assert_eq!(result.cyclic_components, 2);
+        assert!(result.acyclic);
+        apply(&mut g, &result);
+        assert!(is_dag(&g));
+    }
+
+    #[test]
+    fn test_shared_back_edge_is_single_cut() {
+        // Two cycles 0->1->2->0 and 0->3->2->0 share edge 2->0
+        let g = test_util::graph(4, &[(0, 1), (1, 2), (0, 3), (3, 2), (2, 0)]);
+        let result = feedback_arc_set(&g);
+        assert_eq!(result.edges.len(), 1);
+        assert_eq!((result.edges[0].from, result.edges[0].to), (2, 0));
+    }
+
+    #[test]
+    fn test_dense_scc_is_acyclic_and_minimal() {
+        // Complete digraph on 6 nodes: any acyclic subgraph keeps at most 15 of 30 edges
+        let edges: Vec<(usize, usize)> = (0..6)
+            .flat_map(|a| (0..6).filter(move |&b| b != a).map(move |b| (a, b)))
+            .collect();
+        let mut g = test_util::graph(6, &edges);
+        let result = feedback_arc_set(&g);
+        assert_eq!(result.edges.len(), 15);
+        assert!(result.acyclic);
+        assert!(result.minimal);
+        apply(&mut g, &result);
+        assert!(is_dag(&g));
+    }
+
+    #[test]
+    fn test_large_scc_is_not_reported_minimal() {
+        let n = LOCAL_SEARCH_MAX_NODES + 1;
+        let edges: Vec<(usize, usize)> = (0..n).map(|i| (i, (i + 1) % n)).collect();
+        let result = feedback_arc_set(&test_util::graph(n, &edges));
+        assert!(result.acyclic);
+        assert!(!result.minimal);
+    }
+
+    #[test]
+    fn test_restore_keeps_set_minimal() {
+        // Every removed edge must be needed: putting any one back creates a cycle
+        let mut g = test_util::graph(
+            6,
+            &[(0, 1), (1, 2), (2, 0), (2, 3), (3, 4), (4, 2), (4, 5), (5, 3), (1, 4)],
+        );
+        let result = feedback_arc_set(&g);
+        apply(&mut g, &result);
+        assert!(is_dag(&g));
+        for e in &result.edges {
+            g.add_edge(e.from, e.to);
+            assert!(!is_dag(&g));
+            g.remove_edge(e.from, e.to);
+        }
+    }
+}
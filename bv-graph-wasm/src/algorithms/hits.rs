@@ -54,17 +54,21 @@ pub struct HITSResult {
 /// HITSResult containing hub and authority scores
 pub fn hits(graph: &DiGraph, config: &HITSConfig) -> HITSResult {
     let n = graph.len();
-    if n == 0 {
+    let live = graph.node_count();
+    if live == 0 {
         return HITSResult {
-            hubs: Vec::new(),
-            authorities: Vec::new(),
+            hubs: vec![0.0; n],
+            authorities: vec![0.0; n],
             iterations: 0,
         };
     }
 
-    // Initialize with uniform scores
-    let mut hubs = vec![1.0 / (n as f64); n];
-    let mut auth = vec![1.0 / (n as f64); n];
+    // Initialize with uniform scores over live nodes
+    let mut hubs: Vec<f64> = (0..n)
+        .map(|i| if graph.is_live(i) { 1.0 / live as f64 } else { 0.0 })
+        .collect();
+    let mut auth = hubs.clone();
+    let csr = graph.csr();
 
     let mut iterations = 0;
 
@@ -75,16 +79,18 @@ pub fn hits(graph: &DiGraph, config: &HITSConfig) -> HITSResult {
         let mut new_hubs = vec![0.0; n];
 
         // Authority update: auth(v) = sum of hub(u) for all u → v
-        for v in 0..n {
-            for &u in graph.predecessors_slice(v) {
-                new_auth[v] += hubs[u];
+        for (v, a) in new_auth.iter_mut().enumerate() {
+            for &u in csr.predecessors(v) {
+                let u = u as usize;
+                *a += hubs[u];
             }
         }
 
         // Hub update: hub(u) = sum of auth(v) for all u → v
-        for u in 0..n {
-            for &v in graph.successors_slice(u) {
-                new_hubs[u] += new_auth[v];
+        for (u, h) in new_hubs.iter_mut().enumerate() {
+            for &v in csr.successors(u) {
+                let v = v as usize;
+                *h += new_auth[v];
             }
         }
 
@@ -3,6 +3,7 @@
 //! Finds multiple critical paths through the dependency graph.
 //! Uses topological ordering to compute longest paths efficiently.
 
+use crate::algorithms::condensation::condense;
 use crate::algorithms::topo::topological_sort;
 use crate::graph::DiGraph;
 use serde::Serialize;
@@ -25,6 +26,9 @@ pub struct KPathsResult {
     pub total_nodes: usize,
     /// Maximum path length found
     pub max_length: usize,
+    /// Nodes on a cycle (condensed mode only; omitted when empty)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub cyclic_nodes: Vec<usize>,
 }
 
 /// Find k longest paths in a DAG.
@@ -43,13 +47,14 @@ pub struct KPathsResult {
 /// # Note
 /// For cyclic graphs, returns empty result since topological sort fails.
 pub fn k_critical_paths(graph: &DiGraph, k: usize) -> KPathsResult {
-    let n = graph.node_count();
+    let n = graph.len();
 
-    if n == 0 {
+    if graph.node_count() == 0 {
         return KPathsResult {
             paths: Vec::new(),
             total_nodes: 0,
             max_length: 0,
+            cyclic_nodes: Vec::new(),
         };
     }
 
@@ -59,8 +64,9 @@ pub fn k_critical_paths(graph: &DiGraph, k: usize) -> KPathsResult {
         None => {
             return KPathsResult {
                 paths: Vec::new(),
-                total_nodes: n,
+                total_nodes: graph.node_count(),
                 max_length: 0,
+                cyclic_nodes: Vec::new(),
             }
         }
     };
@@ -72,7 +78,7 @@ pub fn k_critical_paths(graph: &DiGraph, k: usize) -> KPathsResult {
 
     // Process in topological order
     for &v in &order {
-        for &u in graph.predecessors_slice(v) {
+        for u in graph.blocking_predecessors(v) {
             if dist[u] + 1 > dist[v] {
                 dist[v] = dist[u] + 1;
                 pred[v] = Some(u);
@@ -82,10 +88,10 @@ pub fn k_critical_paths(graph: &DiGraph, k: usize) -> KPathsResult {
 
     // Find k nodes with longest paths
     // We prefer sinks (out-degree 0) but also consider other nodes
-    let mut candidates: Vec<(usize, usize)> = (0..n).map(|v| (v, dist[v])).collect();
+    let mut candidates: Vec<(usize, usize)> = graph.live_nodes().map(|v| (v, dist[v])).collect();
 
     // Sort by distance descending
-    candidates.sort_by(|a, b| b.1.cmp(&a.1));
+    candidates.sort_by_key(|c| std::cmp::Reverse(c.1));
     candidates.truncate(k);
 
     // Find max length
@@ -112,11 +118,31 @@ pub fn k_critical_paths(graph: &DiGraph, k: usize) -> KPathsResult {
 
     KPathsResult {
         paths,
-        total_nodes: n,
+        total_nodes: graph.node_count(),
         max_length,
+        cyclic_nodes: Vec::new(),
     }
 }
 
+/// K longest paths on the SCC condensation, so cyclic graphs still get
+/// paths. Each step of a path is a component: its `nodes` list all members
+/// of each component in order, while `length` counts components. On a DAG
+/// this equals `k_critical_paths`.
+pub fn k_critical_paths_condensed(graph: &DiGraph, k: usize) -> KPathsResult {
+    let c = condense(graph);
+    let mut result = k_critical_paths(&c.dag, k);
+    for path in &mut result.paths {
+        path.nodes = path
+            .nodes
+            .iter()
+            .flat_map(|&comp| c.members[comp].iter().copied())
+            .collect();
+    }
+    result.total_nodes = graph.node_count();
+    result.cyclic_nodes = c.cyclic_nodes();
+    result
+}
+
 /// Find k longest paths with default k=5.
 pub fn k_critical_paths_default(graph: &DiGraph) -> KPathsResult {
     k_critical_paths(graph, 5)
@@ -236,4 +262,17 @@ mod tests {
         // Should return empty for cyclic graph
         assert!(result.paths.is_empty());
     }
+
+    #[test]
+    fn test_condensed_paths_through_cycle() {
+        // 0 -> 1 <-> 2 -> 3
+        let g = make_graph(&[(0, 1), (1, 2), (2, 1), (2, 3)]);
+        let result = k_critical_paths_condensed(&g, 5);
+
+        assert_eq!(result.paths[0].nodes, vec![0, 1, 2, 3]);
+        assert_eq!(result.paths[0].length, 3); // cycle counts as one step
+        assert_eq!(result.max_length, 3);
+        assert_eq!(result.total_nodes, 4);
+        assert_eq!(result.cyclic_nodes, vec![1, 2]);
+    }
 }
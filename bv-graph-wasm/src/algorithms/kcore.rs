@@ -3,14 +3,19 @@
 //! K-core decomposition finds the maximal subgraph where every node has degree >= k.
 //! A node's core number is the highest k for which it's in the k-core.
 //! High core numbers indicate densely connected regions.
+//!
+//! The directed variant, the (k,l)-D-core, keeps edge direction: every node
+//! needs at least k blockers and at least l dependents inside the core. It
+//! separates heavily blocked clusters from heavily blocking ones, and its
+//! dense cores are groups where everything blocks everything else.
 
 use crate::graph::DiGraph;
-use std::collections::HashSet;
+use serde::Serialize;
 
 /// Compute k-core numbers for all nodes.
 ///
-/// Uses undirected view: edge u→v is treated as u--v.
-/// Employs the standard "k-peeling" algorithm.
+/// Uses undirected view: edge u→v is treated as u--v; self-loops are ignored.
+/// Employs Batagelj–Zaversnik bucket peeling, O(n + m).
 ///
 /// Returns vector of core numbers in node index order.
 pub fn kcore(graph: &DiGraph) -> Vec<u32> {
@@ -19,86 +24,157 @@ pub fn kcore(graph: &DiGraph) -> Vec<u32> {
         return Vec::new();
     }
 
-    // Build undirected degree and neighbor lists
-    // For directed graph, treat as undirected (symmetric edges)
-    let mut neighbors: Vec<HashSet<usize>> = vec![HashSet::new(); n];
+    let csr = graph.csr();
+    let degree = (0..n).map(|v| csr.undirected_neighbors(v).len()).collect();
+    peel(degree, |v| csr.undirected_neighbors(v).iter().map(|&w| w as usize))
+}
 
-    for u in 0..n {
-        for &v in graph.successors_slice(u) {
-            neighbors[u].insert(v);
-            neighbors[v].insert(u);
-        }
-    }
+/// Get the maximum core number (degeneracy of the graph).
+pub fn degeneracy(graph: &DiGraph) -> u32 {
+    kcore(graph).into_iter().max().unwrap_or(0)
+}
 
-    // Compute undirected degree
-    let mut degree: Vec<usize> = neighbors.iter().map(|s| s.len()).collect();
+/// Get nodes in the k-core (nodes with core number >= k).
+pub fn nodes_in_kcore(graph: &DiGraph, k: u32) -> Vec<usize> {
+    kcore(graph)
+        .into_iter()
+        .enumerate()
+        .filter(|&(i, c)| c >= k && graph.is_live(i))
+        .map(|(i, _)| i)
+        .collect()
+}
 
-    // Use bucket-based k-peeling (more efficient for sparse graphs)
-    let max_deg = degree.iter().cloned().max().unwrap_or(0);
+/// In-core and out-core numbers of every node.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DCoreNumbers {
+    /// Highest k such that the node is in the (k,0)-D-core
+    pub in_core: Vec<u32>,
+    /// Highest l such that the node is in the (0,l)-D-core
+    pub out_core: Vec<u32>,
+}
 
-    // Build degree buckets
-    let mut bucket: Vec<Vec<usize>> = vec![Vec::new(); max_deg + 1];
-    for i in 0..n {
-        bucket[degree[i]].push(i);
+/// Compute in-core and out-core numbers over blocking edges.
+///
+/// Self-loops are ignored. Same bucket peeling as `kcore`, once on
+/// in-degrees (removing a node lowers its dependents' in-degree) and once
+/// on out-degrees.
+pub fn dcore(graph: &DiGraph) -> DCoreNumbers {
+    let csr = graph.csr();
+    let successors = |v: usize| csr.blocking_successors(v).iter().map(|&w| w as usize).filter(move |&w| w != v);
+    let predecessors =
+        |v: usize| csr.blocking_predecessors(v).iter().map(|&u| u as usize).filter(move |&u| u != v);
+
+    let in_degree = (0..graph.len()).map(|v| predecessors(v).count()).collect();
+    let out_degree = (0..graph.len()).map(|v| successors(v).count()).collect();
+    DCoreNumbers {
+        in_core: peel(in_degree, successors),
+        out_core: peel(out_degree, predecessors),
     }
+}
 
-    let mut core = vec![0u32; n];
-    let mut processed = vec![false; n];
-    let mut current_core = 0usize;
-
-    for _ in 0..n {
-        // Find node with minimum degree (from lowest non-empty bucket)
-        while current_core <= max_deg && bucket[current_core].is_empty() {
-            current_core += 1;
+/// Nodes of the maximal (k,l)-D-core: the largest set in which every node
+/// has at least `k` blocking predecessors and `l` blocking successors.
+/// Ascending; empty when no such set exists.
+pub fn dcore_members(graph: &DiGraph, k: u32, l: u32) -> Vec<usize> {
+    let n = graph.len();
+    let csr = graph.csr();
+    let (k, l) = (k as usize, l as usize);
+    let mut alive: Vec<bool> = (0..n).map(|v| graph.is_live(v)).collect();
+    let mut in_degree = vec![0usize; n];
+    let mut out_degree = vec![0usize; n];
+    for v in graph.live_nodes() {
+        for &w in csr.blocking_successors(v) {
+            let w = w as usize;
+            if w != v {
+                out_degree[v] += 1;
+                in_degree[w] += 1;
+            }
         }
+    }
 
-        if current_core > max_deg {
-            break;
+    let mut stack: Vec<usize> = (0..n)
+        .filter(|&v| alive[v] && (in_degree[v] < k || out_degree[v] < l))
+        .collect();
+    for &v in &stack {
+        alive[v] = false;
+    }
+    while let Some(v) = stack.pop() {
+        for &w in csr.blocking_successors(v) {
+            let w = w as usize;
+            if alive[w] && w != v {
+                in_degree[w] -= 1;
+                if in_degree[w] < k {
+                    alive[w] = false;
+                    stack.push(w);
+                }
+            }
         }
-
-        // Get a node from this bucket
-        let v = bucket[current_core].pop().unwrap();
-        if processed[v] {
-            continue;
+        for &u in csr.blocking_predecessors(v) {
+            let u = u as usize;
+            if alive[u] && u != v {
+                out_degree[u] -= 1;
+                if out_degree[u] < l {
+                    alive[u] = false;
+                    stack.push(u);
+                }
+            }
         }
+    }
 
-        processed[v] = true;
-        core[v] = current_core as u32;
+    (0..n).filter(|&v| alive[v]).collect()
+}
 
-        // Update neighbors' degrees
-        for &nbr in &neighbors[v] {
-            if processed[nbr] {
-                continue;
-            }
+/// Batagelj–Zaversnik peeling of `degree`, where removing v lowers the
+/// degree of every node in `affected(v)`.
+fn peel<I: Iterator<Item = usize>>(mut degree: Vec<usize>, affected: impl Fn(usize) -> I) -> Vec<u32> {
+    let n = degree.len();
+    let max_deg = degree.iter().copied().max().unwrap_or(0);
+
+    // Counting sort of nodes by degree: `order` holds nodes in non-decreasing
+    // degree, `bin_start[d]` is the first position of degree d, `pos[v]` is
+    // v's position in `order`.
+    let mut bin_start = vec![0usize; max_deg + 1];
+    for &d in &degree {
+        bin_start[d] += 1;
+    }
+    let mut start = 0;
+    for slot in bin_start.iter_mut() {
+        let count = *slot;
+        *slot = start;
+        start += count;
+    }
+    let mut pos = vec![0usize; n];
+    let mut order = vec![0usize; n];
+    {
+        let mut next = bin_start.clone();
+        for v in 0..n {
+            pos[v] = next[degree[v]];
+            order[pos[v]] = v;
+            next[degree[v]] += 1;
+        }
+    }
 
-            let old_deg = degree[nbr];
-            if old_deg > current_core {
-                // Remove from old bucket (if still there)
-                bucket[old_deg].retain(|&x| x != nbr);
-                degree[nbr] -= 1;
-                let new_deg = degree[nbr];
-                // Add to new bucket (may go below current_core)
-                bucket[new_deg].push(nbr);
+    // Peel in order; decrementing a neighbor swaps it to the front of its bin
+    for i in 0..n {
+        let v = order[i];
+        for w in affected(v) {
+            if degree[w] > degree[v] {
+                let dw = degree[w];
+                let pw = pos[w];
+                let first = bin_start[dw];
+                let u = order[first];
+                if u != w {
+                    order.swap(pw, first);
+                    pos[u] = pw;
+                    pos[w] = first;
+                }
+                bin_start[dw] += 1;
+                degree[w] -= 1;
             }
         }
     }
 
-    core
-}
-
-/// Get the maximum core number (degeneracy of the graph).
-pub fn degeneracy(graph: &DiGraph) -> u32 {
-    kcore(graph).into_iter().max().unwrap_or(0)
-}
-
-/// Get nodes in the k-core (nodes with core number >= k).
-pub fn nodes_in_kcore(graph: &DiGraph, k: u32) -> Vec<usize> {
-    kcore(graph)
-        .into_iter()
-        .enumerate()
-        .filter(|(_, c)| *c >= k)
-        .map(|(i, _)| i)
-        .collect()
+    degree.into_iter().map(|d| d as u32).collect()
 }
 
 #[cfg(test)]
@@ -298,4 +374,46 @@ mod tests {
         // 2-core includes all nodes
         assert!(cores.iter().all(|&c| c >= 2), "All should be in 2-core");
     }
+
+    #[test]
+    fn test_dcore_cycle_with_tails() {
+        // 0, 1, 2 all block each other; 3 -> 0 feeds in, 2 -> 4 drains out
+        let mut graph = DiGraph::new();
+        let ids: Vec<usize> = (0..5).map(|i| graph.add_node(&format!("n{}", i))).collect();
+        for (a, b) in [(0, 1), (0, 2), (1, 0), (1, 2), (2, 0), (2, 1), (3, 0), (2, 4), (4, 4)] {
+            graph.add_edge(ids[a], ids[b]);
+        }
+
+        let cores = dcore(&graph);
+        assert_eq!(cores.in_core, vec![2, 2, 2, 0, 1]);
+        assert_eq!(cores.out_core, vec![2, 2, 2, 1, 0]);
+
+        assert_eq!(dcore_members(&graph, 2, 2), vec![0, 1, 2]);
+        assert_eq!(dcore_members(&graph, 0, 1), vec![0, 1, 2, 3]);
+        assert_eq!(dcore_members(&graph, 0, 0), vec![0, 1, 2, 3, 4]);
+        assert!(dcore_members(&graph, 3, 0).is_empty());
+    }
+
+    #[test]
+    fn test_dcore_matches_members() {
+        // in_core[v] >= k iff v is in the (k,0)-D-core, likewise for out-cores
+        let mut graph = DiGraph::new();
+        for i in 0..12 {
+            graph.add_node(&format!("n{}", i));
+        }
+        let mut seed = 7usize;
+        for _ in 0..40 {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345) % (1 << 31);
+            graph.add_edge(seed % 12, (seed / 12) % 12);
+        }
+        graph.remove_node(5);
+
+        let cores = dcore(&graph);
+        for k in 0..5u32 {
+            let expect: Vec<usize> = graph.live_nodes().filter(|&v| cores.in_core[v] >= k).collect();
+            assert_eq!(dcore_members(&graph, k, 0), expect);
+            let expect: Vec<usize> = graph.live_nodes().filter(|&v| cores.out_core[v] >= k).collect();
+            assert_eq!(dcore_members(&graph, 0, k), expect);
+        }
+    }
 }
@@ -4,16 +4,25 @@
 
 pub mod articulation;
 pub mod betweenness;
+pub mod components;
+pub mod condensation;
 pub mod coverage;
+pub mod cpm;
 pub mod critical_path;
 pub mod cycles;
+pub mod dominators;
 pub mod eigenvector;
+pub mod feedback_arc;
 pub mod hits;
 pub mod k_paths;
 pub mod kcore;
+pub mod monte_carlo;
 pub mod pagerank;
 pub mod parallel_cut;
+pub mod schedule;
 pub mod slack;
 pub mod subgraph;
 pub mod topo;
 pub mod topk_set;
+pub mod transitive_reduction;
+pub mod width;
@@ -0,0 +1,409 @@
+//! Monte Carlo completion forecasting from three-point estimates.
+//!
+//! Each issue gets an optimistic, a most likely and a pessimistic duration.
+//! Every sample draws one duration per issue from a triangular or PERT-beta
+//! distribution over that range, then runs the CPM forward pass over the
+//! blocking DAG: an issue starts when its last blocker finishes. Over many
+//! samples this yields completion-time percentiles ("done by day 12 with
+//! 80% confidence") for every issue and for a target set, e.g. an epic's
+//! children.
+//!
+//! A backward pass per sample marks the issues with zero float towards the
+//! target set's completion. The fraction of samples in which an issue was
+//! marked is its criticality index.
+//!
+//! Sampling is seeded, so the same inputs always give the same forecast.
+
+use crate::algorithms::topo::try_topological_sort;
+use crate::error::{GraphError, GraphResult};
+use crate::graph::DiGraph;
+use serde::{Deserialize, Serialize};
+
+/// Largest accepted sample count.
+pub const MAX_SAMPLES: usize = 10_000;
+
+/// Sample count when none is requested, lowered on large graphs to fit
+/// `MAX_SAMPLE_CELLS`.
+pub const DEFAULT_SAMPLES: usize = 1000;
+
+/// Budget for stored per-node finish times (samples x live nodes).
+pub const MAX_SAMPLE_CELLS: usize = 16_000_000;
+
+/// Duration distribution between the optimistic and pessimistic estimates.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Distribution {
+    Triangular,
+    /// Beta distribution with mean (o + 4m + p) / 6
+    #[default]
+    Pert,
+}
+
+/// Forecast options, as accepted by `DiGraph::forecast`.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct ForecastOptions {
+    pub distribution: Distribution,
+    /// Sample count; `None` means `DEFAULT_SAMPLES`, or fewer on graphs too
+    /// large for that many
+    pub samples: Option<usize>,
+    pub seed: u64,
+    /// Nodes whose completion is forecast together; empty means every node
+    pub targets: Vec<usize>,
+    /// Percentile levels in 0..=100
+    pub percentiles: Vec<f64>,
+}
+
+impl Default for ForecastOptions {
+    fn default() -> Self {
+        ForecastOptions {
+            distribution: Distribution::Pert,
+            samples: None,
+            seed: 0,
+            targets: Vec::new(),
+            percentiles: vec![50.0, 80.0, 95.0],
+        }
+    }
+}
+
+/// Result of a forecast.
+#[derive(Debug, Clone, Serialize)]
+pub struct ForecastResult {
+    pub samples: usize,
+    /// Percentile levels, as requested
+    pub percentiles: Vec<f64>,
+    /// Completion time of the whole target set at each level
+    pub target_finish: Vec<f64>,
+    /// Mean completion time of the target set
+    pub target_mean: f64,
+    /// Finish time of each slot at each level (empty for removed slots)
+    pub node_finish: Vec<Vec<f64>>,
+    /// Fraction of samples in which each slot had zero float
+    pub criticality: Vec<f64>,
+}
+
+/// Run a seeded Monte Carlo forecast. `optimistic`, `likely` and
+/// `pessimistic` hold one duration per slot; NaN and negative values count
+/// as 0, and a likely value outside the range is clamped into it.
+pub fn forecast(
+    graph: &DiGraph,
+    optimistic: &[f64],
+    likely: &[f64],
+    pessimistic: &[f64],
+    options: &ForecastOptions,
+) -> GraphResult<ForecastResult> {
+    let n = graph.len();
+    for (what, values) in [("optimistic", optimistic), ("likely", likely), ("pessimistic", pessimistic)] {
+        if values.len() != n {
+            return Err(GraphError::LengthMismatch {
+                what,
+                expected: n,
+                actual: values.len(),
+            });
+        }
+    }
+    let samples = sample_count(options.samples, graph.node_count())?;
+    for &t in &options.targets {
+        graph.check_node(t)?;
+    }
+    let order = try_topological_sort(graph)?;
+
+    let mut is_target = vec![options.targets.is_empty(); n];
+    for &t in &options.targets {
+        is_target[t] = true;
+    }
+    let estimates: Vec<Estimate> = (0..n)
+        .map(|v| Estimate::new(optimistic[v], likely[v], pessimistic[v], options.distribution))
+        .collect();
+
+    let mut rng = SplitMix64(options.seed);
+    let mut duration = vec![0.0; n];
+    let mut start = vec![0.0; n];
+    let mut finish = vec![0.0; n];
+    let mut late_start = vec![0.0; n];
+    // Finish times by position in `order`, one contiguous run per node
+    let mut finishes = vec![0f32; order.len() * samples];
+    let mut target_samples = Vec::with_capacity(samples);
+    let mut critical = vec![0usize; n];
+
+    for s in 0..samples {
+        for &v in &order {
+            duration[v] = estimates[v].sample(&mut rng);
+        }
+        let mut done: f64 = 0.0;
+        for (i, &v) in order.iter().enumerate() {
+            start[v] = graph.blocking_predecessors(v).map(|u| finish[u]).fold(0.0, f64::max);
+            finish[v] = start[v] + duration[v];
+            finishes[i * samples + s] = finish[v] as f32;
+            if is_target[v] {
+                done = done.max(finish[v]);
+            }
+        }
+        target_samples.push(done);
+
+        // Issues that cannot reach a target keep an infinite late start
+        let eps = 1e-9 * done.max(1.0);
+        for &v in order.iter().rev() {
+            let deadline = if is_target[v] { done } else { f64::INFINITY };
+            let late_finish = graph.blocking_successors(v).map(|w| late_start[w]).fold(deadline, f64::min);
+            late_start[v] = late_finish - duration[v];
+            if late_start[v] - start[v] <= eps {
+                critical[v] += 1;
+            }
+        }
+    }
+
+    let mut node_finish = vec![Vec::new(); n];
+    for (i, &v) in order.iter().enumerate() {
+        let column = &mut finishes[i * samples..(i + 1) * samples];
+        column.sort_unstable_by(f32::total_cmp);
+        node_finish[v] = options
+            .percentiles
+            .iter()
+            .map(|&p| f64::from(column[rank(p, samples)]))
+            .collect();
+    }
+    let target_mean = target_samples.iter().sum::<f64>() / samples as f64;
+    target_samples.sort_unstable_by(f64::total_cmp);
+
+    Ok(ForecastResult {
+        samples,
+        percentiles: options.percentiles.clone(),
+        target_finish: options.percentiles.iter().map(|&p| target_samples[rank(p, samples)]).collect(),
+        target_mean,
+        node_finish,
+        criticality: critical.into_iter().map(|c| c as f64 / samples as f64).collect(),
+    })
+}
+
+/// Samples to run for `live_nodes` issues. An explicit count above the
+/// storage budget fails with `LimitExceeded`; the default shrinks to fit.
+fn sample_count(requested: Option<usize>, live_nodes: usize) -> GraphResult<usize> {
+    let max_samples = MAX_SAMPLES.min(MAX_SAMPLE_CELLS / live_nodes.max(1));
+    match requested {
+        None => Ok(DEFAULT_SAMPLES.min(max_samples).max(1)),
+        Some(requested) if requested > max_samples => Err(GraphError::LimitExceeded {
+            what: "samples",
+            requested,
+            max: max_samples,
+        }),
+        Some(requested) => Ok(requested.max(1)),
+    }
+}
+
+/// Nearest-rank index of percentile `p` among `len` sorted values.
+fn rank(p: f64, len: usize) -> usize {
+    let p = if p.is_nan() { 0.0 } else { p.clamp(0.0, 100.0) };
+    ((p / 100.0 * len as f64).ceil() as usize).clamp(1, len) - 1
+}
+
+/// Cleaned three-point estimate of one issue.
+struct Estimate {
+    low: f64,
+    mode: f64,
+    high: f64,
+    distribution: Distribution,
+    /// PERT-beta shape parameters
+    alpha: f64,
+    beta: f64,
+}
+
+impl Estimate {
+    fn new(optimistic: f64, likely: f64, pessimistic: f64, distribution: Distribution) -> Estimate {
+        let clean = |x: f64| if x.is_nan() { 0.0 } else { x.max(0.0) };
+        let (a, b) = (clean(optimistic), clean(pessimistic));
+        let (low, high) = (a.min(b), a.max(b));
+        let mode = clean(likely).clamp(low, high);
+        let range = high - low;
+        let (alpha, beta) = if range > 0.0 {
+            (1.0 + 4.0 * (mode - low) / range, 1.0 + 4.0 * (high - mode) / range)
+        } else {
+            (1.0, 1.0)
+        };
+        Estimate {
+            low,
+            mode,
+            high,
+            distribution,
+            alpha,
+            beta,
+        }
+    }
+
+    fn sample(&self, rng: &mut SplitMix64) -> f64 {
+        let range = self.high - self.low;
+        if range <= 0.0 {
+            return self.low;
+        }
+        match self.distribution {
+            Distribution::Triangular => {
+                // Inverse CDF
+                let u = rng.next_f64();
+                let c = (self.mode - self.low) / range;
+                if u < c {
+                    self.low + (u * range * (self.mode - self.low)).sqrt()
+                } else {
+                    self.high - ((1.0 - u) * range * (self.high - self.mode)).sqrt()
+                }
+            }
+            Distribution::Pert => {
+                let x = rng.gamma(self.alpha);
+                let y = rng.gamma(self.beta);
+                self.low + range * x / (x + y)
+            }
+        }
+    }
+}
+
+/// SplitMix64 generator: small, fast and good enough for sampling.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform in [0, 1)
+    fn next_f64(&mut self) -> f64 {
+        (self.next_u64() >> 11) as f64 * (1.0 / (1u64 << 53) as f64)
+    }
+
+    /// Standard normal (Box–Muller)
+    fn normal(&mut self) -> f64 {
+        let u = 1.0 - self.next_f64();
+        let v = self.next_f64();
+        (-2.0 * u.ln()).sqrt() * (std::f64::consts::TAU * v).cos()
+    }
+
+    /// Gamma(shape, 1) for shape >= 1 (Marsaglia–Tsang)
+    fn gamma(&mut self, shape: f64) -> f64 {
+        let d = shape - 1.0 / 3.0;
+        let c = 1.0 / (9.0 * d).sqrt();
+        loop {
+            let x = self.normal();
+            let v = (1.0 + c * x).powi(3);
+            if v <= 0.0 {
+                continue;
+            }
+            let u = 1.0 - self.next_f64();
+            if u.ln() < 0.5 * x * x + d - d * v + d * v.ln() {
+                return d * v;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util;
+
+    #[test]
+    fn test_fixed_durations_match_cpm() {
+        // 0 -> {1, 2} -> 3 with 2 on the long branch; estimates have no spread
+        let g = test_util::graph(4, &[(0, 1), (0, 2), (1, 3), (2, 3)]);
+        let d = [2.0, 3.0, 10.0, 1.0];
+        let options = ForecastOptions {
+            samples: Some(20),
+            ..ForecastOptions::default()
+        };
+        let result = forecast(&g, &d, &d, &d, &options).unwrap();
+        assert_eq!(result.target_finish, vec![13.0, 13.0, 13.0]);
+        assert_eq!(result.node_finish[1], vec![5.0, 5.0, 5.0]);
+        assert_eq!(result.criticality, vec![1.0, 0.0, 1.0, 1.0]);
+
+        // Towards target 1 alone, only 0 -> 1 is critical
+        let options = ForecastOptions {
+            targets: vec![1],
+            ..options
+        };
+        let result = forecast(&g, &d, &d, &d, &options).unwrap();
+        assert_eq!(result.target_finish, vec![5.0, 5.0, 5.0]);
+        assert_eq!(result.criticality, vec![1.0, 1.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_sampling_is_seeded_and_bounded() {
+        // Two parallel chains into 4, so either can be critical
+        let g = test_util::graph(5, &[(0, 1), (1, 4), (2, 3), (3, 4)]);
+        let o = [1.0, 1.0, 1.0, 1.0, 1.0];
+        let m = [2.0, 2.0, 2.0, 2.0, 1.0];
+        let p = [6.0, 6.0, 6.0, 6.0, 1.0];
+        for distribution in [Distribution::Triangular, Distribution::Pert] {
+            let options = ForecastOptions {
+                distribution,
+                samples: Some(500),
+                seed: 42,
+                percentiles: vec![10.0, 50.0, 90.0],
+                ..ForecastOptions::default()
+            };
+            let a = forecast(&g, &o, &m, &p, &options).unwrap();
+            let b = forecast(&g, &o, &m, &p, &options).unwrap();
+            assert_eq!(a.target_finish, b.target_finish);
+            assert_eq!(a.criticality, b.criticality);
+
+            let t = &a.target_finish;
+            assert!(t[0] <= t[1] && t[1] <= t[2]);
+            assert!(t[0] >= 3.0 && t[2] <= 13.0);
+            assert_eq!(a.criticality[4], 1.0);
+            // Every sample has at least one critical chain
+            let share = a.criticality[0] + a.criticality[2];
+            assert!(share > 0.999 && a.criticality[0] > 0.2 && a.criticality[2] > 0.2);
+        }
+    }
+
+    #[test]
+    fn test_distribution_means() {
+        let g = test_util::graph(1, &[]);
+        let options = ForecastOptions {
+            distribution: Distribution::Pert,
+            samples: Some(10_000),
+            seed: 7,
+            ..ForecastOptions::default()
+        };
+        // PERT mean (1 + 4 * 3 + 11) / 6 = 4, triangular mean (1 + 3 + 11) / 3 = 5
+        let pert = forecast(&g, &[1.0], &[3.0], &[11.0], &options).unwrap();
+        assert!((pert.target_mean - 4.0).abs() < 0.1, "{}", pert.target_mean);
+        let options = ForecastOptions {
+            distribution: Distribution::Triangular,
+            ..options
+        };
+        let tri = forecast(&g, &[1.0], &[3.0], &[11.0], &options).unwrap();
+        assert!((tri.target_mean - 5.0).abs() < 0.1, "{}", tri.target_mean);
+    }
+
+    #[test]
+    fn test_forecast_errors() {
+        let mut g = test_util::graph(2, &[(0, 1)]);
+        let d = [1.0, 1.0];
+        let options = ForecastOptions::default();
+        assert_eq!(forecast(&g, &d, &[1.0], &d, &options).unwrap_err().code(), "LENGTH_MISMATCH");
+        let too_many = ForecastOptions {
+            samples: Some(MAX_SAMPLES + 1),
+            ..ForecastOptions::default()
+        };
+        assert_eq!(forecast(&g, &d, &d, &d, &too_many).unwrap_err().code(), "LIMIT_EXCEEDED");
+        assert_eq!(forecast(&g, &d, &d, &d, &options).unwrap().samples, DEFAULT_SAMPLES);
+        let bad_target = ForecastOptions {
+            targets: vec![5],
+            ..ForecastOptions::default()
+        };
+        assert_eq!(forecast(&g, &d, &d, &d, &bad_target).unwrap_err().code(), "INVALID_NODE");
+        g.add_edge(1, 0);
+        assert_eq!(forecast(&g, &d, &d, &d, &options).unwrap_err().code(), "CYCLIC_GRAPH");
+    }
+
+    #[test]
+    fn test_default_samples_fit_large_graphs() {
+        // 20,000 issues: the default shrinks, an explicit 1000 is rejected
+        assert_eq!(sample_count(None, 20_000), Ok(800));
+        assert_eq!(sample_count(None, 100), Ok(DEFAULT_SAMPLES));
+        assert_eq!(sample_count(None, MAX_SAMPLE_CELLS + 1), Ok(1));
+        assert_eq!(sample_count(Some(0), 100), Ok(1));
+        assert_eq!(sample_count(Some(1000), 20_000).unwrap_err().code(), "LIMIT_EXCEEDED");
+    }
+}
@@ -30,47 +30,48 @@ impl Default for PageRankConfig {
 /// Algorithm: Power iteration method
 /// PR(v) = (1-d)/n + d * Σ PR(u)/out_degree(u) for all u → v
 ///
-/// Returns vector of scores in node index order.
+/// Returns vector of scores in node index order (0 for removed nodes).
 pub fn pagerank(graph: &DiGraph, config: &PageRankConfig) -> Vec<f64> {
     let n = graph.len();
-    if n == 0 {
-        return Vec::new();
+    let live = graph.node_count();
+    if live == 0 {
+        return vec![0.0; n];
     }
 
     let d = config.damping;
-    let base = (1.0 - d) / n as f64;
+    let base = (1.0 - d) / live as f64;
 
-    // Initialize with uniform distribution
-    let mut scores = vec![1.0 / n as f64; n];
+    // Initialize with uniform distribution over live nodes
+    let mut scores: Vec<f64> = (0..n)
+        .map(|i| if graph.is_live(i) { 1.0 / live as f64 } else { 0.0 })
+        .collect();
     let mut new_scores = vec![0.0; n];
 
     // Pre-compute out-degrees
-    let out_degrees: Vec<usize> = (0..n).map(|i| graph.out_degree(i)).collect();
+    let csr = graph.csr();
+    let out_degrees: Vec<usize> = (0..n).map(|i| csr.out_degree(i)).collect();
 
     for _ in 0..config.max_iterations {
-        // Reset new scores to base value
-        for s in &mut new_scores {
-            *s = base;
-        }
-
         // Handle dangling nodes (no outgoing edges)
         // Their rank "leaks" and is distributed uniformly
-        let dangling_sum: f64 = (0..n)
+        let dangling_sum: f64 = graph
+            .live_nodes()
             .filter(|&i| out_degrees[i] == 0)
             .map(|i| scores[i])
             .sum();
-        let dangling_contrib = d * dangling_sum / n as f64;
+        let dangling_contrib = d * dangling_sum / live as f64;
 
-        // Add dangling contribution to all nodes
-        for s in &mut new_scores {
-            *s += dangling_contrib;
+        // Reset live scores to base value plus dangling contribution
+        for (i, s) in new_scores.iter_mut().enumerate() {
+            *s = if graph.is_live(i) { base + dangling_contrib } else { 0.0 };
         }
 
         // Accumulate contributions from predecessors
-        for v in 0..n {
-            for &u in graph.predecessors_slice(v) {
+        for (v, s) in new_scores.iter_mut().enumerate() {
+            for &u in csr.predecessors(v) {
+                let u = u as usize;
                 if out_degrees[u] > 0 {
-                    new_scores[v] += d * scores[u] / out_degrees[u] as f64;
+                    *s += d * scores[u] / out_degrees[u] as f64;
                 }
             }
         }
@@ -216,6 +217,23 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_pagerank_skips_removed() {
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, c);
+        graph.add_edge(b, c);
+        graph.remove_node(b);
+
+        let scores = pagerank(&graph, &PageRankConfig::default());
+        assert_eq!(scores.len(), 3);
+        assert_eq!(scores[b], 0.0);
+        let sum: f64 = scores.iter().sum();
+        assert!((sum - 1.0).abs() < 0.001);
+    }
+
     #[test]
     fn test_pagerank_dangling_nodes() {
         // a -> b, c is isolated (dangling)
@@ -1,7 +1,7 @@
 //! Parallel Cut analysis algorithm.
 //!
 //! Identifies nodes whose completion would increase opportunities for
-//! parallel work by unblocking multiple dependents.
+//! parallel work by unblocking multiple dependents (blocking edges only).
 
 use crate::graph::DiGraph;
 use serde::Serialize;
@@ -45,39 +45,39 @@ pub fn parallel_cut_suggestions(
     closed_set: &[bool],
     limit: usize,
 ) -> ParallelCutResult {
-    let n = graph.node_count();
 
     // Count current actionable nodes
-    let current_actionable = (0..n)
+    let current_actionable = graph
+        .live_nodes()
         .filter(|&v| {
             !closed_set.get(v).copied().unwrap_or(false)
                 && graph
-                    .predecessors_slice(v)
-                    .iter()
-                    .all(|&p| closed_set.get(p).copied().unwrap_or(false))
+                    .blocking_predecessors(v)
+                    .all(|p| closed_set.get(p).copied().unwrap_or(false))
         })
         .count();
 
     // Count open nodes
-    let open_nodes = (0..n)
+    let open_nodes = graph
+        .live_nodes()
         .filter(|&v| !closed_set.get(v).copied().unwrap_or(false))
         .count();
 
     // Calculate parallel gain for each open node
-    let mut suggestions: Vec<ParallelCutItem> = (0..n)
+    let mut suggestions: Vec<ParallelCutItem> = graph
+        .live_nodes()
         .filter(|&v| !closed_set.get(v).copied().unwrap_or(false))
         .map(|v| {
             // Count how many dependents would become actionable if v is closed
             let new_actionable = graph
-                .successors_slice(v)
-                .iter()
-                .filter(|&&w| {
+                .blocking_successors(v)
+                .filter(|&w| {
                     // w must be open
                     !closed_set.get(w).copied().unwrap_or(false)
                     // All of w's other predecessors must be closed
-                    && graph.predecessors_slice(w).iter()
-                        .filter(|&&p| p != v)
-                        .all(|&p| closed_set.get(p).copied().unwrap_or(false))
+                    && graph.blocking_predecessors(w)
+                        .filter(|&p| p != v)
+                        .all(|p| closed_set.get(p).copied().unwrap_or(false))
                 })
                 .count();
 
@@ -93,7 +93,7 @@ pub fn parallel_cut_suggestions(
         .collect();
 
     // Sort by parallel gain descending
-    suggestions.sort_by(|a, b| b.parallel_gain.cmp(&a.parallel_gain));
+    suggestions.sort_by_key(|s| std::cmp::Reverse(s.parallel_gain));
     suggestions.truncate(limit);
 
     ParallelCutResult {
@@ -111,28 +111,26 @@ pub fn parallel_cut_default(graph: &DiGraph, closed_set: &[bool]) -> ParallelCut
 /// Get nodes sorted by how many dependents they unblock.
 /// Unlike parallel_cut_suggestions, this includes nodes with gain <= 0.
 pub fn unblock_ranking(graph: &DiGraph, closed_set: &[bool], limit: usize) -> Vec<(usize, usize)> {
-    let n = graph.node_count();
 
-    let mut ranking: Vec<(usize, usize)> = (0..n)
+    let mut ranking: Vec<(usize, usize)> = graph
+        .live_nodes()
         .filter(|&v| !closed_set.get(v).copied().unwrap_or(false))
         .map(|v| {
             let unblocks = graph
-                .successors_slice(v)
-                .iter()
-                .filter(|&&w| {
+                .blocking_successors(v)
+                .filter(|&w| {
                     !closed_set.get(w).copied().unwrap_or(false)
                         && graph
-                            .predecessors_slice(w)
-                            .iter()
-                            .filter(|&&p| p != v)
-                            .all(|&p| closed_set.get(p).copied().unwrap_or(false))
+                            .blocking_predecessors(w)
+                            .filter(|&p| p != v)
+                            .all(|p| closed_set.get(p).copied().unwrap_or(false))
                 })
                 .count();
             (v, unblocks)
         })
         .collect();
 
-    ranking.sort_by(|a, b| b.1.cmp(&a.1));
+    ranking.sort_by_key(|r| std::cmp::Reverse(r.1));
     ranking.truncate(limit);
     ranking
 }
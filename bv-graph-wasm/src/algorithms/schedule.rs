@@ -0,0 +1,375 @@
+//! Resource-constrained list scheduling for N parallel workers.
+//!
+//! `actionable_nodes` and `parallel_cut_suggestions` say what *could* run in
+//! parallel. This module sequences the open issues onto a fixed team.
+//! Whenever a worker is free, it takes the best-ranked ready issue it may
+//! work on. An issue is ready once all its open blockers have finished.
+//! Ranking follows a `PriorityRule`, with ties broken by the longer
+//! remaining chain and then by index. Closed issues count as done.
+//!
+//! Workers can be restricted:
+//! - An issue whose assignee names a worker is pinned to that worker.
+//! - A worker with `skills` only takes issues carrying one of those labels.
+//!
+//! Issues no worker may take, and everything behind them or behind a
+//! blocking cycle, are reported as unscheduled.
+
+use crate::error::{GraphError, GraphResult};
+use crate::graph::DiGraph;
+use serde::{Deserialize, Serialize};
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap};
+
+/// How ready issues are ranked.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PriorityRule {
+    /// Longest duration-weighted chain of open dependents first
+    #[default]
+    CriticalPath,
+    /// Most open direct dependents first
+    MostUnblocks,
+    /// Most urgent `priority` first (lowest number)
+    Priority,
+}
+
+/// Restrictions on one worker.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct WorkerSpec {
+    /// Matched against issue assignees
+    pub name: String,
+    /// Labels this worker can take; empty means any issue
+    pub skills: Vec<String>,
+}
+
+/// Scheduling options, as accepted by `DiGraph::schedule`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct ScheduleOptions {
+    pub rule: PriorityRule,
+    /// Specs for the first workers; the others are unnamed generalists
+    pub team: Vec<WorkerSpec>,
+}
+
+/// One issue placed on the timeline.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ScheduledTask {
+    pub worker: usize,
+    pub node: usize,
+    pub node_id: Option<String>,
+    pub start: f64,
+    pub end: f64,
+}
+
+/// Load of one worker over the schedule.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WorkerStats {
+    pub worker: usize,
+    pub name: String,
+    pub tasks: usize,
+    pub busy: f64,
+    /// Time before the makespan spent without work
+    pub idle: f64,
+}
+
+/// Result of list scheduling.
+#[derive(Debug, Clone, Serialize)]
+pub struct ScheduleResult {
+    /// Tasks by start time, then worker
+    pub timeline: Vec<ScheduledTask>,
+    pub makespan: f64,
+    pub workers: Vec<WorkerStats>,
+    pub total_idle: f64,
+    /// Busy time over workers x makespan (0 for an empty schedule)
+    pub utilization: f64,
+    /// Open issues that could not be placed, ascending
+    pub unscheduled: Vec<usize>,
+}
+
+/// Schedule the open issues of `graph` onto `worker_count` workers (at least
+/// as many as `options.team` lists). `durations` holds one value per slot;
+/// negative and NaN durations count as 0. Fails with `InvalidArgument` when
+/// that leaves no workers.
+pub fn schedule(
+    graph: &DiGraph,
+    closed_set: &[bool],
+    durations: &[f64],
+    worker_count: usize,
+    options: &ScheduleOptions,
+) -> GraphResult<ScheduleResult> {
+    let n = graph.len();
+    if durations.len() != n {
+        return Err(GraphError::LengthMismatch {
+            what: "durations",
+            expected: n,
+            actual: durations.len(),
+        });
+    }
+    let attrs = graph.attrs();
+    let open: Vec<bool> = (0..n)
+        .map(|v| graph.is_live(v) && !closed_set.get(v).copied().unwrap_or(false))
+        .collect();
+    let duration: Vec<f64> = durations.iter().map(|&d| d.max(0.0)).collect();
+
+    let mut team: Vec<WorkerSpec> = options.team.clone();
+    team.resize_with(worker_count.max(team.len()), WorkerSpec::default);
+    if team.is_empty() {
+        return Err(GraphError::InvalidArgument {
+            what: "workers",
+            reason: "must be at least 1",
+        });
+    }
+    let by_name: HashMap<&str, usize> = team
+        .iter()
+        .enumerate()
+        .filter(|(_, w)| !w.name.is_empty())
+        .map(|(i, w)| (w.name.as_str(), i))
+        .collect();
+    let may_take = |w: usize, v: usize| match attrs.assignee(v).and_then(|a| by_name.get(a)) {
+        Some(&pinned) => pinned == w,
+        None => team[w].skills.is_empty() || team[w].skills.iter().any(|s| attrs.has_label(v, s)),
+    };
+
+    // Static ranking of every open issue
+    let tail = remaining_chain(graph, &open, &duration);
+    let unblocks: Vec<usize> = (0..n)
+        .map(|v| graph.blocking_successors(v).filter(|&w| open[w]).count())
+        .collect();
+    let primary = |a: usize, b: usize| match options.rule {
+        PriorityRule::CriticalPath => Ordering::Equal,
+        PriorityRule::MostUnblocks => unblocks[b].cmp(&unblocks[a]),
+        PriorityRule::Priority => attrs.priority(a).cmp(&attrs.priority(b)),
+    };
+    let mut ranked: Vec<usize> = (0..n).filter(|&v| open[v]).collect();
+    ranked.sort_by(|&a, &b| {
+        primary(a, b)
+            .then(tail[b].total_cmp(&tail[a]))
+            .then(a.cmp(&b))
+    });
+    let mut rank = vec![usize::MAX; n];
+    for (r, &v) in ranked.iter().enumerate() {
+        rank[v] = r;
+    }
+
+    let mut waiting: Vec<usize> = (0..n)
+        .map(|v| graph.blocking_predecessors(v).filter(|&u| open[u]).count())
+        .collect();
+    let mut ready: BinaryHeap<Reverse<usize>> =
+        ranked.iter().filter(|&&v| waiting[v] == 0).map(|&v| Reverse(rank[v])).collect();
+    let mut running: Vec<Option<(f64, usize)>> = vec![None; team.len()];
+    let mut started = vec![false; n];
+    let mut timeline = Vec::new();
+    let mut time = 0.0;
+
+    loop {
+        // Hand out ready issues, best first, to the lowest free worker allowed
+        let mut deferred = Vec::new();
+        while running.iter().any(Option::is_none) {
+            let Some(Reverse(r)) = ready.pop() else { break };
+            let v = ranked[r];
+            match (0..team.len()).find(|&w| running[w].is_none() && may_take(w, v)) {
+                Some(w) => {
+                    let end = time + duration[v];
+                    running[w] = Some((end, v));
+                    started[v] = true;
+                    timeline.push(ScheduledTask {
+                        worker: w,
+                        node: v,
+                        node_id: graph.node_id(v),
+                        start: time,
+                        end,
+                    });
+                }
+                None => deferred.push(Reverse(r)),
+            }
+        }
+        ready.extend(deferred);
+
+        // Advance to the next completion
+        let Some(next) = running.iter().flatten().map(|&(end, _)| end).reduce(f64::min) else {
+            break;
+        };
+        time = next;
+        for slot in running.iter_mut() {
+            if let Some((end, v)) = *slot {
+                if end <= time {
+                    *slot = None;
+                    for w in graph.blocking_successors(v) {
+                        if open[w] {
+                            waiting[w] -= 1;
+                            if waiting[w] == 0 {
+                                ready.push(Reverse(rank[w]));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    let makespan = timeline.iter().map(|t| t.end).fold(0.0, f64::max);
+    let mut workers: Vec<WorkerStats> = team
+        .iter()
+        .enumerate()
+        .map(|(w, spec)| WorkerStats {
+            worker: w,
+            name: spec.name.clone(),
+            tasks: 0,
+            busy: 0.0,
+            idle: makespan,
+        })
+        .collect();
+    for task in &timeline {
+        let stats = &mut workers[task.worker];
+        stats.tasks += 1;
+        stats.busy += task.end - task.start;
+        stats.idle = makespan - stats.busy;
+    }
+    let total_busy: f64 = workers.iter().map(|w| w.busy).sum();
+    let capacity = makespan * workers.len() as f64;
+    timeline.sort_by(|a, b| a.start.total_cmp(&b.start).then(a.worker.cmp(&b.worker)));
+
+    Ok(ScheduleResult {
+        timeline,
+        makespan,
+        total_idle: capacity - total_busy,
+        utilization: if capacity > 0.0 { total_busy / capacity } else { 0.0 },
+        workers,
+        unscheduled: (0..n).filter(|&v| open[v] && !started[v]).collect(),
+    })
+}
+
+/// Longest duration-weighted chain of open issues starting at each open
+/// issue, itself included. Issues on or feeding a cycle only count the
+/// part of their chain that can be ordered.
+fn remaining_chain(graph: &DiGraph, open: &[bool], duration: &[f64]) -> Vec<f64> {
+    let n = graph.len();
+    let mut tail = vec![0.0; n];
+    let mut pending: Vec<usize> = (0..n)
+        .map(|v| graph.blocking_successors(v).filter(|&w| open[w]).count())
+        .collect();
+    let mut stack: Vec<usize> = (0..n).filter(|&v| open[v] && pending[v] == 0).collect();
+    while let Some(v) = stack.pop() {
+        tail[v] += duration[v];
+        for u in graph.blocking_predecessors(v) {
+            if open[u] {
+                tail[u] = f64::max(tail[u], tail[v]);
+                pending[u] -= 1;
+                if pending[u] == 0 {
+                    stack.push(u);
+                }
+            }
+        }
+    }
+    tail
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util;
+
+    fn placed(result: &ScheduleResult) -> Vec<(usize, usize, f64, f64)> {
+        result.timeline.iter().map(|t| (t.worker, t.node, t.start, t.end)).collect()
+    }
+
+    #[test]
+    fn test_two_workers_critical_path_first() {
+        // 0 -> 1 -> 2 is the long chain; 3 and 4 are independent short tasks
+        let g = test_util::graph(5, &[(0, 1), (1, 2)]);
+        let d = [2.0, 2.0, 2.0, 1.0, 1.0];
+        let result = schedule(&g, &[], &d, 2, &ScheduleOptions::default()).unwrap();
+        assert_eq!(
+            placed(&result),
+            vec![(0, 0, 0.0, 2.0), (1, 3, 0.0, 1.0), (1, 4, 1.0, 2.0), (0, 1, 2.0, 4.0), (0, 2, 4.0, 6.0)]
+        );
+        assert_eq!(result.makespan, 6.0);
+        assert_eq!(result.workers[1].idle, 4.0);
+        assert_eq!(result.total_idle, 4.0);
+        assert!((result.utilization - 8.0 / 12.0).abs() < 1e-12);
+        assert!(result.unscheduled.is_empty());
+    }
+
+    #[test]
+    fn test_rules_change_the_order() {
+        // 0 unblocks three issues, 4 is most urgent, 5 -> 6 -> 7 is the longest chain
+        let mut g = test_util::graph(8, &[(0, 1), (0, 2), (0, 3), (5, 6), (6, 7)]);
+        for v in 0..8 {
+            g.set_priority(v, 3);
+        }
+        g.set_priority(4, 0);
+        let d = [1.0; 8];
+        let first = |rule| {
+            let options = ScheduleOptions {
+                rule,
+                ..ScheduleOptions::default()
+            };
+            schedule(&g, &[], &d, 1, &options).unwrap().timeline[0].node
+        };
+        assert_eq!(first(PriorityRule::CriticalPath), 5);
+        assert_eq!(first(PriorityRule::MostUnblocks), 0);
+        assert_eq!(first(PriorityRule::Priority), 4);
+    }
+
+    #[test]
+    fn test_pins_skills_and_closed() {
+        // 0 is closed, so 1 is ready at once. ann takes "ui", bob takes "api";
+        // 3 is pinned to bob without the label and nobody takes "db".
+        let mut g = test_util::graph(5, &[(0, 1)]);
+        g.set_labels(1, vec!["api".to_string()]);
+        g.set_labels(2, vec!["ui".to_string()]);
+        g.set_assignee(3, "bob");
+        g.set_labels(4, vec!["db".to_string()]);
+        let closed = [true, false, false, false, false];
+        let options = ScheduleOptions {
+            rule: PriorityRule::CriticalPath,
+            team: vec![
+                WorkerSpec {
+                    name: "ann".to_string(),
+                    skills: vec!["ui".to_string()],
+                },
+                WorkerSpec {
+                    name: "bob".to_string(),
+                    skills: vec!["api".to_string()],
+                },
+            ],
+        };
+        let d = [5.0, 1.0, 1.0, 1.0, 1.0];
+        let result = schedule(&g, &closed, &d, 2, &options).unwrap();
+        assert_eq!(placed(&result), vec![(0, 2, 0.0, 1.0), (1, 1, 0.0, 1.0), (1, 3, 1.0, 2.0)]);
+        assert_eq!(result.unscheduled, vec![4]);
+        assert_eq!(result.workers[1].tasks, 2);
+        assert_eq!(result.workers[0].idle, 1.0);
+        assert_eq!(result.makespan, 2.0);
+
+        assert_eq!(
+            schedule(&g, &closed, &[1.0], 2, &options).unwrap_err().code(),
+            "LENGTH_MISMATCH"
+        );
+    }
+
+    #[test]
+    fn test_zero_workers_rejected() {
+        let g = test_util::graph(2, &[(0, 1)]);
+        let err = schedule(&g, &[], &[1.0; 2], 0, &ScheduleOptions::default()).unwrap_err();
+        assert_eq!(err.code(), "INVALID_ARGUMENT");
+        assert_eq!(err.to_string(), "INVALID_ARGUMENT: workers must be at least 1");
+
+        // A team alone supplies the workers
+        let options = ScheduleOptions {
+            team: vec![WorkerSpec::default()],
+            ..Default::default()
+        };
+        assert_eq!(schedule(&g, &[], &[1.0; 2], 0, &options).unwrap().makespan, 2.0);
+    }
+
+    #[test]
+    fn test_cycle_members_unscheduled() {
+        // 0 -> 1 <-> 2 -> 3; only 0 can run
+        let g = test_util::graph(4, &[(0, 1), (1, 2), (2, 1), (2, 3)]);
+        let result = schedule(&g, &[], &[1.0; 4], 3, &ScheduleOptions::default()).unwrap();
+        assert_eq!(placed(&result), vec![(0, 0, 0.0, 1.0)]);
+        assert_eq!(result.unscheduled, vec![1, 2, 3]);
+    }
+}
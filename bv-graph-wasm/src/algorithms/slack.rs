@@ -4,10 +4,12 @@
 //! the overall project completion time (critical path length).
 //! Nodes with zero slack are on the critical path.
 
-use crate::algorithms::topo::topological_sort;
+use crate::algorithms::condensation::{condense, CondensedValues};
+use crate::algorithms::topo::try_topological_sort;
+use crate::error::GraphResult;
 use crate::graph::DiGraph;
 
-/// Compute slack for each node in a DAG.
+/// Compute slack for each node in a DAG (blocking edges only).
 ///
 /// Slack = (critical path length) - (longest path through this node)
 ///
@@ -20,27 +22,29 @@ use crate::graph::DiGraph;
 /// 4. Slack = max_path_length - (forward + backward distances)
 ///
 /// # Returns
-/// Vector of slack values indexed by node. Returns zeros for cyclic graphs.
+/// Vector of slack values indexed by node. Returns zeros for cyclic graphs;
+/// use `try_slack` to detect that case.
 pub fn slack(graph: &DiGraph) -> Vec<f64> {
+    try_slack(graph).unwrap_or_else(|_| vec![0.0; graph.len()])
+}
+
+/// Compute slack, failing with `GraphError::CyclicGraph` if the blocking
+/// graph has a cycle.
+pub fn try_slack(graph: &DiGraph) -> GraphResult<Vec<f64>> {
     let n = graph.len();
     if n == 0 {
-        return Vec::new();
+        return Ok(Vec::new());
     }
 
-    // Get topological order (None if cyclic)
-    let order = match topological_sort(graph) {
-        Some(o) => o,
-        None => return vec![0.0; n], // Return zeros for cyclic graphs
-    };
+    let order = try_topological_sort(graph)?;
 
     // Forward pass: longest distance from any start (nodes with no predecessors)
     // dist_from_start[v] = length of longest path from any root to v
     let mut dist_from_start = vec![0usize; n];
     for &v in &order {
         let max_pred = graph
-            .predecessors_slice(v)
-            .iter()
-            .map(|&u| dist_from_start[u])
+            .blocking_predecessors(v)
+            .map(|u| dist_from_start[u])
             .max()
             .unwrap_or(0);
         dist_from_start[v] = max_pred + 1;
@@ -51,9 +55,8 @@ pub fn slack(graph: &DiGraph) -> Vec<f64> {
     let mut dist_to_end = vec![0usize; n];
     for &v in order.iter().rev() {
         let max_succ = graph
-            .successors_slice(v)
-            .iter()
-            .map(|&w| dist_to_end[w])
+            .blocking_successors(v)
+            .map(|w| dist_to_end[w])
             .max()
             .unwrap_or(0);
         dist_to_end[v] = max_succ + 1;
@@ -62,18 +65,31 @@ pub fn slack(graph: &DiGraph) -> Vec<f64> {
     // Find the longest path length in the entire graph
     // longest_path_length = max(dist_from_start[i] + dist_to_end[i] - 1) for all i
     // (we subtract 1 because node v is counted in both distances)
-    let longest_path: usize = (0..n)
-        .map(|i| dist_from_start[i] + dist_to_end[i] - 1)
+    let longest_path: usize = order
+        .iter()
+        .map(|&i| dist_from_start[i] + dist_to_end[i] - 1)
         .max()
         .unwrap_or(0);
 
-    // Slack = longest_path - (dist_from_start + dist_to_end - 1)
-    (0..n)
-        .map(|i| {
-            let path_through_i = dist_from_start[i] + dist_to_end[i] - 1;
-            (longest_path - path_through_i) as f64
-        })
-        .collect()
+    // Slack = longest_path - (dist_from_start + dist_to_end - 1); removed nodes get 0
+    let mut slacks = vec![0.0; n];
+    for &i in &order {
+        let path_through_i = dist_from_start[i] + dist_to_end[i] - 1;
+        slacks[i] = (longest_path - path_through_i) as f64;
+    }
+    Ok(slacks)
+}
+
+/// Slack on the SCC condensation, so cyclic graphs still get slack values.
+/// A cycle counts as one step and all its members share the component's
+/// slack. On a DAG this equals `slack`.
+pub fn slack_condensed(graph: &DiGraph) -> CondensedValues {
+    let c = condense(graph);
+    let slacks = slack(&c.dag);
+    CondensedValues {
+        values: c.expand(&slacks, 0.0),
+        cyclic_nodes: c.cyclic_nodes(),
+    }
 }
 
 /// Get nodes with zero slack (on the critical path).
@@ -82,7 +98,7 @@ pub fn zero_slack_nodes(graph: &DiGraph) -> Vec<usize> {
     slacks
         .iter()
         .enumerate()
-        .filter_map(|(i, &s)| if s < 0.001 { Some(i) } else { None })
+        .filter_map(|(i, &s)| if s < 0.001 && graph.is_live(i) { Some(i) } else { None })
         .collect()
 }
 
@@ -252,6 +268,13 @@ mod tests {
 
         let s = slack(&graph);
         assert_eq!(s, vec![0.0, 0.0, 0.0]);
+
+        match try_slack(&graph) {
+            Err(crate::error::GraphError::CyclicGraph { nodes }) => {
+                assert_eq!(nodes, vec![a, b, c])
+            }
+            other => panic!("expected CyclicGraph, got {:?}", other),
+        }
     }
 
     #[test]
@@ -292,6 +315,25 @@ mod tests {
         assert_eq!(tf, 2.0);
     }
 
+    #[test]
+    fn test_slack_removed_node() {
+        // a -> b -> c, d -> c; removing b leaves a isolated
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(d, c);
+        graph.remove_node(b);
+
+        let s = slack(&graph);
+        assert_eq!(s[a], 1.0);
+        assert_eq!(s[b], 0.0);
+        assert_eq!(zero_slack_nodes(&graph), vec![c, d]);
+    }
+
     #[test]
     fn test_slack_non_negative() {
         // Various graph structures should never produce negative slack
@@ -318,4 +360,28 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn test_slack_condensed_with_cycle() {
+        // a -> b <-> c -> d, a -> e -> d
+        // Condensed: a -> {b,c} -> d is as long as a -> e -> d, so all slack is 0
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        let e = graph.add_node("e");
+        let f = graph.add_node("f");
+        graph.add_edge(a, b);
+        graph.add_edge(b, c);
+        graph.add_edge(c, b);
+        graph.add_edge(c, d);
+        graph.add_edge(a, e);
+        graph.add_edge(e, d);
+        graph.add_edge(a, f);
+
+        let result = slack_condensed(&graph);
+        assert_eq!(result.values, vec![0.0, 0.0, 0.0, 0.0, 0.0, 1.0]);
+        assert_eq!(result.cyclic_nodes, vec![b, c]);
+    }
 }
@@ -11,7 +11,8 @@ use std::collections::HashMap;
 ///
 /// Creates a new DiGraph with:
 /// - Only the nodes at the given indices
-/// - Only edges that connect nodes within the subset
+/// - Only edges that connect nodes within the subset (kinds preserved)
+/// - The attributes of each retained node
 ///
 /// # Arguments
 /// * `graph` - The source graph
@@ -19,7 +20,8 @@ use std::collections::HashMap;
 ///
 /// # Returns
 /// New DiGraph containing only the specified nodes and their interconnecting edges.
-/// Node indices in the new graph are renumbered 0..n.
+/// Node indices in the new graph are renumbered 0..n in input order, one per
+/// distinct live index, even when two nodes share an ID.
 pub fn extract_subgraph(graph: &DiGraph, node_indices: &[usize]) -> DiGraph {
     let n = graph.len();
     if node_indices.is_empty() || n == 0 {
@@ -29,12 +31,15 @@ pub fn extract_subgraph(graph: &DiGraph, node_indices: &[usize]) -> DiGraph {
     // Create mapping: old index -> new index
     let mut index_map: HashMap<usize, usize> = HashMap::with_capacity(node_indices.len());
     let mut new_graph = DiGraph::with_capacity(node_indices.len(), node_indices.len() * 2);
+    // A fresh graph is not strict, so any mask is accepted
+    let _ = new_graph.set_blocking_kinds_checked(graph.blocking_kinds());
 
     // Add nodes to new graph
     for &old_idx in node_indices {
-        if old_idx < n {
+        if old_idx < n && !index_map.contains_key(&old_idx) {
             if let Some(id) = graph.node_id(old_idx) {
-                let new_idx = new_graph.add_node(&id);
+                let new_idx = new_graph.push_slot(&id);
+                new_graph.attrs_mut().copy_node(new_idx, graph.attrs(), old_idx);
                 index_map.insert(old_idx, new_idx);
             }
         }
@@ -45,7 +50,7 @@ pub fn extract_subgraph(graph: &DiGraph, node_indices: &[usize]) -> DiGraph {
         if let Some(&new_from) = index_map.get(&old_from) {
             for &old_to in graph.successors_slice(old_from) {
                 if let Some(&new_to) = index_map.get(&old_to) {
-                    new_graph.add_edge(new_from, new_to);
+                    new_graph.add_edge_mask(new_from, new_to, graph.edge_kinds(old_from, old_to));
                 }
             }
         }
@@ -74,10 +79,10 @@ pub fn reachable_subgraph_from(graph: &DiGraph, source: usize) -> DiGraph {
 /// Get nodes reachable from a source node (outgoing direction).
 ///
 /// Uses BFS to find all nodes that can be reached by following
-/// outgoing edges from the source.
+/// outgoing blocking edges from the source.
 pub fn reachable_from(graph: &DiGraph, source: usize) -> Vec<usize> {
     let n = graph.len();
-    if source >= n {
+    if !graph.is_live(source) {
         return Vec::new();
     }
 
@@ -90,7 +95,7 @@ pub fn reachable_from(graph: &DiGraph, source: usize) -> Vec<usize> {
     queue.push_back(source);
 
     while let Some(v) = queue.pop_front() {
-        for &w in graph.successors_slice(v) {
+        for w in graph.blocking_successors(v) {
             if !visited[w] {
                 visited[w] = true;
                 result.push(w);
@@ -105,10 +110,10 @@ pub fn reachable_from(graph: &DiGraph, source: usize) -> Vec<usize> {
 /// Get nodes that can reach a target node (incoming direction).
 ///
 /// Uses BFS on reverse edges to find all nodes that can reach
-/// the target by following outgoing edges.
+/// the target by following outgoing blocking edges.
 pub fn reachable_to(graph: &DiGraph, target: usize) -> Vec<usize> {
     let n = graph.len();
-    if target >= n {
+    if !graph.is_live(target) {
         return Vec::new();
     }
 
@@ -121,7 +126,7 @@ pub fn reachable_to(graph: &DiGraph, target: usize) -> Vec<usize> {
     queue.push_back(target);
 
     while let Some(v) = queue.pop_front() {
-        for &w in graph.predecessors_slice(v) {
+        for w in graph.blocking_predecessors(v) {
             if !visited[w] {
                 visited[w] = true;
                 result.push(w);
@@ -34,7 +34,8 @@ pub struct TopKSetResult {
 ///
 /// Iteratively selects nodes that maximize transitive unblocks.
 /// Each iteration simulates closing the best node and updates
-/// the closed set for the next iteration.
+/// the closed set for the next iteration. Equal gains are broken by
+/// node priority, then by node index.
 ///
 /// # Arguments
 /// * `graph` - The directed dependency graph
@@ -62,7 +63,7 @@ pub fn topk_set(graph: &DiGraph, closed_set: &[bool], k: usize) -> TopKSetResult
     current_closed.resize(n, false);
 
     // Count open nodes
-    let open_nodes = (0..n).filter(|&i| !current_closed[i]).count();
+    let open_nodes = graph.live_nodes().filter(|&i| !current_closed[i]).count();
 
     let mut selected = Vec::new();
     let mut total_gain = 0;
@@ -74,16 +75,17 @@ pub fn topk_set(graph: &DiGraph, closed_set: &[bool], k: usize) -> TopKSetResult
         let mut best_unblocked: Vec<usize> = Vec::new();
 
         // Collect candidates (non-closed nodes)
-        let mut candidates: Vec<usize> = (0..n).filter(|&i| !current_closed[i]).collect();
-        // Sort for determinism when gains are equal
-        candidates.sort_unstable();
+        let candidates: Vec<usize> = graph.live_nodes().filter(|&i| !current_closed[i]).collect();
 
         for node in candidates {
             let result = what_if_close(graph, node, &current_closed);
             let gain = result.transitive_unblocks;
 
-            // Prefer higher gain, or lower node index for determinism
-            if gain > best_gain || (gain == best_gain && best_node.is_none()) {
+            // Prefer higher gain, then more urgent priority, then lower node index
+            let more_urgent = best_node.is_none_or(|best| {
+                graph.priority_or_default(node) < graph.priority_or_default(best)
+            });
+            if gain > best_gain || (gain == best_gain && more_urgent) {
                 best_gain = gain;
                 best_node = Some(node);
                 best_unblocked = result.cascade_ids;
@@ -257,6 +259,16 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_priority_tiebreak() {
+        // 0 -> 1, 2 -> 3 (both gain 1), node 2 is P0
+        let mut g = make_graph(&[(0, 1), (2, 3)]);
+        g.set_priority(2, 0);
+        let result = topk_set(&g, &[false; 4], 5);
+        assert_eq!(result.items[0].node, 2);
+        assert_eq!(result.items[1].node, 0);
+    }
+
     #[test]
     fn test_monotonic_marginal_gains() {
         // Submodularity: marginal gains should be non-increasing
@@ -1,8 +1,11 @@
 //! Topological Sort using Kahn's algorithm.
 //!
-//! Orders nodes such that for every edge u→v, u comes before v.
+//! Orders nodes such that for every blocking edge u→v, u comes before v.
 //! Essential for execution planning and critical path analysis.
 
+use crate::algorithms::condensation::{condense, CondensedOrder};
+use crate::algorithms::cycles::cyclic_graph_error;
+use crate::error::GraphResult;
 use crate::graph::DiGraph;
 use std::cmp::Reverse;
 use std::collections::BinaryHeap;
@@ -18,7 +21,7 @@ pub struct TopoSortResult {
 /// Topological sort using Kahn's algorithm with deterministic ordering.
 ///
 /// Uses a min-heap to ensure consistent output across runs.
-/// Returns None if the graph contains cycles.
+/// Returns None if the graph contains cycles. Removed nodes are not included.
 ///
 /// # Arguments
 /// * `graph` - The directed graph to sort
@@ -33,10 +36,11 @@ pub fn topological_sort(graph: &DiGraph) -> Option<Vec<usize>> {
     }
 
     // Compute in-degrees
-    let mut in_degree: Vec<usize> = (0..n).map(|i| graph.in_degree(i)).collect();
+    let mut in_degree: Vec<usize> = (0..n).map(|i| graph.blocking_in_degree(i)).collect();
 
     // Min-heap for deterministic ordering (process lowest index first)
-    let mut heap: BinaryHeap<Reverse<usize>> = (0..n)
+    let mut heap: BinaryHeap<Reverse<usize>> = graph
+        .live_nodes()
         .filter(|&i| in_degree[i] == 0)
         .map(Reverse)
         .collect();
@@ -46,7 +50,7 @@ pub fn topological_sort(graph: &DiGraph) -> Option<Vec<usize>> {
     while let Some(Reverse(u)) = heap.pop() {
         order.push(u);
 
-        for &v in graph.successors_slice(u) {
+        for v in graph.blocking_successors(u) {
             in_degree[v] -= 1;
             if in_degree[v] == 0 {
                 heap.push(Reverse(v));
@@ -54,13 +58,33 @@ pub fn topological_sort(graph: &DiGraph) -> Option<Vec<usize>> {
         }
     }
 
-    if order.len() == n {
+    if order.len() == graph.node_count() {
         Some(order)
     } else {
         None // Cycle detected
     }
 }
 
+/// Topological sort that reports cycles as `GraphError::CyclicGraph`.
+pub fn try_topological_sort(graph: &DiGraph) -> GraphResult<Vec<usize>> {
+    topological_sort(graph).ok_or_else(|| cyclic_graph_error(graph))
+}
+
+/// Topological order that tolerates cycles: sorts the SCC condensation and
+/// lists each component's members together. On a DAG this equals
+/// `topological_sort`.
+pub fn topological_sort_condensed(graph: &DiGraph) -> CondensedOrder {
+    let c = condense(graph);
+    let order = topological_sort(&c.dag).unwrap_or_default();
+    CondensedOrder {
+        order: order
+            .into_iter()
+            .flat_map(|comp| c.members[comp].iter().copied())
+            .collect(),
+        cyclic_nodes: c.cyclic_nodes(),
+    }
+}
+
 /// Check if the graph is a DAG (directed acyclic graph).
 ///
 /// A graph is a DAG if and only if it has a valid topological order.
@@ -82,6 +106,8 @@ pub fn topological_sort_result(graph: &DiGraph) -> TopoSortResult {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::GraphError;
+    use crate::graph::EdgeKind;
 
     #[test]
     fn test_empty_graph() {
@@ -153,6 +179,52 @@ mod tests {
         assert!(result.is_none());
     }
 
+    #[test]
+    fn test_try_cycle_reports_nodes() {
+        // a -> b -> a, b -> c: c is downstream but not on the cycle
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        g.add_edge(a, b);
+        g.add_edge(b, a);
+        g.add_edge(b, c);
+
+        match try_topological_sort(&g) {
+            Err(GraphError::CyclicGraph { nodes }) => assert_eq!(nodes, vec![a, b]),
+            other => panic!("expected CyclicGraph, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_removed_nodes_skipped() {
+        // a -> b -> c, remove b
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        g.add_edge(a, b);
+        g.add_edge(b, c);
+        g.remove_node(b);
+
+        assert_eq!(topological_sort(&g), Some(vec![a, c]));
+    }
+
+    #[test]
+    fn test_related_cycle_ignored() {
+        // a -blocks-> b, b -related-> a: not a blocking cycle
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        g.add_edge(a, b);
+        g.add_edge_kind(b, a, EdgeKind::Related);
+
+        assert_eq!(topological_sort(&g), Some(vec![a, b]));
+
+        g.set_blocking_kinds_checked(crate::graph::ALL_KINDS).unwrap();
+        assert!(topological_sort(&g).is_none());
+    }
+
     #[test]
     fn test_self_loop() {
         // a -> a
@@ -229,4 +301,27 @@ mod tests {
         assert_eq!(result1, result2);
         assert_eq!(result2, result3);
     }
+
+    #[test]
+    fn test_condensed_order_with_cycle() {
+        // a -> b -> c -> b, c -> d
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        let d = g.add_node("d");
+        g.add_edge(a, b);
+        g.add_edge(b, c);
+        g.add_edge(c, b);
+        g.add_edge(c, d);
+        assert_eq!(topological_sort(&g), None);
+
+        let result = topological_sort_condensed(&g);
+        assert_eq!(result.order, vec![a, b, c, d]);
+        assert_eq!(result.cyclic_nodes, vec![b, c]);
+
+        // Same as the plain sort on a DAG
+        g.remove_edge(c, b);
+        assert_eq!(Some(topological_sort_condensed(&g).order), topological_sort(&g));
+    }
 }
@@ -0,0 +1,222 @@
+//! Transitive reduction of the blocking graph.
+//!
+//! A blocking edge u -> v is redundant when another path u -> w -> ... -> v
+//! already implies it. Redundant edges inflate path-counting metrics
+//! (betweenness, coverage, parallel-cut gains) without changing what blocks
+//! what, so the viewer can suggest removing them.
+//!
+//! The reduction runs on the SCC condensation (see `condensation`): each
+//! component's successors are searched in topological order, and one that
+//! an earlier successor already reached is redundant. Every node edge behind
+//! a redundant condensed edge is redundant, as is every edge but the first
+//! between the same two components. The implying path is the shortest
+//! other path between the endpoints. Edges inside a cycle are never
+//! reported: the reduction of a strongly connected component is not unique.
+
+use crate::algorithms::condensation::condense;
+use crate::algorithms::topo::topological_sort;
+use crate::graph::DiGraph;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet, VecDeque};
+
+/// A blocking edge implied by a longer path.
+#[derive(Debug, Clone, Serialize)]
+pub struct RedundantEdge {
+    pub from: usize,
+    pub to: usize,
+    /// Implying path from -> ... -> to (at least three nodes)
+    pub path: Vec<usize>,
+    pub from_id: Option<String>,
+    pub to_id: Option<String>,
+}
+
+/// Result of transitive reduction.
+#[derive(Debug, Clone, Serialize)]
+pub struct TransitiveReductionResult {
+    /// Redundant edges, sorted by (from, to)
+    pub redundant: Vec<RedundantEdge>,
+    /// Blocking edges left after removing the redundant ones
+    pub kept_edges: usize,
+    /// Nodes on blocking cycles, whose internal edges were left alone
+    pub cyclic_nodes: Vec<usize>,
+}
+
+/// Find the redundant blocking edges of `graph`.
+pub fn transitive_reduction(graph: &DiGraph) -> TransitiveReductionResult {
+    let cond = condense(graph);
+    let m = cond.len();
+    let mut comp_rank = vec![0; m];
+    for (r, c) in topological_sort(&cond.dag)
+        .unwrap_or_default()
+        .into_iter()
+        .enumerate()
+    {
+        comp_rank[c] = r;
+    }
+    let mut comp_succ: Vec<Vec<usize>> = vec![Vec::new(); m];
+    for &(c, d) in &cond.edges {
+        comp_succ[c].push(d);
+    }
+
+    // Redundant condensed edges: a successor already reached from an earlier
+    // one. stamp[d] == c + 1 once d was reached while processing c.
+    let mut implied: HashSet<(usize, usize)> = HashSet::new();
+    let mut stamp = vec![0usize; m];
+    let mut stack = Vec::new();
+    for c in 0..m {
+        let mut succ = comp_succ[c].clone();
+        succ.sort_unstable_by_key(|&d| comp_rank[d]);
+        let horizon = succ.last().map_or(0, |&d| comp_rank[d]);
+        for &d in &succ {
+            if stamp[d] == c + 1 {
+                implied.insert((c, d));
+                continue;
+            }
+            stamp[d] = c + 1;
+            stack.push(d);
+            while let Some(x) = stack.pop() {
+                for &y in &comp_succ[x] {
+                    // Components ranked after every successor cannot imply an edge
+                    if stamp[y] != c + 1 && comp_rank[y] <= horizon {
+                        stamp[y] = c + 1;
+                        stack.push(y);
+                    }
+                }
+            }
+        }
+    }
+
+    // Map back to node edges. Between two components only one edge is
+    // needed; the others are implied through the components' cycles.
+    let rank = |v: usize| cond.component[v].map_or(usize::MAX, |c| comp_rank[c]);
+    let mut linked: HashSet<(usize, usize)> = HashSet::new();
+    let mut redundant = Vec::new();
+    let mut blocking_edges = 0;
+    for u in graph.live_nodes() {
+        let mut succ: Vec<usize> = graph.blocking_successors(u).collect();
+        blocking_edges += succ.len();
+        succ.sort_unstable();
+        for v in succ {
+            let (Some(c), Some(d)) = (cond.component[u], cond.component[v]) else {
+                continue;
+            };
+            if c == d || (!implied.contains(&(c, d)) && linked.insert((c, d))) {
+                continue;
+            }
+            if let Some(path) = implying_path(graph, u, v, |w| rank(w) <= rank(v)) {
+                redundant.push(RedundantEdge {
+                    from: u,
+                    to: v,
+                    path,
+                    from_id: graph.node_id(u),
+                    to_id: graph.node_id(v),
+                });
+            }
+        }
+    }
+
+    TransitiveReductionResult {
+        kept_edges: blocking_edges - redundant.len(),
+        redundant,
+        cyclic_nodes: cond.cyclic_nodes(),
+    }
+}
+
+/// Shortest blocking path from -> ... -> to that avoids the direct edge,
+/// searching only nodes accepted by `within`.
+fn implying_path(
+    graph: &DiGraph,
+    from: usize,
+    to: usize,
+    within: impl Fn(usize) -> bool,
+) -> Option<Vec<usize>> {
+    let mut parent: HashMap<usize, usize> = HashMap::from([(from, from)]);
+    let mut queue = VecDeque::from([from]);
+    while let Some(x) = queue.pop_front() {
+        for w in graph.blocking_successors(x) {
+            if (x == from && w == to) || parent.contains_key(&w) || !within(w) {
+                continue;
+            }
+            parent.insert(w, x);
+            if w == to {
+                let mut path = vec![to];
+                let mut y = to;
+                while y != from {
+                    y = parent[&y];
+                    path.push(y);
+                }
+                path.reverse();
+                return Some(path);
+            }
+            queue.push_back(w);
+        }
+    }
+    None
+}
+
+/// Copy of `graph` with the blocking kinds cleared from every redundant
+/// edge. Node indices, attributes and non-blocking edges are unchanged.
+pub fn reduced_graph(graph: &DiGraph) -> DiGraph {
+    let result = transitive_reduction(graph);
+    let mut reduced = graph.clone();
+    let blocking = graph.blocking_kinds();
+    for e in &result.redundant {
+        let kinds = graph.edge_kinds(e.from, e.to);
+        reduced.set_edge_mask(e.from, e.to, kinds & !blocking);
+    }
+    reduced
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util;
+    use crate::graph::EdgeKind;
+    use crate::reachability::reachable_from;
+
+    #[test]
+    fn test_shortcut_edges() {
+        // 0 -> 1 -> 2 -> 3 with shortcuts 0 -> 2 and 0 -> 3
+        let g = test_util::graph(4, &[(0, 3), (0, 2), (0, 1), (1, 2), (2, 3)]);
+        let result = transitive_reduction(&g);
+        let found: Vec<(usize, usize, Vec<usize>)> = result
+            .redundant
+            .iter()
+            .map(|e| (e.from, e.to, e.path.clone()))
+            .collect();
+        assert_eq!(found, vec![(0, 2, vec![0, 1, 2]), (0, 3, vec![0, 2, 3])]);
+        assert_eq!(result.kept_edges, 3);
+    }
+
+    #[test]
+    fn test_cycles_keep_internal_edges() {
+        // 0 <-> 1 form a cycle and both reach 2; only the first edge into 2 is kept
+        let g = test_util::graph(3, &[(0, 1), (1, 0), (0, 2), (1, 2)]);
+        let result = transitive_reduction(&g);
+        assert_eq!(result.redundant.len(), 1);
+        assert_eq!(result.redundant[0].path, vec![1, 0, 2]);
+        assert_eq!(result.cyclic_nodes, vec![0, 1]);
+    }
+
+    #[test]
+    fn test_reduced_graph_preserves_reachability() {
+        let edges = [
+            (0, 1), (0, 2), (0, 4), (1, 3), (2, 3), (3, 4), (1, 4), (4, 5), (5, 4), (2, 5), (0, 5),
+        ];
+        let mut g = test_util::graph(6, &edges);
+        g.add_edge_kind(0, 4, EdgeKind::Related);
+        let reduced = reduced_graph(&g);
+        let closure = |g: &DiGraph, v: usize| {
+            let mut r = reachable_from(g, v);
+            r.sort_unstable();
+            r
+        };
+        for v in 0..6 {
+            assert_eq!(closure(&g, v), closure(&reduced, v));
+        }
+        assert!(reduced.edge_count() < g.edge_count());
+        // The related link on a redundant edge survives
+        assert_eq!(reduced.edge_kinds(0, 4), EdgeKind::Related.bit());
+        assert!(transitive_reduction(&reduced).redundant.is_empty());
+    }
+}
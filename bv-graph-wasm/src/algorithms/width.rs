@@ -0,0 +1,320 @@
+//! Maximum parallelism width (Dilworth's theorem).
+//!
+//! "How many agents can usefully work on this backlog at once?" The answer
+//! is the width of the open subgraph: the size of its largest antichain,
+//! i.e. the largest set of open issues with no blocking path between any
+//! two of them. By Dilworth's theorem it equals the smallest number of
+//! chains covering every open issue. Each chain is an ordered work queue,
+//! so the cover reads directly as "one agent per chain".
+//!
+//! The cover comes from a maximum bipartite matching (Hopcroft–Karp) on the
+//! transitive closure: matching u to v means v follows u in the same chain.
+//! The antichain follows from the matching by König's theorem. Closed
+//! issues are dropped first. Each blocking cycle among the open issues
+//! acts as one element: its members sit together in one chain, and at most
+//! one of them (the smallest) joins the antichain.
+
+use crate::algorithms::condensation::condense;
+use crate::algorithms::subgraph::extract_subgraph;
+use crate::algorithms::topo::topological_sort;
+use crate::error::{GraphError, GraphResult};
+use crate::graph::DiGraph;
+use serde::Serialize;
+use std::collections::VecDeque;
+
+/// Largest number of open components (cycles count as one) accepted; the
+/// closure takes components² bits.
+pub const WIDTH_MAX_COMPONENTS: usize = 10_000;
+
+const NONE: usize = usize::MAX;
+
+/// Width of the open subgraph with a witness on both sides.
+#[derive(Debug, Clone, Serialize)]
+pub struct WidthResult {
+    /// Size of the largest antichain, which equals the number of chains
+    pub width: usize,
+    /// Mutually independent open issues, ascending
+    pub antichain: Vec<usize>,
+    /// Minimum chain cover, each chain in blocking order. Consecutive issues
+    /// are ordered by a blocking path but need not share an edge.
+    pub chains: Vec<Vec<usize>>,
+    pub open_nodes: usize,
+    /// Open issues on blocking cycles among open issues, ascending
+    pub cyclic_nodes: Vec<usize>,
+}
+
+/// Compute the width, a maximum antichain and a minimum chain cover of the
+/// open issues. `closed_set[v]` marks closed (or otherwise resolved) nodes.
+pub fn parallel_width(graph: &DiGraph, closed_set: &[bool]) -> GraphResult<WidthResult> {
+    let open: Vec<usize> = graph
+        .live_nodes()
+        .filter(|&v| !closed_set.get(v).copied().unwrap_or(false))
+        .collect();
+    // Node i of the subgraph is open[i], also when open issues share an ID
+    let sub = extract_subgraph(graph, &open);
+    let cond = condense(&sub);
+    let m = cond.len();
+    if m > WIDTH_MAX_COMPONENTS {
+        return Err(GraphError::LimitExceeded {
+            what: "open components",
+            requested: m,
+            max: WIDTH_MAX_COMPONENTS,
+        });
+    }
+
+    // Transitive closure, one bit row per component
+    let words = m.div_ceil(64);
+    let mut closure = vec![0u64; m * words];
+    let mut succ: Vec<Vec<usize>> = vec![Vec::new(); m];
+    for &(c, d) in &cond.edges {
+        succ[c].push(d);
+    }
+    for &c in topological_sort(&cond.dag).unwrap_or_default().iter().rev() {
+        for &d in &succ[c] {
+            closure[c * words + d / 64] |= 1 << (d % 64);
+            for i in 0..words {
+                let reach = closure[d * words + i];
+                closure[c * words + i] |= reach;
+            }
+        }
+    }
+    let row = |c: usize| &closure[c * words..(c + 1) * words];
+
+    let (mate_l, mate_r) = hopcroft_karp(m, row);
+
+    // Chains start at components nothing is matched into
+    let mut chains: Vec<Vec<usize>> = (0..m)
+        .filter(|&c| mate_r[c] == NONE)
+        .map(|start| {
+            let mut chain = Vec::new();
+            let mut c = start;
+            loop {
+                chain.extend(cond.members[c].iter().map(|&v| open[v]));
+                if mate_l[c] == NONE {
+                    break;
+                }
+                c = mate_l[c];
+            }
+            chain
+        })
+        .collect();
+    chains.sort_unstable_by_key(|chain| chain[0]);
+
+    // König: alternating search from unmatched left vertices. Components
+    // reached on the left but not on the right form a maximum antichain.
+    let mut seen_l = vec![false; m];
+    let mut seen_r = vec![false; m];
+    let mut queue: VecDeque<usize> = (0..m).filter(|&c| mate_l[c] == NONE).collect();
+    for &c in &queue {
+        seen_l[c] = true;
+    }
+    while let Some(c) = queue.pop_front() {
+        for_each_bit(row(c), |d| {
+            if !seen_r[d] && mate_l[c] != d {
+                seen_r[d] = true;
+                let w = mate_r[d];
+                if w != NONE && !seen_l[w] {
+                    seen_l[w] = true;
+                    queue.push_back(w);
+                }
+            }
+        });
+    }
+    let antichain: Vec<usize> = (0..m)
+        .filter(|&c| seen_l[c] && !seen_r[c])
+        .map(|c| open[cond.members[c][0]])
+        .collect();
+
+    Ok(WidthResult {
+        width: chains.len(),
+        antichain,
+        chains,
+        open_nodes: open.len(),
+        cyclic_nodes: cond.cyclic_nodes().into_iter().map(|v| open[v]).collect(),
+    })
+}
+
+/// Maximum matching between left and right copies of `0..m`, where left u
+/// links to right v if bit v of `row(u)` is set. Returns the mate of every
+/// left and every right vertex (`NONE` if unmatched).
+fn hopcroft_karp<'a>(m: usize, row: impl Fn(usize) -> &'a [u64]) -> (Vec<usize>, Vec<usize>) {
+    let mut mate_l = vec![NONE; m];
+    let mut mate_r = vec![NONE; m];
+    let mut dist = vec![NONE; m];
+    loop {
+        // Layer the left vertices by alternating distance from the free ones
+        let mut queue = VecDeque::new();
+        for u in 0..m {
+            dist[u] = if mate_l[u] == NONE { 0 } else { NONE };
+            if dist[u] == 0 {
+                queue.push_back(u);
+            }
+        }
+        let mut found = false;
+        while let Some(u) = queue.pop_front() {
+            for_each_bit(row(u), |v| match mate_r[v] {
+                NONE => found = true,
+                w if dist[w] == NONE => {
+                    dist[w] = dist[u] + 1;
+                    queue.push_back(w);
+                }
+                _ => {}
+            });
+        }
+        if !found {
+            return (mate_l, mate_r);
+        }
+
+        // Vertex-disjoint shortest augmenting paths, by iterative DFS. Each
+        // frame keeps the next right vertex to try; the one before it is the
+        // current choice.
+        for u in 0..m {
+            if mate_l[u] != NONE {
+                continue;
+            }
+            let mut stack: Vec<(usize, usize)> = vec![(u, 0)];
+            while let Some(&mut (x, ref mut next)) = stack.last_mut() {
+                let Some(v) = next_bit(row(x), *next) else {
+                    dist[x] = NONE;
+                    stack.pop();
+                    continue;
+                };
+                *next = v + 1;
+                let w = mate_r[v];
+                if w == NONE {
+                    for &(x, next) in &stack {
+                        mate_l[x] = next - 1;
+                        mate_r[next - 1] = x;
+                    }
+                    break;
+                }
+                if dist[w] == dist[x] + 1 {
+                    stack.push((w, 0));
+                }
+            }
+        }
+    }
+}
+
+/// Call `f` with the index of every set bit.
+fn for_each_bit(row: &[u64], mut f: impl FnMut(usize)) {
+    for (i, &word) in row.iter().enumerate() {
+        let mut bits = word;
+        while bits != 0 {
+            f(i * 64 + bits.trailing_zeros() as usize);
+            bits &= bits - 1;
+        }
+    }
+}
+
+/// First set bit at or after `from`.
+fn next_bit(row: &[u64], from: usize) -> Option<usize> {
+    let mut i = from / 64;
+    let mut word = *row.get(i)? & (!0u64 << (from % 64));
+    loop {
+        if word != 0 {
+            return Some(i * 64 + word.trailing_zeros() as usize);
+        }
+        i += 1;
+        word = *row.get(i)?;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util;
+    use crate::reachability::reachable_from;
+
+    /// Checks the witnesses: chains cover every open node once and follow
+    /// blocking paths; antichain members are pairwise unreachable.
+    fn assert_valid(g: &DiGraph, closed: &[bool], result: &WidthResult) {
+        let open: Vec<usize> = g.live_nodes().filter(|&v| !closed.get(v).copied().unwrap_or(false)).collect();
+        let sub = extract_subgraph(g, &open);
+        let pos = |v: usize| open.binary_search(&v).unwrap();
+        let reaches = |a: usize, b: usize| reachable_from(&sub, pos(a)).contains(&pos(b));
+
+        let mut covered: Vec<usize> = result.chains.iter().flatten().copied().collect();
+        covered.sort_unstable();
+        assert_eq!(covered, open);
+        for chain in &result.chains {
+            for pair in chain.windows(2) {
+                assert!(reaches(pair[0], pair[1]), "{:?}", chain);
+            }
+        }
+        assert_eq!(result.antichain.len(), result.width);
+        for &a in &result.antichain {
+            for &b in &result.antichain {
+                assert!(a == b || !reaches(a, b), "{} reaches {}", a, b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_width_of_diamond_and_chain() {
+        // 0 -> {1, 2, 3} -> 4, plus 5 -> 6 on the side
+        let g = test_util::graph(7, &[(0, 1), (0, 2), (0, 3), (1, 4), (2, 4), (3, 4), (5, 6)]);
+        let result = parallel_width(&g, &[]).unwrap();
+        assert_eq!(result.width, 4);
+        assert_eq!(result.antichain.len(), 4);
+        assert_valid(&g, &[], &result);
+
+        // Closing 0 and 4 leaves the middle layer and the side chain
+        let mut closed = vec![false; 7];
+        closed[0] = true;
+        closed[4] = true;
+        let result = parallel_width(&g, &closed).unwrap();
+        assert_eq!(result.width, 4);
+        assert_eq!(&result.antichain[..3], &[1, 2, 3]);
+        assert_eq!(result.chains, vec![vec![1], vec![2], vec![3], vec![5, 6]]);
+    }
+
+    #[test]
+    fn test_chains_skip_through_closure() {
+        // 0 -> 2, 1 -> 2, 2 -> 3, 2 -> 4: disjoint paths need three agents, but
+        // a chain may pass over 2 while another chain does it: 0, 2, 3 and 1, 4
+        let g = test_util::graph(5, &[(0, 2), (1, 2), (2, 3), (2, 4)]);
+        let result = parallel_width(&g, &[]).unwrap();
+        assert_eq!(result.width, 2);
+        assert_valid(&g, &[], &result);
+    }
+
+    #[test]
+    fn test_repeated_ids_keep_their_own_slots() {
+        // Bulk-built graph where 1 and 2 share an ID: 0 -> 1, 2 -> 3
+        let g = DiGraph::try_from_arrays(&["a", "b", "b", "c"], &[0, 2], &[1, 3]).unwrap();
+        let mut closed = vec![false; 4];
+        closed[0] = true;
+        let result = parallel_width(&g, &closed).unwrap();
+        assert_eq!(result.width, 2);
+        assert_eq!(result.chains, vec![vec![1], vec![2, 3]]);
+        assert_eq!(result.open_nodes, 3);
+    }
+
+    #[test]
+    fn test_cycles_and_random_graphs() {
+        // 0 <-> 1 -> 2; 3 alone
+        let g = test_util::graph(4, &[(0, 1), (1, 0), (1, 2)]);
+        let result = parallel_width(&g, &[]).unwrap();
+        assert_eq!(result.width, 2);
+        assert_eq!(result.chains, vec![vec![0, 1, 2], vec![3]]);
+        assert_eq!(result.antichain.len(), 2);
+        assert_eq!(result.cyclic_nodes, vec![0, 1]);
+
+        let mut seed = 3usize;
+        for _ in 0..5 {
+            let mut edges = Vec::new();
+            for _ in 0..30 {
+                seed = seed.wrapping_mul(1103515245).wrapping_add(12345) % (1 << 31);
+                let (a, b) = (seed % 16, (seed / 16) % 16);
+                if a < b {
+                    edges.push((a, b));
+                }
+            }
+            let g = test_util::graph(16, &edges);
+            let closed: Vec<bool> = (0..16).map(|v| v % 5 == 0).collect();
+            let result = parallel_width(&g, &closed).unwrap();
+            assert_valid(&g, &closed, &result);
+        }
+    }
+}
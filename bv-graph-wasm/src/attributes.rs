@@ -0,0 +1,581 @@
+//! Per-node issue attributes stored column-wise.
+//!
+//! Status, priority, estimate, type, assignee, labels and due date live in
+//! parallel vectors indexed by node, so algorithms can rank and filter issues
+//! without joining results back to issue data in JS. Repeated strings
+//! (types, assignees, labels) are interned into a shared string table.
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use wasm_bindgen::prelude::*;
+
+/// Default beads priority (P2, "medium").
+pub const DEFAULT_PRIORITY: i32 = 2;
+
+/// Issue status (mirrors beads `Status`).
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+#[repr(u8)]
+pub enum IssueStatus {
+    Open = 0,
+    InProgress = 1,
+    Blocked = 2,
+    /// Deliberately put on ice for later
+    Deferred = 3,
+    /// Persistent bead that stays open indefinitely
+    Pinned = 4,
+    /// Work attached to an agent's hook
+    Hooked = 5,
+    /// Awaiting review before completion
+    Review = 6,
+    Closed = 7,
+    /// Soft-deleted issue
+    Tombstone = 8,
+}
+
+impl IssueStatus {
+    /// Parse a beads status string.
+    pub fn parse(status: &str) -> Option<IssueStatus> {
+        match status {
+            "open" => Some(IssueStatus::Open),
+            "in_progress" => Some(IssueStatus::InProgress),
+            "blocked" => Some(IssueStatus::Blocked),
+            "deferred" => Some(IssueStatus::Deferred),
+            "pinned" => Some(IssueStatus::Pinned),
+            "hooked" => Some(IssueStatus::Hooked),
+            "review" => Some(IssueStatus::Review),
+            "closed" => Some(IssueStatus::Closed),
+            "tombstone" => Some(IssueStatus::Tombstone),
+            _ => None,
+        }
+    }
+
+    /// Convert from the `repr(u8)` value used in typed arrays.
+    pub fn from_u8(value: u8) -> Option<IssueStatus> {
+        match value {
+            0 => Some(IssueStatus::Open),
+            1 => Some(IssueStatus::InProgress),
+            2 => Some(IssueStatus::Blocked),
+            3 => Some(IssueStatus::Deferred),
+            4 => Some(IssueStatus::Pinned),
+            5 => Some(IssueStatus::Hooked),
+            6 => Some(IssueStatus::Review),
+            7 => Some(IssueStatus::Closed),
+            8 => Some(IssueStatus::Tombstone),
+            _ => None,
+        }
+    }
+
+    /// True once the issue no longer blocks anything (closed or tombstoned).
+    pub fn is_resolved(self) -> bool {
+        matches!(self, IssueStatus::Closed | IssueStatus::Tombstone)
+    }
+}
+
+/// Interned string storage shared by the string-valued columns.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct StringTable {
+    strings: Vec<String>,
+    index: HashMap<String, u32>,
+}
+
+impl StringTable {
+    /// Return the id for `s`, inserting it if needed.
+    pub(crate) fn intern(&mut self, s: &str) -> u32 {
+        if let Some(&id) = self.index.get(s) {
+            return id;
+        }
+        let id = self.strings.len() as u32;
+        self.strings.push(s.to_string());
+        self.index.insert(s.to_string(), id);
+        id
+    }
+
+    /// Look up an existing id without inserting.
+    pub(crate) fn lookup(&self, s: &str) -> Option<u32> {
+        self.index.get(s).copied()
+    }
+
+    /// Resolve an id back to its string.
+    pub(crate) fn resolve(&self, id: u32) -> &str {
+        &self.strings[id as usize]
+    }
+}
+
+/// Column-oriented attribute store, one entry per node.
+#[derive(Debug, Default, Clone)]
+pub struct NodeAttributes {
+    status: Vec<IssueStatus>,
+    priority: Vec<i32>,
+    estimated_minutes: Vec<Option<u32>>,
+    issue_type: Vec<Option<u32>>,
+    assignee: Vec<Option<u32>>,
+    labels: Vec<Vec<u32>>,
+    /// Due date as Unix epoch milliseconds
+    due_date: Vec<Option<f64>>,
+    strings: StringTable,
+}
+
+impl NodeAttributes {
+    /// Append default attributes for a newly added node.
+    pub(crate) fn push_default(&mut self) {
+        self.status.push(IssueStatus::Open);
+        self.priority.push(DEFAULT_PRIORITY);
+        self.estimated_minutes.push(None);
+        self.issue_type.push(None);
+        self.assignee.push(None);
+        self.labels.push(Vec::new());
+        self.due_date.push(None);
+    }
+
+    /// Number of nodes with attribute rows.
+    pub fn len(&self) -> usize {
+        self.status.len()
+    }
+
+    /// True if no rows are stored.
+    pub fn is_empty(&self) -> bool {
+        self.status.is_empty()
+    }
+
+    pub fn status(&self, node: usize) -> Option<IssueStatus> {
+        self.status.get(node).copied()
+    }
+
+    pub fn set_status(&mut self, node: usize, status: IssueStatus) {
+        if let Some(slot) = self.status.get_mut(node) {
+            *slot = status;
+        }
+    }
+
+    /// Statuses column.
+    pub fn statuses(&self) -> &[IssueStatus] {
+        &self.status
+    }
+
+    pub fn priority(&self, node: usize) -> Option<i32> {
+        self.priority.get(node).copied()
+    }
+
+    pub fn set_priority(&mut self, node: usize, priority: i32) {
+        if let Some(slot) = self.priority.get_mut(node) {
+            *slot = priority;
+        }
+    }
+
+    /// Priorities column (lower number = more urgent).
+    pub fn priorities(&self) -> &[i32] {
+        &self.priority
+    }
+
+    pub fn estimated_minutes(&self, node: usize) -> Option<u32> {
+        self.estimated_minutes.get(node).copied().flatten()
+    }
+
+    pub fn set_estimated_minutes(&mut self, node: usize, minutes: Option<u32>) {
+        if let Some(slot) = self.estimated_minutes.get_mut(node) {
+            *slot = minutes;
+        }
+    }
+
+    pub fn issue_type(&self, node: usize) -> Option<&str> {
+        self.issue_type
+            .get(node)
+            .copied()
+            .flatten()
+            .map(|id| self.strings.resolve(id))
+    }
+
+    pub fn set_issue_type(&mut self, node: usize, issue_type: Option<&str>) {
+        if node < self.issue_type.len() {
+            self.issue_type[node] = non_empty(issue_type).map(|s| self.strings.intern(s));
+        }
+    }
+
+    pub fn assignee(&self, node: usize) -> Option<&str> {
+        self.assignee
+            .get(node)
+            .copied()
+            .flatten()
+            .map(|id| self.strings.resolve(id))
+    }
+
+    pub fn set_assignee(&mut self, node: usize, assignee: Option<&str>) {
+        if node < self.assignee.len() {
+            self.assignee[node] = non_empty(assignee).map(|s| self.strings.intern(s));
+        }
+    }
+
+    pub fn labels(&self, node: usize) -> Vec<&str> {
+        self.labels.get(node).map_or_else(Vec::new, |ids| {
+            ids.iter().map(|&id| self.strings.resolve(id)).collect()
+        })
+    }
+
+    pub fn set_labels<S: AsRef<str>>(&mut self, node: usize, labels: &[S]) {
+        if node >= self.labels.len() {
+            return;
+        }
+        let mut ids: Vec<u32> = labels
+            .iter()
+            .map(|l| l.as_ref())
+            .filter(|l| !l.is_empty())
+            .map(|l| self.strings.intern(l))
+            .collect();
+        ids.sort_unstable();
+        ids.dedup();
+        self.labels[node] = ids;
+    }
+
+    /// Check whether a node carries a label.
+    pub fn has_label(&self, node: usize, label: &str) -> bool {
+        match (self.strings.lookup(label), self.labels.get(node)) {
+            (Some(id), Some(ids)) => ids.binary_search(&id).is_ok(),
+            _ => false,
+        }
+    }
+
+    pub fn due_date(&self, node: usize) -> Option<f64> {
+        self.due_date.get(node).copied().flatten()
+    }
+
+    pub fn set_due_date(&mut self, node: usize, epoch_ms: Option<f64>) {
+        if let Some(slot) = self.due_date.get_mut(node) {
+            *slot = epoch_ms.filter(|ms| ms.is_finite());
+        }
+    }
+
+    /// Closed set derived from status: 1 for closed or tombstoned issues.
+    pub fn resolved_mask(&self) -> Vec<bool> {
+        self.status.iter().map(|s| s.is_resolved()).collect()
+    }
+
+    /// Apply a parsed attribute record to a node. Fields absent from the record are left unchanged.
+    pub(crate) fn apply(&mut self, node: usize, record: &AttributeRecord) {
+        if let Some(status) = record.status.as_deref().and_then(IssueStatus::parse) {
+            self.set_status(node, status);
+        }
+        if let Some(priority) = record.priority {
+            self.set_priority(node, priority);
+        }
+        if let Some(minutes) = record.estimated_minutes {
+            self.set_estimated_minutes(node, u32::try_from(minutes).ok());
+        }
+        if record.issue_type.is_some() {
+            self.set_issue_type(node, record.issue_type.as_deref());
+        }
+        if record.assignee.is_some() {
+            self.set_assignee(node, record.assignee.as_deref());
+        }
+        if let Some(labels) = &record.labels {
+            self.set_labels(node, labels);
+        }
+        if let Some(due) = &record.due_date {
+            self.set_due_date(node, due.epoch_ms());
+        }
+    }
+
+    /// Copy one node's attributes from another store (re-interning strings).
+    pub(crate) fn copy_node(&mut self, dst: usize, other: &NodeAttributes, src: usize) {
+        if src >= other.len() || dst >= self.len() {
+            return;
+        }
+        self.status[dst] = other.status[src];
+        self.priority[dst] = other.priority[src];
+        self.estimated_minutes[dst] = other.estimated_minutes[src];
+        self.set_issue_type(dst, other.issue_type(src));
+        self.set_assignee(dst, other.assignee(src));
+        self.set_labels(dst, &other.labels(src));
+        self.due_date[dst] = other.due_date[src];
+    }
+}
+
+fn non_empty(s: Option<&str>) -> Option<&str> {
+    s.filter(|s| !s.is_empty())
+}
+
+/// Due date as found in JSON: an RFC 3339 string or epoch milliseconds.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub(crate) enum DueDate {
+    Millis(f64),
+    Text(String),
+}
+
+impl DueDate {
+    pub(crate) fn epoch_ms(&self) -> Option<f64> {
+        match self {
+            DueDate::Millis(ms) => Some(*ms),
+            DueDate::Text(s) => parse_rfc3339_ms(s),
+        }
+    }
+}
+
+/// Attribute fields of a beads issue, as accepted by `setAttributesJson`.
+#[derive(Debug, Clone, Default, Deserialize)]
+pub(crate) struct AttributeRecord {
+    #[serde(default)]
+    pub id: String,
+    #[serde(default)]
+    pub status: Option<String>,
+    #[serde(default)]
+    pub priority: Option<i32>,
+    #[serde(default)]
+    pub estimated_minutes: Option<i64>,
+    #[serde(default)]
+    pub issue_type: Option<String>,
+    #[serde(default)]
+    pub assignee: Option<String>,
+    #[serde(default)]
+    pub labels: Option<Vec<String>>,
+    #[serde(default)]
+    pub due_date: Option<DueDate>,
+}
+
+/// Attribute filter accepted by `filterNodes`. Every present field must match.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct NodeFilter {
+    /// Allowed statuses
+    pub statuses: Option<Vec<IssueStatus>>,
+    /// Inclusive priority bounds
+    pub min_priority: Option<i32>,
+    pub max_priority: Option<i32>,
+    /// Allowed issue types
+    pub issue_types: Option<Vec<String>>,
+    /// Allowed assignees
+    pub assignees: Option<Vec<String>>,
+    /// Node must carry at least one of these labels
+    pub labels_any: Option<Vec<String>>,
+    /// Node must carry all of these labels
+    pub labels_all: Option<Vec<String>>,
+    /// Only nodes with an estimate (true) or without one (false)
+    pub has_estimate: Option<bool>,
+    /// Only nodes due strictly before this epoch-millisecond timestamp
+    pub due_before: Option<f64>,
+}
+
+impl NodeFilter {
+    /// Indices of all nodes matching the filter.
+    pub fn apply(&self, attrs: &NodeAttributes) -> Vec<usize> {
+        (0..attrs.len()).filter(|&i| self.matches(attrs, i)).collect()
+    }
+
+    /// Check a single node against the filter.
+    pub fn matches(&self, attrs: &NodeAttributes, node: usize) -> bool {
+        if node >= attrs.len() {
+            return false;
+        }
+        if let Some(statuses) = &self.statuses {
+            if !statuses.contains(&attrs.status[node]) {
+                return false;
+            }
+        }
+        let priority = attrs.priority[node];
+        if self.min_priority.is_some_and(|min| priority < min)
+            || self.max_priority.is_some_and(|max| priority > max)
+        {
+            return false;
+        }
+        if let Some(types) = &self.issue_types {
+            match attrs.issue_type(node) {
+                Some(t) if types.iter().any(|x| x == t) => {}
+                _ => return false,
+            }
+        }
+        if let Some(assignees) = &self.assignees {
+            match attrs.assignee(node) {
+                Some(a) if assignees.iter().any(|x| x == a) => {}
+                _ => return false,
+            }
+        }
+        if let Some(any) = &self.labels_any {
+            if !any.iter().any(|l| attrs.has_label(node, l)) {
+                return false;
+            }
+        }
+        if let Some(all) = &self.labels_all {
+            if !all.iter().all(|l| attrs.has_label(node, l)) {
+                return false;
+            }
+        }
+        if let Some(has) = self.has_estimate {
+            if attrs.estimated_minutes(node).is_some() != has {
+                return false;
+            }
+        }
+        if let Some(before) = self.due_before {
+            match attrs.due_date(node) {
+                Some(due) if due < before => {}
+                _ => return false,
+            }
+        }
+        true
+    }
+}
+
+/// Parse an RFC 3339 timestamp (or bare `YYYY-MM-DD` date) into epoch milliseconds.
+pub(crate) fn parse_rfc3339_ms(s: &str) -> Option<f64> {
+    let s = s.trim();
+    let b = s.as_bytes();
+    if b.len() < 10 || b[4] != b'-' || b[7] != b'-' {
+        return None;
+    }
+    let num = |range: std::ops::Range<usize>| -> Option<i64> { s.get(range)?.parse().ok() };
+    let (year, month, day) = (num(0..4)?, num(5..7)?, num(8..10)?);
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    let days = days_from_civil(year, month, day);
+    if b.len() == 10 {
+        return Some((days * 86_400_000) as f64);
+    }
+    if b.len() < 19 || !matches!(b[10], b'T' | b't' | b' ') {
+        return None;
+    }
+    let (hour, minute, second) = (num(11..13)?, num(14..16)?, num(17..19)?);
+
+    // Optional fractional seconds
+    let mut pos = 19;
+    let mut frac_ms = 0.0;
+    if b.get(pos) == Some(&b'.') {
+        let start = pos + 1;
+        pos = start;
+        while pos < b.len() && b[pos].is_ascii_digit() {
+            pos += 1;
+        }
+        frac_ms = format!("0.{}", &s[start..pos]).parse::<f64>().ok()? * 1000.0;
+    }
+
+    // Offset: Z or ±HH:MM
+    let offset_min = match b.get(pos) {
+        None | Some(b'Z') | Some(b'z') => 0,
+        Some(&sign @ (b'+' | b'-')) => {
+            let oh = num(pos + 1..pos + 3)?;
+            let om = num(pos + 4..pos + 6)?;
+            let total = oh * 60 + om;
+            if sign == b'-' {
+                -total
+            } else {
+                total
+            }
+        }
+        _ => return None,
+    };
+
+    let secs = days * 86_400 + hour * 3600 + minute * 60 + second - offset_min * 60;
+    Some(secs as f64 * 1000.0 + frac_ms)
+}
+
+/// Days since 1970-01-01 for a proleptic Gregorian date (Howard Hinnant's algorithm).
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = if y >= 0 { y } else { y - 399 } / 400;
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attrs_with(n: usize) -> NodeAttributes {
+        let mut attrs = NodeAttributes::default();
+        for _ in 0..n {
+            attrs.push_default();
+        }
+        attrs
+    }
+
+    #[test]
+    fn test_defaults() {
+        let attrs = attrs_with(2);
+        assert_eq!(attrs.status(0), Some(IssueStatus::Open));
+        assert_eq!(attrs.priority(1), Some(DEFAULT_PRIORITY));
+        assert_eq!(attrs.estimated_minutes(0), None);
+        assert!(attrs.labels(0).is_empty());
+        assert_eq!(attrs.status(5), None);
+    }
+
+    #[test]
+    fn test_labels_interned_and_deduped() {
+        let mut attrs = attrs_with(2);
+        attrs.set_labels(0, &["backend", "auth", "backend"]);
+        attrs.set_labels(1, &["auth"]);
+        assert_eq!(attrs.labels(0).len(), 2);
+        assert!(attrs.has_label(0, "backend"));
+        assert!(attrs.has_label(1, "auth"));
+        assert!(!attrs.has_label(1, "backend"));
+        assert!(!attrs.has_label(1, "unknown"));
+    }
+
+    #[test]
+    fn test_apply_record() {
+        let mut attrs = attrs_with(1);
+        let record: AttributeRecord = serde_json::from_str(
+            r#"{"id":"bv-1","status":"in_progress","priority":0,"estimated_minutes":90,
+                "issue_type":"bug","assignee":"alice","labels":["ui"],
+                "due_date":"2026-01-02T00:00:00Z"}"#,
+        )
+        .unwrap();
+        attrs.apply(0, &record);
+
+        assert_eq!(attrs.status(0), Some(IssueStatus::InProgress));
+        assert_eq!(attrs.priority(0), Some(0));
+        assert_eq!(attrs.estimated_minutes(0), Some(90));
+        assert_eq!(attrs.issue_type(0), Some("bug"));
+        assert_eq!(attrs.assignee(0), Some("alice"));
+        assert_eq!(attrs.labels(0), vec!["ui"]);
+        assert_eq!(attrs.due_date(0), Some(1_767_312_000_000.0));
+    }
+
+    #[test]
+    fn test_resolved_mask() {
+        let mut attrs = attrs_with(3);
+        attrs.set_status(0, IssueStatus::Closed);
+        attrs.set_status(1, IssueStatus::Tombstone);
+        assert_eq!(attrs.resolved_mask(), vec![true, true, false]);
+    }
+
+    #[test]
+    fn test_filter() {
+        let mut attrs = attrs_with(3);
+        attrs.set_priority(0, 0);
+        attrs.set_labels(0, &["auth", "backend"]);
+        attrs.set_labels(1, &["auth"]);
+        attrs.set_status(2, IssueStatus::Closed);
+        attrs.set_issue_type(1, Some("bug"));
+
+        let filter: NodeFilter =
+            serde_json::from_str(r#"{"statuses":["open","in_progress"]}"#).unwrap();
+        assert_eq!(filter.apply(&attrs), vec![0, 1]);
+
+        let filter: NodeFilter = serde_json::from_str(r#"{"labels_all":["auth","backend"]}"#).unwrap();
+        assert_eq!(filter.apply(&attrs), vec![0]);
+
+        let filter: NodeFilter = serde_json::from_str(r#"{"max_priority":1}"#).unwrap();
+        assert_eq!(filter.apply(&attrs), vec![0]);
+
+        let filter: NodeFilter = serde_json::from_str(r#"{"issue_types":["bug"]}"#).unwrap();
+        assert_eq!(filter.apply(&attrs), vec![1]);
+    }
+
+    #[test]
+    fn test_parse_rfc3339() {
+        assert_eq!(parse_rfc3339_ms("1970-01-01T00:00:00Z"), Some(0.0));
+        assert_eq!(parse_rfc3339_ms("1970-01-02"), Some(86_400_000.0));
+        assert_eq!(
+            parse_rfc3339_ms("1970-01-01T01:00:00.5+01:00"),
+            Some(500.0)
+        );
+        assert_eq!(
+            parse_rfc3339_ms("2026-02-18T22:21:18.762706+01:00"),
+            parse_rfc3339_ms("2026-02-18T21:21:18.762706Z")
+        );
+        assert_eq!(parse_rfc3339_ms("not a date"), None);
+    }
+}
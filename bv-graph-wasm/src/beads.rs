@@ -0,0 +1,422 @@
+//! Loader for beads `.beads/issues.jsonl` exports.
+//!
+//! Each line is one issue object. Issues become nodes (with their attributes
+//! applied), and every `dependencies[]` entry becomes a typed edge from the
+//! `depends_on_id` issue to the issue that declares it (blocker -> dependent).
+//! Problems are never fatal: malformed lines, duplicate IDs, unknown statuses
+//! and references to unknown issues are collected into a `BeadsLoadReport`
+//! with line numbers.
+
+use crate::attributes::{AttributeRecord, IssueStatus};
+use crate::error::to_js;
+use crate::graph::{DiGraph, EdgeKind};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use wasm_bindgen::prelude::*;
+
+/// Options for `DiGraph::fromBeadsJsonl`.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct BeadsLoadOptions {
+    /// Keep tombstoned issues as nodes (default: drop them and their edges)
+    pub include_tombstones: bool,
+    /// On duplicate IDs keep the last record instead of the first
+    pub last_duplicate_wins: bool,
+}
+
+/// A line that produced no node.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SkippedLine {
+    /// 1-based line number
+    pub line: usize,
+    pub reason: String,
+}
+
+/// A dependency whose `depends_on_id` names no loaded issue.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DanglingRef {
+    /// 1-based line number of the declaring issue
+    pub line: usize,
+    pub issue_id: String,
+    pub depends_on_id: String,
+}
+
+/// A dependency that was dropped for a reason other than a missing target.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SkippedDependency {
+    pub line: usize,
+    pub issue_id: String,
+    pub depends_on_id: String,
+    pub reason: String,
+}
+
+/// An issue ID defined on more than one line.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DuplicateId {
+    pub id: String,
+    /// Line of the ignored record
+    pub line: usize,
+    /// Line of the record that was kept
+    pub kept_line: usize,
+}
+
+/// A status string that is not a known beads status. The node keeps the
+/// default status (open).
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct UnknownStatus {
+    pub line: usize,
+    pub id: String,
+    pub status: String,
+}
+
+/// Summary of a beads JSONL load.
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct BeadsLoadReport {
+    /// Non-blank lines read
+    pub lines: usize,
+    /// Nodes created
+    pub nodes: usize,
+    /// Edges created
+    pub edges: usize,
+    /// Tombstoned issues dropped (0 when `include_tombstones` is set)
+    pub tombstones: usize,
+    /// Dependencies dropped because they pointed at a dropped tombstone
+    pub tombstone_refs: usize,
+    pub skipped_lines: Vec<SkippedLine>,
+    pub dangling_refs: Vec<DanglingRef>,
+    pub skipped_dependencies: Vec<SkippedDependency>,
+    pub duplicate_ids: Vec<DuplicateId>,
+    pub unknown_statuses: Vec<UnknownStatus>,
+}
+
+impl BeadsLoadReport {
+    /// True if nothing was skipped, dangling, duplicated or unrecognised.
+    pub fn is_clean(&self) -> bool {
+        self.skipped_lines.is_empty()
+            && self.dangling_refs.is_empty()
+            && self.skipped_dependencies.is_empty()
+            && self.duplicate_ids.is_empty()
+            && self.unknown_statuses.is_empty()
+    }
+}
+
+/// Result of `DiGraph::fromBeadsJsonl`: the graph plus its load report.
+#[wasm_bindgen]
+pub struct BeadsLoad {
+    graph: Option<DiGraph>,
+    report: BeadsLoadReport,
+}
+
+#[wasm_bindgen]
+impl BeadsLoad {
+    /// Take ownership of the loaded graph (returns undefined on a second call).
+    #[wasm_bindgen(js_name = takeGraph)]
+    pub fn take_graph(&mut self) -> Option<DiGraph> {
+        self.graph.take()
+    }
+
+    /// Load report as a JS object.
+    #[wasm_bindgen(js_name = report)]
+    pub fn report_js(&self) -> Result<JsValue, JsError> {
+        Ok(to_js(&self.report)?)
+    }
+
+    /// True if nothing was skipped, dangling, duplicated or unrecognised.
+    #[wasm_bindgen(js_name = isClean)]
+    pub fn is_clean(&self) -> bool {
+        self.report.is_clean()
+    }
+}
+
+impl BeadsLoad {
+    /// The load report.
+    pub fn report(&self) -> &BeadsLoadReport {
+        &self.report
+    }
+
+    /// Split into graph and report.
+    pub fn into_parts(self) -> (DiGraph, BeadsLoadReport) {
+        (self.graph.unwrap_or_default(), self.report)
+    }
+}
+
+/// One line of issues.jsonl (only the fields the graph needs).
+#[derive(Deserialize)]
+struct BeadsIssue {
+    #[serde(flatten)]
+    record: AttributeRecord,
+    #[serde(default)]
+    dependencies: Option<Vec<BeadsDependency>>,
+}
+
+#[derive(Deserialize)]
+struct BeadsDependency {
+    #[serde(default)]
+    depends_on_id: String,
+    #[serde(default, rename = "type")]
+    dep_type: String,
+}
+
+/// Parse beads JSONL text into a graph with typed edges and attributes.
+pub fn load_beads_jsonl(text: &str, options: &BeadsLoadOptions) -> BeadsLoad {
+    let mut report = BeadsLoadReport::default();
+
+    // Pass 1: parse lines, resolve duplicates and tombstones.
+    let mut issues: Vec<(usize, BeadsIssue)> = Vec::new();
+    let mut slot_by_id: HashMap<String, usize> = HashMap::new();
+    let mut tombstoned: HashSet<String> = HashSet::new();
+
+    for (i, raw) in text.lines().enumerate() {
+        let line = i + 1;
+        let raw = raw.trim();
+        if raw.is_empty() {
+            continue;
+        }
+        report.lines += 1;
+
+        let issue: BeadsIssue = match serde_json::from_str(raw) {
+            Ok(issue) => issue,
+            Err(e) => {
+                report.skipped_lines.push(SkippedLine {
+                    line,
+                    reason: e.to_string(),
+                });
+                continue;
+            }
+        };
+        let id = issue.record.id.trim();
+        if id.is_empty() {
+            report.skipped_lines.push(SkippedLine {
+                line,
+                reason: "missing id".to_string(),
+            });
+            continue;
+        }
+
+        if let Some(&slot) = slot_by_id.get(id) {
+            let kept_line = issues[slot].0;
+            if options.last_duplicate_wins {
+                report.duplicate_ids.push(DuplicateId {
+                    id: id.to_string(),
+                    line: kept_line,
+                    kept_line: line,
+                });
+                issues[slot] = (line, issue);
+            } else {
+                report.duplicate_ids.push(DuplicateId {
+                    id: id.to_string(),
+                    line,
+                    kept_line,
+                });
+            }
+            continue;
+        }
+        slot_by_id.insert(id.to_string(), issues.len());
+        issues.push((line, issue));
+    }
+
+    if !options.include_tombstones {
+        issues.retain(|(_, issue)| {
+            let is_tombstone = issue.record.status.as_deref().and_then(IssueStatus::parse)
+                == Some(IssueStatus::Tombstone);
+            if is_tombstone {
+                tombstoned.insert(issue.record.id.trim().to_string());
+            }
+            !is_tombstone
+        });
+        report.tombstones = tombstoned.len();
+    }
+
+    // Pass 2: nodes and attributes, then edges once every ID is known.
+    let dep_count = issues
+        .iter()
+        .map(|(_, issue)| issue.dependencies.as_ref().map_or(0, Vec::len))
+        .sum();
+    let mut graph = DiGraph::with_capacity(issues.len(), dep_count);
+    for (line, issue) in &issues {
+        let id = issue.record.id.trim();
+        let node = graph.add_node(id);
+        graph.attrs_mut().apply(node, &issue.record);
+        if let Some(status) = issue.record.status.as_deref() {
+            if IssueStatus::parse(status).is_none() {
+                report.unknown_statuses.push(UnknownStatus {
+                    line: *line,
+                    id: id.to_string(),
+                    status: status.to_string(),
+                });
+            }
+        }
+    }
+
+    for (line, issue) in &issues {
+        let issue_id = issue.record.id.trim();
+        let Some(to) = graph.node_idx(issue_id) else {
+            continue;
+        };
+        for dep in issue.dependencies.iter().flatten() {
+            let target = dep.depends_on_id.trim();
+            let skip = |reason: &str| SkippedDependency {
+                line: *line,
+                issue_id: issue_id.to_string(),
+                depends_on_id: target.to_string(),
+                reason: reason.to_string(),
+            };
+
+            let Some(kind) = EdgeKind::from_dependency_type(dep.dep_type.trim()) else {
+                report
+                    .skipped_dependencies
+                    .push(skip(&format!("unknown dependency type '{}'", dep.dep_type)));
+                continue;
+            };
+            if target == issue_id {
+                report.skipped_dependencies.push(skip("self-dependency"));
+                continue;
+            }
+            match graph.node_idx(target) {
+                Some(from) => graph.add_edge_kind(from, to, kind),
+                None if tombstoned.contains(target) => report.tombstone_refs += 1,
+                None => report.dangling_refs.push(DanglingRef {
+                    line: *line,
+                    issue_id: issue_id.to_string(),
+                    depends_on_id: target.to_string(),
+                }),
+            }
+        }
+    }
+
+    report.nodes = graph.node_count();
+    report.edges = graph.edge_count();
+    BeadsLoad {
+        graph: Some(graph),
+        report,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn load(text: &str) -> (DiGraph, BeadsLoadReport) {
+        load_beads_jsonl(text, &BeadsLoadOptions::default()).into_parts()
+    }
+
+    #[test]
+    fn test_basic_load() {
+        let text = r#"{"id":"bv-1","status":"open","priority":1,"labels":["ui"],"estimated_minutes":30}
+{"id":"bv-2","status":"in_progress","dependencies":[{"issue_id":"bv-2","depends_on_id":"bv-1","type":"blocks"}]}
+{"id":"bv-3","dependencies":[{"depends_on_id":"bv-1","type":"related"},{"depends_on_id":"bv-2","type":""}]}
+"#;
+        let (graph, report) = load(text);
+        assert!(report.is_clean());
+        assert_eq!(report.nodes, 3);
+        assert_eq!(report.edges, 3);
+
+        let (a, b, c) = (0, 1, 2);
+        assert_eq!(graph.edge_kinds(a, b), EdgeKind::Blocks.bit());
+        assert_eq!(graph.edge_kinds(a, c), EdgeKind::Related.bit());
+        assert_eq!(graph.edge_kinds(b, c), EdgeKind::Blocks.bit());
+        assert_eq!(graph.attrs().priority(a), Some(1));
+        assert_eq!(graph.attrs().estimated_minutes(a), Some(30));
+        assert_eq!(graph.attrs().labels(a), vec!["ui"]);
+        assert_eq!(graph.attrs().status(b), Some(IssueStatus::InProgress));
+    }
+
+    #[test]
+    fn test_forward_reference() {
+        // Dependency declared before its target appears
+        let text = r#"{"id":"b","dependencies":[{"depends_on_id":"a","type":"blocks"}]}
+{"id":"a"}"#;
+        let (graph, report) = load(text);
+        assert!(report.dangling_refs.is_empty());
+        let a = graph.node_idx("a").unwrap();
+        let b = graph.node_idx("b").unwrap();
+        assert_eq!(graph.edge_kinds(a, b), EdgeKind::Blocks.bit());
+    }
+
+    #[test]
+    fn test_report_problems() {
+        let text = r#"{"id":"a"}
+not json
+
+{"title":"no id"}
+{"id":"a","priority":0}
+{"id":"b","dependencies":[{"depends_on_id":"zz","type":"blocks"},{"depends_on_id":"a","type":"weird"},{"depends_on_id":"b"}]}"#;
+        let (graph, report) = load(text);
+        assert_eq!(report.lines, 5);
+        assert_eq!(graph.node_count(), 2);
+        assert_eq!(graph.edge_count(), 0);
+
+        let skipped: Vec<usize> = report.skipped_lines.iter().map(|s| s.line).collect();
+        assert_eq!(skipped, vec![2, 4]);
+        assert_eq!(
+            report.duplicate_ids,
+            vec![DuplicateId {
+                id: "a".to_string(),
+                line: 5,
+                kept_line: 1
+            }]
+        );
+        // First record kept
+        assert_eq!(graph.attrs().priority(0), Some(2));
+        assert_eq!(
+            report.dangling_refs,
+            vec![DanglingRef {
+                line: 6,
+                issue_id: "b".to_string(),
+                depends_on_id: "zz".to_string()
+            }]
+        );
+        assert_eq!(report.skipped_dependencies.len(), 2);
+        assert!(!report.is_clean());
+    }
+
+    #[test]
+    fn test_unknown_status_reported() {
+        let text = "{\"id\":\"a\",\"status\":\"done\"}\n{\"id\":\"b\",\"status\":\"closed\"}";
+        let (graph, report) = load(text);
+        assert_eq!(graph.attrs().status(0), Some(IssueStatus::Open));
+        assert_eq!(
+            report.unknown_statuses,
+            vec![UnknownStatus {
+                line: 1,
+                id: "a".to_string(),
+                status: "done".to_string()
+            }]
+        );
+        assert!(!report.is_clean());
+    }
+
+    #[test]
+    fn test_last_duplicate_wins() {
+        let text = "{\"id\":\"a\",\"priority\":3}\n{\"id\":\"a\",\"priority\":0}";
+        let options = BeadsLoadOptions {
+            last_duplicate_wins: true,
+            ..Default::default()
+        };
+        let (graph, report) = load_beads_jsonl(text, &options).into_parts();
+        assert_eq!(graph.attrs().priority(0), Some(0));
+        assert_eq!(report.duplicate_ids[0].line, 1);
+        assert_eq!(report.duplicate_ids[0].kept_line, 2);
+    }
+
+    #[test]
+    fn test_tombstones() {
+        let text = r#"{"id":"a","status":"tombstone"}
+{"id":"b","dependencies":[{"depends_on_id":"a","type":"blocks"}]}"#;
+        let (graph, report) = load(text);
+        assert_eq!(graph.node_count(), 1);
+        assert_eq!(report.tombstones, 1);
+        assert_eq!(report.tombstone_refs, 1);
+        assert!(report.dangling_refs.is_empty());
+
+        let options = BeadsLoadOptions {
+            include_tombstones: true,
+            ..Default::default()
+        };
+        let (graph, report) = load_beads_jsonl(text, &options).into_parts();
+        assert_eq!(graph.node_count(), 2);
+        assert_eq!(graph.edge_count(), 1);
+        assert_eq!(report.tombstones, 0);
+        assert_eq!(graph.attrs().status(0), Some(IssueStatus::Tombstone));
+    }
+}
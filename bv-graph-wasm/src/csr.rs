@@ -0,0 +1,284 @@
+//! Frozen compressed-sparse-row (CSR) view of a `DiGraph`.
+//!
+//! `DiGraph` keeps growable adjacency lists so it can be edited cheaply.
+//! Algorithms instead read an immutable CSR snapshot: for each view, the
+//! neighbors of node `v` are `targets[offsets[v]..offsets[v + 1]]`. The
+//! snapshot holds forward and reverse views over all edges, the same two
+//! views restricted to blocking edges, and a deduplicated undirected view
+//! without self-loops (used by k-core and articulation analysis).
+//!
+//! `DiGraph` builds the snapshot lazily on first use and discards it on any
+//! structural change, so algorithms share one build per graph version.
+
+use crate::graph::DiGraph;
+use wasm_bindgen::prelude::*;
+
+/// One CSR adjacency structure.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Csr {
+    offsets: Vec<u32>,
+    targets: Vec<u32>,
+}
+
+impl Csr {
+    /// Build from per-node neighbor rows.
+    fn from_rows<I>(n: usize, mut row: impl FnMut(usize) -> I) -> Csr
+    where
+        I: IntoIterator<Item = usize>,
+    {
+        let mut offsets = Vec::with_capacity(n + 1);
+        let mut targets = Vec::new();
+        offsets.push(0);
+        for v in 0..n {
+            targets.extend(row(v).into_iter().map(|w| w as u32));
+            offsets.push(targets.len() as u32);
+        }
+        Csr { offsets, targets }
+    }
+
+    /// Neighbors of `v` (empty if out of range).
+    #[inline]
+    pub(crate) fn neighbors(&self, v: usize) -> &[u32] {
+        match (self.offsets.get(v), self.offsets.get(v + 1)) {
+            (Some(&start), Some(&end)) => &self.targets[start as usize..end as usize],
+            _ => &[],
+        }
+    }
+
+    /// Number of neighbors of `v`.
+    #[inline]
+    pub(crate) fn degree(&self, v: usize) -> usize {
+        self.neighbors(v).len()
+    }
+}
+
+/// Immutable CSR snapshot of a `DiGraph`.
+///
+/// Node indices are the graph's slot indices; removed slots have no edges.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Default)]
+pub struct CsrGraph {
+    forward: Csr,
+    /// Kind masks parallel to `forward.targets`
+    forward_kinds: Vec<u8>,
+    reverse: Csr,
+    /// Kind masks parallel to `reverse.targets`
+    reverse_kinds: Vec<u8>,
+    blocking_forward: Csr,
+    blocking_reverse: Csr,
+    undirected: Csr,
+}
+
+impl CsrGraph {
+    /// Snapshot the current structure of `graph`.
+    pub(crate) fn build(graph: &DiGraph) -> CsrGraph {
+        let n = graph.len();
+        let mask = graph.blocking_kinds();
+
+        let forward = Csr::from_rows(n, |v| graph.successors_slice(v).iter().copied());
+        let forward_kinds = (0..n)
+            .flat_map(|v| graph.successor_kinds(v).iter().copied())
+            .collect();
+        let reverse = Csr::from_rows(n, |v| graph.predecessors_slice(v).iter().copied());
+        let reverse_kinds = (0..n)
+            .flat_map(|v| graph.predecessor_kinds(v).iter().copied())
+            .collect();
+
+        let masked = |neighbors: &[usize], kinds: &[u8]| -> Vec<usize> {
+            neighbors
+                .iter()
+                .zip(kinds)
+                .filter(|&(_, &k)| k & mask != 0)
+                .map(|(&w, _)| w)
+                .collect()
+        };
+        let blocking_forward = Csr::from_rows(n, |v| {
+            masked(graph.successors_slice(v), graph.successor_kinds(v))
+        });
+        let blocking_reverse = Csr::from_rows(n, |v| {
+            masked(graph.predecessors_slice(v), graph.predecessor_kinds(v))
+        });
+
+        let mut row = Vec::new();
+        let undirected = Csr::from_rows(n, |v| {
+            row.clear();
+            row.extend(
+                graph
+                    .successors_slice(v)
+                    .iter()
+                    .chain(graph.predecessors_slice(v))
+                    .copied()
+                    .filter(|&w| w != v),
+            );
+            row.sort_unstable();
+            row.dedup();
+            row.clone()
+        });
+
+        CsrGraph {
+            forward,
+            forward_kinds,
+            reverse,
+            reverse_kinds,
+            blocking_forward,
+            blocking_reverse,
+            undirected,
+        }
+    }
+
+    /// Successors of `v` over all edges.
+    #[inline]
+    pub(crate) fn successors(&self, v: usize) -> &[u32] {
+        self.forward.neighbors(v)
+    }
+
+    /// Predecessors of `v` over all edges.
+    #[inline]
+    pub(crate) fn predecessors(&self, v: usize) -> &[u32] {
+        self.reverse.neighbors(v)
+    }
+
+    /// Successors of `v` over blocking edges.
+    #[inline]
+    pub(crate) fn blocking_successors(&self, v: usize) -> &[u32] {
+        self.blocking_forward.neighbors(v)
+    }
+
+    /// Predecessors of `v` over blocking edges.
+    #[inline]
+    pub(crate) fn blocking_predecessors(&self, v: usize) -> &[u32] {
+        self.blocking_reverse.neighbors(v)
+    }
+
+    /// Distinct neighbors of `v` ignoring direction, excluding `v` itself.
+    #[inline]
+    pub(crate) fn undirected_neighbors(&self, v: usize) -> &[u32] {
+        self.undirected.neighbors(v)
+    }
+
+    /// Out-degree over all edges.
+    #[inline]
+    pub(crate) fn out_degree(&self, v: usize) -> usize {
+        self.forward.degree(v)
+    }
+}
+
+#[wasm_bindgen]
+impl CsrGraph {
+    /// Number of node slots (offset arrays have this length + 1).
+    #[wasm_bindgen(js_name = slotCount)]
+    pub fn slot_count(&self) -> usize {
+        self.forward.offsets.len().saturating_sub(1)
+    }
+
+    /// Number of directed edges.
+    #[wasm_bindgen(js_name = edgeCount)]
+    pub fn edge_count(&self) -> usize {
+        self.forward.targets.len()
+    }
+
+    /// Forward view offsets (Uint32Array of length slotCount + 1).
+    #[wasm_bindgen(js_name = forwardOffsets)]
+    pub fn forward_offsets(&self) -> Vec<u32> {
+        self.forward.offsets.clone()
+    }
+
+    /// Forward view targets (Uint32Array): successors of v are
+    /// targets[offsets[v]..offsets[v + 1]].
+    #[wasm_bindgen(js_name = forwardTargets)]
+    pub fn forward_targets(&self) -> Vec<u32> {
+        self.forward.targets.clone()
+    }
+
+    /// Edge kind masks parallel to forwardTargets (Uint8Array).
+    #[wasm_bindgen(js_name = forwardKinds)]
+    pub fn forward_kinds(&self) -> Vec<u8> {
+        self.forward_kinds.clone()
+    }
+
+    /// Reverse view offsets (Uint32Array).
+    #[wasm_bindgen(js_name = reverseOffsets)]
+    pub fn reverse_offsets(&self) -> Vec<u32> {
+        self.reverse.offsets.clone()
+    }
+
+    /// Reverse view targets (Uint32Array): predecessors of each node.
+    #[wasm_bindgen(js_name = reverseTargets)]
+    pub fn reverse_targets(&self) -> Vec<u32> {
+        self.reverse.targets.clone()
+    }
+
+    /// Edge kind masks parallel to reverseTargets (Uint8Array).
+    #[wasm_bindgen(js_name = reverseKinds)]
+    pub fn reverse_kinds(&self) -> Vec<u8> {
+        self.reverse_kinds.clone()
+    }
+
+    /// Undirected view offsets (Uint32Array).
+    #[wasm_bindgen(js_name = undirectedOffsets)]
+    pub fn undirected_offsets(&self) -> Vec<u32> {
+        self.undirected.offsets.clone()
+    }
+
+    /// Undirected view targets (Uint32Array): distinct neighbors, no self-loops.
+    #[wasm_bindgen(js_name = undirectedTargets)]
+    pub fn undirected_targets(&self) -> Vec<u32> {
+        self.undirected.targets.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::EdgeKind;
+
+    #[test]
+    fn test_views() {
+        // a -> b, a -related-> c, c -> a, b -> b
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        g.add_edge(a, b);
+        g.add_edge_kind(a, c, EdgeKind::Related);
+        g.add_edge(c, a);
+        g.add_edge(b, b);
+
+        let csr = CsrGraph::build(&g);
+        assert_eq!(csr.slot_count(), 3);
+        assert_eq!(csr.edge_count(), 4);
+        assert_eq!(csr.forward_offsets(), vec![0, 2, 3, 4]);
+        assert_eq!(csr.successors(a), &[1, 2]);
+        assert_eq!(csr.predecessors(a), &[2]);
+        assert_eq!(csr.blocking_successors(a), &[1]);
+        assert_eq!(csr.blocking_predecessors(c), &[] as &[u32]);
+        // a and c are linked both ways but appear once; b's self-loop is dropped
+        assert_eq!(csr.undirected_neighbors(a), &[1, 2]);
+        assert_eq!(csr.undirected_neighbors(b), &[0]);
+        assert_eq!(csr.forward_kinds(), vec![1, 2, 1, 1]);
+        assert!(csr.successors(9).is_empty());
+    }
+
+    #[test]
+    fn test_cache_invalidated_on_mutation() {
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        assert_eq!(g.csr().out_degree(a), 0);
+
+        g.add_edge(a, b);
+        assert_eq!(g.csr().successors(a), &[1]);
+
+        g.add_edge_kind(b, a, EdgeKind::Related);
+        assert!(g.csr().blocking_successors(b).is_empty());
+        g.set_blocking_kinds_checked(crate::graph::ALL_KINDS).unwrap();
+        assert_eq!(g.csr().blocking_successors(b), &[0]);
+
+        g.remove_edge(a, b);
+        assert!(g.csr().successors(a).is_empty());
+
+        let c = g.add_node("c");
+        assert_eq!(g.freeze().slot_count(), 3);
+        assert!(g.csr().successors(c).is_empty());
+    }
+}
@@ -0,0 +1,152 @@
+//! Crate-wide error type.
+//!
+//! Fallible (`try*`) bindings return `Result<_, JsError>`. A `GraphError`
+//! converts into a `JsError` whose message starts with a stable,
+//! machine-readable code followed by a colon, e.g.
+//! `"CYCLIC_GRAPH: blocking graph contains a cycle through 3 nodes"`, so JS
+//! callers can branch on `err.message.split(':')[0]`.
+
+use serde::Serialize;
+use std::fmt;
+use wasm_bindgen::JsValue;
+
+/// Errors reported by graph operations and algorithms.
+#[derive(Debug, Clone, PartialEq)]
+pub enum GraphError {
+    /// A node index outside `0..node_count`
+    InvalidNode { index: usize, node_count: usize },
+    /// A node index whose slot has been removed
+    RemovedNode { index: usize },
+    /// An algorithm that requires a DAG was run on a cyclic graph.
+    /// `nodes` lists the nodes that lie on a blocking cycle.
+    CyclicGraph { nodes: Vec<usize> },
+    /// Strict mode rejected an edge that would close a blocking cycle.
+    /// `path` is the existing path to -> ... -> from.
+    WouldCreateCycle {
+        from: usize,
+        to: usize,
+        path: Vec<usize>,
+    },
+    /// A requested limit is larger than the crate allows
+    LimitExceeded {
+        what: &'static str,
+        requested: usize,
+        max: usize,
+    },
+    /// Two arrays that must be parallel have different lengths
+    LengthMismatch {
+        what: &'static str,
+        expected: usize,
+        actual: usize,
+    },
+    /// An argument is outside the range an algorithm accepts
+    InvalidArgument {
+        what: &'static str,
+        reason: &'static str,
+    },
+    /// A binary snapshot is malformed, corrupt or from a newer version
+    InvalidSnapshot(String),
+    /// Parsing input or serializing a result failed
+    Serialization(String),
+}
+
+impl GraphError {
+    /// Stable machine-readable code.
+    pub fn code(&self) -> &'static str {
+        match self {
+            GraphError::InvalidNode { .. } => "INVALID_NODE",
+            GraphError::RemovedNode { .. } => "REMOVED_NODE",
+            GraphError::CyclicGraph { .. } => "CYCLIC_GRAPH",
+            GraphError::WouldCreateCycle { .. } => "WOULD_CREATE_CYCLE",
+            GraphError::LimitExceeded { .. } => "LIMIT_EXCEEDED",
+            GraphError::LengthMismatch { .. } => "LENGTH_MISMATCH",
+            GraphError::InvalidArgument { .. } => "INVALID_ARGUMENT",
+            GraphError::InvalidSnapshot(_) => "INVALID_SNAPSHOT",
+            GraphError::Serialization(_) => "SERIALIZATION",
+        }
+    }
+}
+
+impl fmt::Display for GraphError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: ", self.code())?;
+        match self {
+            GraphError::InvalidNode { index, node_count } => {
+                write!(f, "node index {} out of range ({} nodes)", index, node_count)
+            }
+            GraphError::RemovedNode { index } => write!(f, "node {} has been removed", index),
+            GraphError::CyclicGraph { nodes } => write!(
+                f,
+                "blocking graph contains a cycle through {} nodes",
+                nodes.len()
+            ),
+            GraphError::WouldCreateCycle { from, to, path } => write!(
+                f,
+                "edge {} -> {} would close a blocking cycle through {} nodes",
+                from,
+                to,
+                path.len()
+            ),
+            GraphError::LimitExceeded {
+                what,
+                requested,
+                max,
+            } => write!(f, "{} of {} exceeds maximum {}", what, requested, max),
+            GraphError::LengthMismatch {
+                what,
+                expected,
+                actual,
+            } => write!(f, "{} has length {}, expected {}", what, actual, expected),
+            GraphError::InvalidArgument { what, reason } => write!(f, "{} {}", what, reason),
+            GraphError::InvalidSnapshot(reason) => write!(f, "invalid snapshot: {}", reason),
+            GraphError::Serialization(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl std::error::Error for GraphError {}
+
+impl From<serde_json::Error> for GraphError {
+    fn from(e: serde_json::Error) -> Self {
+        GraphError::Serialization(e.to_string())
+    }
+}
+
+impl From<serde_wasm_bindgen::Error> for GraphError {
+    fn from(e: serde_wasm_bindgen::Error) -> Self {
+        GraphError::Serialization(e.to_string())
+    }
+}
+
+pub type GraphResult<T> = Result<T, GraphError>;
+
+/// Serialize a result for JS, surfacing failures instead of returning null.
+pub(crate) fn to_js<T: Serialize + ?Sized>(value: &T) -> GraphResult<JsValue> {
+    Ok(serde_wasm_bindgen::to_value(value)?)
+}
+
+/// Narrow node indices to `u32` for a Uint32Array result.
+pub(crate) fn to_u32(nodes: &[usize]) -> Vec<u32> {
+    nodes.iter().map(|&v| v as u32).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codes_prefix_message() {
+        let err = GraphError::InvalidNode {
+            index: 7,
+            node_count: 3,
+        };
+        assert_eq!(err.code(), "INVALID_NODE");
+        assert_eq!(err.to_string(), "INVALID_NODE: node index 7 out of range (3 nodes)");
+
+        let err = GraphError::CyclicGraph { nodes: vec![0, 1] };
+        assert!(err.to_string().starts_with("CYCLIC_GRAPH: "));
+
+        let err: GraphError = serde_json::from_str::<Vec<u8>>("nope").unwrap_err().into();
+        assert_eq!(err.code(), "SERIALIZATION");
+    }
+}
@@ -1,12 +1,83 @@
 //! Core directed graph structure with adjacency lists.
 
+use crate::attributes::{AttributeRecord, IssueStatus, NodeAttributes, NodeFilter};
+use crate::beads::{load_beads_jsonl, BeadsLoad, BeadsLoadOptions};
+use crate::csr::CsrGraph;
+use crate::topo_order::{cycle_path, TopoOrder};
+use crate::reach_index::ReachIndex;
+use crate::work_state::WorkState;
+use crate::error::{to_js, to_u32, GraphError, GraphResult};
+use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::cell::OnceCell;
+use std::collections::{HashMap, HashSet};
 use wasm_bindgen::prelude::*;
 
+/// Kind of dependency an edge represents (mirrors beads `DependencyType`).
+///
+/// Only `Blocks` edges gate work by default; the other kinds are kept for
+/// structural analysis and can be opted into via `setBlockingKinds`.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum EdgeKind {
+    /// `blocks` (or legacy empty type): target cannot start until source closes
+    Blocks = 0,
+    /// `related`: informational link, never blocking by default
+    Related = 1,
+    /// `parent-child`: hierarchy link (epic -> child)
+    ParentChild = 2,
+    /// `discovered-from`: provenance link
+    DiscoveredFrom = 3,
+}
+
+/// Edge kind mask containing only `Blocks` (the default blocking view).
+pub const BLOCKING_KINDS: u8 = 1 << EdgeKind::Blocks as u8;
+
+/// Edge kind mask containing every kind.
+pub const ALL_KINDS: u8 = 0b1111;
+
+impl EdgeKind {
+    /// Bit for this kind inside an edge kind mask.
+    pub fn bit(self) -> u8 {
+        1 << self as u8
+    }
+
+    /// Parse a beads dependency type string.
+    /// The empty string maps to `Blocks` for compatibility with legacy data.
+    pub fn from_dependency_type(dep_type: &str) -> Option<EdgeKind> {
+        match dep_type {
+            "" | "blocks" => Some(EdgeKind::Blocks),
+            "related" => Some(EdgeKind::Related),
+            "parent-child" => Some(EdgeKind::ParentChild),
+            "discovered-from" => Some(EdgeKind::DiscoveredFrom),
+            _ => None,
+        }
+    }
+
+    /// Beads dependency type string for this kind.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            EdgeKind::Blocks => "blocks",
+            EdgeKind::Related => "related",
+            EdgeKind::ParentChild => "parent-child",
+            EdgeKind::DiscoveredFrom => "discovered-from",
+        }
+    }
+}
+
 /// Directed graph optimized for graph algorithms.
 /// Uses adjacency lists for O(1) neighbor access.
+///
+/// Each edge carries a kind mask (see `EdgeKind`). Structural algorithms
+/// (centrality, k-core, articulation) see every edge; algorithms that reason
+/// about blocking only follow edges whose kinds intersect `blocking_kinds`.
+///
+/// Removed nodes leave a tombstoned slot behind so that every other index
+/// stays valid; algorithms skip tombstoned slots and report zero/empty
+/// values for them. `compact()` reclaims the slots.
 #[wasm_bindgen]
+#[derive(Clone)]
 pub struct DiGraph {
     /// Node ID strings (issue IDs like "bv-123")
     nodes: Vec<String>,
@@ -22,6 +93,37 @@ pub struct DiGraph {
     /// (these nodes depend on v)
     rev_adj: Vec<Vec<usize>>,
 
+    /// Kind masks parallel to `adj`: adj_kinds[u][i] describes edge u -> adj[u][i]
+    adj_kinds: Vec<Vec<u8>>,
+
+    /// Kind masks parallel to `rev_adj`
+    rev_kinds: Vec<Vec<u8>>,
+
+    /// Edge membership for O(1) duplicate detection
+    edge_set: HashSet<(usize, usize)>,
+
+    /// Edge kinds treated as blocking (defaults to `BLOCKING_KINDS`)
+    blocking_kinds: u8,
+
+    /// Per-node issue attributes (status, priority, estimate, ...)
+    attrs: NodeAttributes,
+
+    /// Tombstone flags: removed[v] is true once node v has been removed
+    removed: Vec<bool>,
+
+    /// Number of tombstoned slots
+    removed_count: usize,
+
+    /// Frozen CSR view used by algorithms; built lazily, cleared on mutation
+    csr: OnceCell<CsrGraph>,
+
+    /// Incremental topological order of the blocking graph; built lazily,
+    /// `Some(None)` while the blocking graph is cyclic
+    topo: OnceCell<Option<TopoOrder>>,
+
+    /// Reject blocking edges that would close a cycle
+    strict_acyclic: bool,
+
     /// Edge count (for density calculation)
     edge_count: usize,
 }
@@ -31,6 +133,9 @@ pub struct DiGraph {
 pub struct GraphSnapshot {
     pub nodes: Vec<String>,
     pub edges: Vec<(usize, usize)>,
+    /// Tombstoned slots (omitted when empty)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub removed: Vec<usize>,
 }
 
 #[wasm_bindgen]
@@ -43,6 +148,16 @@ impl DiGraph {
             node_index: HashMap::new(),
             adj: Vec::new(),
             rev_adj: Vec::new(),
+            adj_kinds: Vec::new(),
+            rev_kinds: Vec::new(),
+            edge_set: HashSet::new(),
+            blocking_kinds: BLOCKING_KINDS,
+            attrs: NodeAttributes::default(),
+            removed: Vec::new(),
+            removed_count: 0,
+            csr: OnceCell::new(),
+            topo: OnceCell::new(),
+            strict_acyclic: false,
             edge_count: 0,
         }
     }
@@ -50,12 +165,21 @@ impl DiGraph {
     /// Create a graph with pre-allocated capacity.
     #[wasm_bindgen(js_name = withCapacity)]
     pub fn with_capacity(node_capacity: usize, edge_capacity: usize) -> DiGraph {
-        let _ = edge_capacity; // Used for documentation, not pre-allocation
         DiGraph {
             nodes: Vec::with_capacity(node_capacity),
             node_index: HashMap::with_capacity(node_capacity),
             adj: Vec::with_capacity(node_capacity),
             rev_adj: Vec::with_capacity(node_capacity),
+            adj_kinds: Vec::with_capacity(node_capacity),
+            rev_kinds: Vec::with_capacity(node_capacity),
+            edge_set: HashSet::with_capacity(edge_capacity),
+            blocking_kinds: BLOCKING_KINDS,
+            attrs: NodeAttributes::default(),
+            removed: Vec::new(),
+            removed_count: 0,
+            csr: OnceCell::new(),
+            topo: OnceCell::new(),
+            strict_acyclic: false,
             edge_count: 0,
         }
     }
@@ -66,36 +190,202 @@ impl DiGraph {
         if let Some(&idx) = self.node_index.get(id) {
             return idx;
         }
-        let idx = self.nodes.len();
-        self.nodes.push(id.to_string());
-        self.node_index.insert(id.to_string(), idx);
-        self.adj.push(Vec::new());
-        self.rev_adj.push(Vec::new());
-        idx
+        self.push_slot(id)
+    }
+
+    /// Remove the edge from -> to (all kinds). Returns true if an edge was removed.
+    #[wasm_bindgen(js_name = removeEdge)]
+    pub fn remove_edge(&mut self, from: usize, to: usize) -> bool {
+        if !self.edge_set.remove(&(from, to)) {
+            return false;
+        }
+        let Some(pos) = self.adj[from].iter().position(|&w| w == to) else {
+            return false;
+        };
+        self.csr.take();
+        if matches!(self.topo.get(), Some(None)) {
+            // Removing an edge may have broken the last cycle
+            self.topo.take();
+        }
+        self.adj[from].remove(pos);
+        self.adj_kinds[from].remove(pos);
+        if let Some(rpos) = self.rev_adj[to].iter().position(|&u| u == from) {
+            self.rev_adj[to].remove(rpos);
+            self.rev_kinds[to].remove(rpos);
+        }
+        self.edge_count -= 1;
+        true
+    }
+
+    /// Remove a node and all its edges. The slot is tombstoned so every other
+    /// index stays valid; the ID becomes free for `addNode` to reuse in a new slot.
+    /// Returns false if the index is out of range or already removed.
+    #[wasm_bindgen(js_name = removeNode)]
+    pub fn remove_node(&mut self, idx: usize) -> bool {
+        if !self.is_live(idx) {
+            return false;
+        }
+        for to in self.adj[idx].clone() {
+            self.remove_edge(idx, to);
+        }
+        for from in self.rev_adj[idx].clone() {
+            self.remove_edge(from, idx);
+        }
+        if self.node_index.get(&self.nodes[idx]) == Some(&idx) {
+            self.node_index.remove(&self.nodes[idx]);
+        }
+        self.attrs.set_status(idx, IssueStatus::Tombstone);
+        self.removed[idx] = true;
+        self.removed_count += 1;
+        true
+    }
+
+    /// True if the slot at idx holds a removed (tombstoned) node.
+    #[wasm_bindgen(js_name = isRemoved)]
+    pub fn is_removed(&self, idx: usize) -> bool {
+        self.removed.get(idx).copied().unwrap_or(false)
+    }
+
+    /// Number of index slots, including tombstoned ones.
+    /// Per-node result arrays have this length.
+    #[wasm_bindgen(js_name = slotCount)]
+    pub fn slot_count(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Drop tombstoned slots and renumber the remaining nodes.
+    /// Returns the old-to-new index map as an Int32Array (-1 for removed slots).
+    pub fn compact(&mut self) -> Vec<i32> {
+        let n = self.nodes.len();
+        let mut map = vec![-1i32; n];
+        let mut next = 0;
+        for (old, slot) in map.iter_mut().enumerate() {
+            if !self.removed[old] {
+                *slot = next;
+                next += 1;
+            }
+        }
+        if self.removed_count == 0 {
+            return map;
+        }
+
+        let remap = |list: &[usize]| -> Vec<usize> { list.iter().map(|&v| map[v] as usize).collect() };
+        let mut compacted = DiGraph::with_capacity(next as usize, self.edge_count);
+        compacted.blocking_kinds = self.blocking_kinds;
+        compacted.strict_acyclic = self.strict_acyclic;
+        for old in (0..n).filter(|&v| !self.removed[v]) {
+            let new = compacted.push_slot(&self.nodes[old]);
+            compacted.attrs.copy_node(new, &self.attrs, old);
+            compacted.adj[new] = remap(&self.adj[old]);
+            compacted.adj_kinds[new] = self.adj_kinds[old].clone();
+            compacted.rev_adj[new] = remap(&self.rev_adj[old]);
+            compacted.rev_kinds[new] = self.rev_kinds[old].clone();
+        }
+        compacted.edge_set = self
+            .edge_set
+            .iter()
+            .map(|&(from, to)| (map[from] as usize, map[to] as usize))
+            .collect();
+        compacted.edge_count = self.edge_count;
+        *self = compacted;
+        map
+    }
+
+    /// Immutable CSR snapshot (forward, reverse and undirected views as
+    /// Uint32Array offset/target pairs). Algorithms build and share the same
+    /// snapshot automatically; calling this up front moves the build cost out
+    /// of the first query. Any mutation invalidates it.
+    pub fn freeze(&self) -> CsrGraph {
+        self.csr().clone()
     }
 
-    /// Add a directed edge from -> to. Idempotent.
+    /// Add a directed blocking edge from -> to. Idempotent.
+    /// Out-of-range indices are ignored; use `tryAddEdge` to detect them.
     #[wasm_bindgen(js_name = addEdge)]
     pub fn add_edge(&mut self, from: usize, to: usize) {
-        // Check bounds
-        if from >= self.nodes.len() || to >= self.nodes.len() {
-            return; // Silently ignore invalid edges
-        }
+        self.add_edge_kind(from, to, EdgeKind::Blocks);
+    }
 
-        // Check if edge already exists (linear scan is fine for typical degree)
-        if self.adj[from].contains(&to) {
-            return;
+    /// Add a directed edge of the given kind. Idempotent.
+    /// Adding a second kind to an existing edge merges it into the edge's kind mask.
+    /// Out-of-range indices are ignored; use `tryAddEdgeKind` to detect them.
+    #[wasm_bindgen(js_name = addEdgeKind)]
+    pub fn add_edge_kind(&mut self, from: usize, to: usize, kind: EdgeKind) {
+        let _ = self.add_edge_checked(from, to, kind);
+    }
+
+    /// Add a directed blocking edge, failing with INVALID_NODE on a bad index.
+    #[wasm_bindgen(js_name = tryAddEdge)]
+    pub fn try_add_edge(&mut self, from: usize, to: usize) -> Result<(), JsError> {
+        Ok(self.add_edge_checked(from, to, EdgeKind::Blocks)?)
+    }
+
+    /// Add a directed edge of the given kind, failing with INVALID_NODE on a bad index.
+    #[wasm_bindgen(js_name = tryAddEdgeKind)]
+    pub fn try_add_edge_kind(&mut self, from: usize, to: usize, kind: EdgeKind) -> Result<(), JsError> {
+        Ok(self.add_edge_checked(from, to, kind)?)
+    }
+
+    /// Add a blocking edge between two node IDs, creating either node if missing.
+    #[wasm_bindgen(js_name = addEdgeByIds)]
+    pub fn add_edge_by_ids(&mut self, from_id: &str, to_id: &str) {
+        let from = self.add_node(from_id);
+        let to = self.add_node(to_id);
+        self.add_edge(from, to);
+    }
+
+    /// Add blocking edges from[i] -> to[i] in one call.
+    /// All indices are validated first, so on INVALID_NODE or LENGTH_MISMATCH
+    /// the graph is unchanged. Returns the number of new edges.
+    #[wasm_bindgen(js_name = addEdgesBulk)]
+    pub fn add_edges_bulk(&mut self, from: &[u32], to: &[u32]) -> Result<usize, JsError> {
+        Ok(self.add_edges_bulk_checked(from, to, EdgeKind::Blocks)?)
+    }
+
+    /// Build a graph from node IDs and parallel edge index arrays.
+    /// Node i gets index i; a repeated ID keeps its first index for `nodeIdx`.
+    #[wasm_bindgen(js_name = fromArrays)]
+    pub fn from_arrays(ids: Vec<String>, from: &[u32], to: &[u32]) -> Result<DiGraph, JsError> {
+        Ok(DiGraph::try_from_arrays(&ids, from, to)?)
+    }
+
+    /// Kind mask of edge from -> to (bit i set means `EdgeKind` i). 0 if no edge.
+    #[wasm_bindgen(js_name = edgeKinds)]
+    pub fn edge_kinds(&self, from: usize, to: usize) -> u8 {
+        if !self.edge_set.contains(&(from, to)) {
+            return 0;
         }
+        self.adj[from]
+            .iter()
+            .position(|&w| w == to)
+            .map_or(0, |pos| self.adj_kinds[from][pos])
+    }
 
-        self.adj[from].push(to);
-        self.rev_adj[to].push(from);
-        self.edge_count += 1;
+    /// Check whether the edge from -> to exists (any kind).
+    #[wasm_bindgen(js_name = hasEdge)]
+    pub fn has_edge(&self, from: usize, to: usize) -> bool {
+        self.edge_set.contains(&(from, to))
+    }
+
+    /// Set which edge kinds block work (bitmask of `1 << EdgeKind`).
+    /// Defaults to `blocks` only; pass e.g. `0b0101` to also treat parent-child as blocking.
+    /// In strict mode a mask that makes the blocking graph cyclic fails with
+    /// CYCLIC_GRAPH and the previous mask is kept.
+    #[wasm_bindgen(js_name = setBlockingKinds)]
+    pub fn set_blocking_kinds(&mut self, mask: u8) -> Result<(), JsError> {
+        Ok(self.set_blocking_kinds_checked(mask)?)
+    }
+
+    /// Current blocking edge kind mask.
+    #[wasm_bindgen(js_name = blockingKinds)]
+    pub fn blocking_kinds(&self) -> u8 {
+        self.blocking_kinds
     }
 
-    /// Number of nodes.
+    /// Number of nodes (excluding removed ones).
     #[wasm_bindgen(js_name = nodeCount)]
     pub fn node_count(&self) -> usize {
-        self.nodes.len()
+        self.nodes.len() - self.removed_count
     }
 
     /// Number of edges.
@@ -115,22 +405,41 @@ impl DiGraph {
         }
     }
 
-    /// Get node ID by index.
+    /// Get node ID by index (undefined for removed nodes).
     #[wasm_bindgen(js_name = nodeId)]
     pub fn node_id(&self, idx: usize) -> Option<String> {
+        if self.is_removed(idx) {
+            return None;
+        }
         self.nodes.get(idx).cloned()
     }
 
+    /// Get node ID by index, failing with INVALID_NODE if out of range.
+    #[wasm_bindgen(js_name = tryNodeId)]
+    pub fn try_node_id(&self, idx: usize) -> Result<String, JsError> {
+        self.check_node(idx)?;
+        Ok(self.nodes[idx].clone())
+    }
+
     /// Get node index by ID.
     #[wasm_bindgen(js_name = nodeIdx)]
     pub fn node_idx(&self, id: &str) -> Option<usize> {
         self.node_index.get(id).copied()
     }
 
-    /// Get all node IDs as JSON array.
+    /// Get all node IDs as JSON array indexed by slot (null for removed nodes).
     #[wasm_bindgen(js_name = nodeIds)]
     pub fn node_ids(&self) -> JsValue {
-        serde_wasm_bindgen::to_value(&self.nodes).unwrap_or(JsValue::NULL)
+        self.try_node_ids().unwrap_or(JsValue::NULL)
+    }
+
+    /// `nodeIds`, failing with SERIALIZATION instead of returning null.
+    #[wasm_bindgen(js_name = tryNodeIds)]
+    pub fn try_node_ids(&self) -> Result<JsValue, JsError> {
+        let ids: Vec<Option<&String>> = (0..self.nodes.len())
+            .map(|i| (!self.removed[i]).then(|| &self.nodes[i]))
+            .collect();
+        Ok(to_js(&ids)?)
     }
 
     /// Out-degree of a node (number of dependencies).
@@ -148,41 +457,76 @@ impl DiGraph {
     /// All out-degrees as a vector (JSON array).
     #[wasm_bindgen(js_name = outDegrees)]
     pub fn out_degrees(&self) -> JsValue {
+        self.try_out_degrees().unwrap_or(JsValue::NULL)
+    }
+
+    /// `outDegrees`, failing with SERIALIZATION instead of returning null.
+    #[wasm_bindgen(js_name = tryOutDegrees)]
+    pub fn try_out_degrees(&self) -> Result<JsValue, JsError> {
         let degrees: Vec<usize> = self.adj.iter().map(|v| v.len()).collect();
-        serde_wasm_bindgen::to_value(&degrees).unwrap_or(JsValue::NULL)
+        Ok(to_js(&degrees)?)
     }
 
     /// All in-degrees as a vector (JSON array).
     #[wasm_bindgen(js_name = inDegrees)]
     pub fn in_degrees(&self) -> JsValue {
+        self.try_in_degrees().unwrap_or(JsValue::NULL)
+    }
+
+    /// `inDegrees`, failing with SERIALIZATION instead of returning null.
+    #[wasm_bindgen(js_name = tryInDegrees)]
+    pub fn try_in_degrees(&self) -> Result<JsValue, JsError> {
         let degrees: Vec<usize> = self.rev_adj.iter().map(|v| v.len()).collect();
-        serde_wasm_bindgen::to_value(&degrees).unwrap_or(JsValue::NULL)
+        Ok(to_js(&degrees)?)
     }
 
-    /// Export graph as JSON snapshot.
+    /// Export graph as JSON snapshot (legacy format; prefer `toBinary`).
     #[wasm_bindgen(js_name = toJson)]
     pub fn to_json(&self) -> String {
         let snapshot = GraphSnapshot {
             nodes: self.nodes.clone(),
             edges: self.edges_vec(),
+            removed: self.live_or_removed(true).collect(),
         };
         serde_json::to_string(&snapshot).unwrap_or_default()
     }
 
-    /// Import graph from JSON snapshot.
+    /// Import graph from JSON snapshot (legacy format; prefer `fromBinary`).
+    /// Edges referencing missing nodes fail with INVALID_NODE.
     #[wasm_bindgen(js_name = fromJson)]
     pub fn from_json(json: &str) -> Result<DiGraph, JsError> {
-        let snapshot: GraphSnapshot =
-            serde_json::from_str(json).map_err(|e| JsError::new(&e.to_string()))?;
+        Ok(DiGraph::from_snapshot_json(json)?)
+    }
 
-        let mut graph = DiGraph::with_capacity(snapshot.nodes.len(), snapshot.edges.len());
-        for id in snapshot.nodes {
-            graph.add_node(&id);
-        }
-        for (from, to) in snapshot.edges {
-            graph.add_edge(from, to);
-        }
-        Ok(graph)
+    /// Export as a versioned binary snapshot (Uint8Array) including removed
+    /// slots, edge kinds and node attributes.
+    #[wasm_bindgen(js_name = toBinary)]
+    pub fn to_binary(&self) -> Vec<u8> {
+        crate::snapshot::encode_snapshot(self)
+    }
+
+    /// Import a binary snapshot, failing with INVALID_SNAPSHOT on a bad
+    /// checksum, unsupported version or malformed data.
+    #[wasm_bindgen(js_name = fromBinary)]
+    pub fn from_binary(bytes: &[u8]) -> Result<DiGraph, JsError> {
+        Ok(crate::snapshot::decode_snapshot(bytes)?)
+    }
+
+    /// Import either a binary snapshot or legacy `toJson` bytes, detected
+    /// from the content. Re-export with `toBinary` to migrate old files.
+    #[wasm_bindgen(js_name = fromSnapshot)]
+    pub fn from_snapshot(bytes: &[u8]) -> Result<DiGraph, JsError> {
+        Ok(crate::snapshot::decode_any_snapshot(bytes)?)
+    }
+
+    /// Build a graph from beads `issues.jsonl` text.
+    /// Options JSON (may be empty): { include_tombstones?, last_duplicate_wins? }.
+    /// Returns a `BeadsLoad` holding the graph (`takeGraph()`) and a load report
+    /// listing skipped lines, dangling references and duplicate IDs.
+    #[wasm_bindgen(js_name = fromBeadsJsonl)]
+    pub fn from_beads_jsonl(text: &str, options_json: &str) -> Result<BeadsLoad, JsError> {
+        let options: BeadsLoadOptions = parse_options(options_json)?;
+        Ok(load_beads_jsonl(text, &options))
     }
 
     /// Get successors of a node as JSON array of indices.
@@ -191,12 +535,28 @@ impl DiGraph {
         serde_wasm_bindgen::to_value(succs).unwrap_or(JsValue::NULL)
     }
 
+    /// `successors`, failing with INVALID_NODE or REMOVED_NODE on a bad index.
+    #[wasm_bindgen(js_name = trySuccessors)]
+    pub fn try_successors(&self, node: usize) -> Result<JsValue, JsError> {
+        self.check_node(node)?;
+        let succs = self.adj.get(node).map_or(&[][..], |v| v.as_slice());
+        Ok(to_js(succs)?)
+    }
+
     /// Get predecessors of a node as JSON array of indices.
     pub fn predecessors(&self, node: usize) -> JsValue {
         let preds = self.rev_adj.get(node).map_or(&[][..], |v| v.as_slice());
         serde_wasm_bindgen::to_value(preds).unwrap_or(JsValue::NULL)
     }
 
+    /// `predecessors`, failing with INVALID_NODE or REMOVED_NODE on a bad index.
+    #[wasm_bindgen(js_name = tryPredecessors)]
+    pub fn try_predecessors(&self, node: usize) -> Result<JsValue, JsError> {
+        self.check_node(node)?;
+        let preds = self.rev_adj.get(node).map_or(&[][..], |v| v.as_slice());
+        Ok(to_js(preds)?)
+    }
+
     /// Topological sort using Kahn's algorithm.
     /// Returns node indices in topological order, or null if graph has cycles.
     #[wasm_bindgen(js_name = topologicalSort)]
@@ -208,6 +568,30 @@ impl DiGraph {
         }
     }
 
+    /// Topological sort, failing with CYCLIC_GRAPH if the blocking graph has a cycle.
+    #[wasm_bindgen(js_name = tryTopologicalSort)]
+    pub fn try_topological_sort(&self) -> Result<JsValue, JsError> {
+        use crate::algorithms::topo;
+        let order = topo::try_topological_sort(self)?;
+        Ok(to_js(&order)?)
+    }
+
+    /// Topological order that tolerates cycles (runs on the SCC condensation).
+    /// Returns JSON: { order: number[], cyclic_nodes: number[] }
+    #[wasm_bindgen(js_name = topologicalSortCondensed)]
+    pub fn topological_sort_condensed(&self) -> Result<JsValue, JsError> {
+        use crate::algorithms::topo::topological_sort_condensed;
+        Ok(to_js(&topological_sort_condensed(self))?)
+    }
+
+    /// Collapse blocking cycles into super-nodes.
+    /// Returns JSON: { component: (number|null)[], members: number[][], edges: [from, to][], cyclic: bool[] }
+    #[wasm_bindgen(js_name = condensation)]
+    pub fn condensation(&self) -> Result<JsValue, JsError> {
+        use crate::algorithms::condensation::condense;
+        Ok(to_js(&condense(self))?)
+    }
+
     /// Check if graph is a DAG (directed acyclic graph).
     #[wasm_bindgen(js_name = isDag)]
     pub fn is_dag(&self) -> bool {
@@ -215,6 +599,29 @@ impl DiGraph {
         topo::is_dag(self)
     }
 
+    /// Would adding the blocking edge from -> to close a cycle?
+    /// Returns the existing path to -> ... -> from (Uint32Array) that the edge
+    /// would close, or undefined if the edge is safe. Backed by an incrementally
+    /// maintained topological order, so most checks are O(1).
+    #[wasm_bindgen(js_name = wouldCreateCycle)]
+    pub fn would_create_cycle(&self, from: usize, to: usize) -> Result<Option<Vec<u32>>, JsError> {
+        Ok(self.cycle_path_checked(from, to)?.map(|path| to_u32(&path)))
+    }
+
+    /// In strict mode, blocking edges that would close a cycle are rejected:
+    /// `tryAddEdge`/`addEdgesBulk` fail with WOULD_CREATE_CYCLE and `addEdge`
+    /// ignores them. Enabling fails with CYCLIC_GRAPH on a cyclic graph.
+    #[wasm_bindgen(js_name = setStrictAcyclic)]
+    pub fn set_strict_acyclic(&mut self, enabled: bool) -> Result<(), JsError> {
+        Ok(self.set_strict_acyclic_checked(enabled)?)
+    }
+
+    /// Whether strict acyclic mode is on.
+    #[wasm_bindgen(js_name = isStrictAcyclic)]
+    pub fn is_strict_acyclic(&self) -> bool {
+        self.strict_acyclic
+    }
+
     /// Compute critical path heights (depth in DAG).
     /// Returns heights as JSON array, or zeros for cyclic graphs.
     #[wasm_bindgen(js_name = criticalPathHeights)]
@@ -224,12 +631,34 @@ impl DiGraph {
         serde_wasm_bindgen::to_value(&heights).unwrap_or(JsValue::NULL)
     }
 
+    /// Critical path heights, failing with CYCLIC_GRAPH instead of returning zeros.
+    #[wasm_bindgen(js_name = tryCriticalPathHeights)]
+    pub fn try_critical_path_heights(&self) -> Result<JsValue, JsError> {
+        use crate::algorithms::critical_path;
+        let heights = critical_path::try_critical_path_heights(self)?;
+        Ok(to_js(&heights)?)
+    }
+
+    /// Critical path heights on the SCC condensation; cycles count as one step.
+    /// Returns JSON: { values: number[], cyclic_nodes: number[] }
+    #[wasm_bindgen(js_name = criticalPathHeightsCondensed)]
+    pub fn critical_path_heights_condensed(&self) -> Result<JsValue, JsError> {
+        use crate::algorithms::critical_path::critical_path_heights_condensed;
+        Ok(to_js(&critical_path_heights_condensed(self))?)
+    }
+
     /// Get nodes on the critical path (those with maximum height).
     #[wasm_bindgen(js_name = criticalPathNodes)]
     pub fn critical_path_nodes(&self) -> JsValue {
+        self.try_critical_path_nodes().unwrap_or(JsValue::NULL)
+    }
+
+    /// `criticalPathNodes`, failing with SERIALIZATION instead of returning null.
+    #[wasm_bindgen(js_name = tryCriticalPathNodes)]
+    pub fn try_critical_path_nodes(&self) -> Result<JsValue, JsError> {
         use crate::algorithms::critical_path;
         let nodes = critical_path::critical_path_nodes(self);
-        serde_wasm_bindgen::to_value(&nodes).unwrap_or(JsValue::NULL)
+        Ok(to_js(&nodes)?)
     }
 
     /// Get the maximum height (critical path length).
@@ -243,6 +672,12 @@ impl DiGraph {
     /// Returns array of scores in node index order.
     #[wasm_bindgen(js_name = pagerank)]
     pub fn pagerank(&self, damping: f64, max_iterations: u32) -> JsValue {
+        self.try_pagerank(damping, max_iterations).unwrap_or(JsValue::NULL)
+    }
+
+    /// `pagerank`, failing with SERIALIZATION instead of returning null.
+    #[wasm_bindgen(js_name = tryPagerank)]
+    pub fn try_pagerank(&self, damping: f64, max_iterations: u32) -> Result<JsValue, JsError> {
         use crate::algorithms::pagerank::{pagerank, PageRankConfig};
         let config = PageRankConfig {
             damping,
@@ -250,36 +685,54 @@ impl DiGraph {
             tolerance: 1e-6,
         };
         let scores = pagerank(self, &config);
-        serde_wasm_bindgen::to_value(&scores).unwrap_or(JsValue::NULL)
+        Ok(to_js(&scores)?)
     }
 
     /// Compute PageRank with default parameters (damping=0.85, max_iterations=100).
     #[wasm_bindgen(js_name = pagerankDefault)]
     pub fn pagerank_default(&self) -> JsValue {
+        self.try_pagerank_default().unwrap_or(JsValue::NULL)
+    }
+
+    /// `pagerankDefault`, failing with SERIALIZATION instead of returning null.
+    #[wasm_bindgen(js_name = tryPagerankDefault)]
+    pub fn try_pagerank_default(&self) -> Result<JsValue, JsError> {
         use crate::algorithms::pagerank::pagerank_default;
         let scores = pagerank_default(self);
-        serde_wasm_bindgen::to_value(&scores).unwrap_or(JsValue::NULL)
+        Ok(to_js(&scores)?)
     }
 
     /// Compute eigenvector centrality using power iteration.
     /// Returns array of scores in node index order, normalized to unit length.
     #[wasm_bindgen(js_name = eigenvector)]
     pub fn eigenvector(&self, iterations: u32) -> JsValue {
+        self.try_eigenvector(iterations).unwrap_or(JsValue::NULL)
+    }
+
+    /// `eigenvector`, failing with SERIALIZATION instead of returning null.
+    #[wasm_bindgen(js_name = tryEigenvector)]
+    pub fn try_eigenvector(&self, iterations: u32) -> Result<JsValue, JsError> {
         use crate::algorithms::eigenvector::{eigenvector, EigenvectorConfig};
         let config = EigenvectorConfig {
             iterations,
             tolerance: 1e-6,
         };
         let scores = eigenvector(self, &config);
-        serde_wasm_bindgen::to_value(&scores).unwrap_or(JsValue::NULL)
+        Ok(to_js(&scores)?)
     }
 
     /// Compute eigenvector centrality with default parameters (50 iterations).
     #[wasm_bindgen(js_name = eigenvectorDefault)]
     pub fn eigenvector_default(&self) -> JsValue {
+        self.try_eigenvector_default().unwrap_or(JsValue::NULL)
+    }
+
+    /// `eigenvectorDefault`, failing with SERIALIZATION instead of returning null.
+    #[wasm_bindgen(js_name = tryEigenvectorDefault)]
+    pub fn try_eigenvector_default(&self) -> Result<JsValue, JsError> {
         use crate::algorithms::eigenvector::eigenvector_default;
         let scores = eigenvector_default(self);
-        serde_wasm_bindgen::to_value(&scores).unwrap_or(JsValue::NULL)
+        Ok(to_js(&scores)?)
     }
 
     /// Compute exact betweenness centrality using Brandes' algorithm.
@@ -287,9 +740,15 @@ impl DiGraph {
     /// Complexity: O(V*E) - use betweenness_approx for large graphs.
     #[wasm_bindgen(js_name = betweenness)]
     pub fn betweenness(&self) -> JsValue {
+        self.try_betweenness().unwrap_or(JsValue::NULL)
+    }
+
+    /// `betweenness`, failing with SERIALIZATION instead of returning null.
+    #[wasm_bindgen(js_name = tryBetweenness)]
+    pub fn try_betweenness(&self) -> Result<JsValue, JsError> {
         use crate::algorithms::betweenness::betweenness;
         let scores = betweenness(self);
-        serde_wasm_bindgen::to_value(&scores).unwrap_or(JsValue::NULL)
+        Ok(to_js(&scores)?)
     }
 
     /// Compute approximate betweenness centrality using sampling.
@@ -297,30 +756,48 @@ impl DiGraph {
     /// Error: O(1/sqrt(k)) - with k=100, ~10% error in ranking.
     #[wasm_bindgen(js_name = betweennessApprox)]
     pub fn betweenness_approx(&self, sample_size: usize) -> JsValue {
+        self.try_betweenness_approx(sample_size).unwrap_or(JsValue::NULL)
+    }
+
+    /// `betweennessApprox`, failing with SERIALIZATION instead of returning null.
+    #[wasm_bindgen(js_name = tryBetweennessApprox)]
+    pub fn try_betweenness_approx(&self, sample_size: usize) -> Result<JsValue, JsError> {
         use crate::algorithms::betweenness::betweenness_approx;
         let scores = betweenness_approx(self, sample_size, None);
-        serde_wasm_bindgen::to_value(&scores).unwrap_or(JsValue::NULL)
+        Ok(to_js(&scores)?)
     }
 
     /// Compute HITS hub and authority scores.
     /// Returns JSON object: { hubs: number[], authorities: number[], iterations: number }
     #[wasm_bindgen(js_name = hits)]
     pub fn hits(&self, tolerance: f64, max_iterations: u32) -> JsValue {
+        self.try_hits(tolerance, max_iterations).unwrap_or(JsValue::NULL)
+    }
+
+    /// `hits`, failing with SERIALIZATION instead of returning null.
+    #[wasm_bindgen(js_name = tryHits)]
+    pub fn try_hits(&self, tolerance: f64, max_iterations: u32) -> Result<JsValue, JsError> {
         use crate::algorithms::hits::{hits, HITSConfig};
         let config = HITSConfig {
             tolerance,
             max_iterations,
         };
         let result = hits(self, &config);
-        serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+        Ok(to_js(&result)?)
     }
 
     /// Compute HITS with default parameters (tolerance=1e-6, max_iterations=100).
     #[wasm_bindgen(js_name = hitsDefault)]
     pub fn hits_default(&self) -> JsValue {
+        self.try_hits_default().unwrap_or(JsValue::NULL)
+    }
+
+    /// `hitsDefault`, failing with SERIALIZATION instead of returning null.
+    #[wasm_bindgen(js_name = tryHitsDefault)]
+    pub fn try_hits_default(&self) -> Result<JsValue, JsError> {
         use crate::algorithms::hits::hits_default;
         let result = hits_default(self);
-        serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+        Ok(to_js(&result)?)
     }
 
     /// Compute k-core numbers for all nodes.
@@ -328,9 +805,15 @@ impl DiGraph {
     /// Returns array of core numbers in node index order.
     #[wasm_bindgen(js_name = kcore)]
     pub fn kcore(&self) -> JsValue {
+        self.try_kcore().unwrap_or(JsValue::NULL)
+    }
+
+    /// `kcore`, failing with SERIALIZATION instead of returning null.
+    #[wasm_bindgen(js_name = tryKcore)]
+    pub fn try_kcore(&self) -> Result<JsValue, JsError> {
         use crate::algorithms::kcore::kcore;
         let cores = kcore(self);
-        serde_wasm_bindgen::to_value(&cores).unwrap_or(JsValue::NULL)
+        Ok(to_js(&cores)?)
     }
 
     /// Get the degeneracy of the graph (maximum core number).
@@ -340,14 +823,37 @@ impl DiGraph {
         degeneracy(self)
     }
 
+    /// Directed core numbers over blocking edges: `{in_core, out_core}`, in
+    /// node index order. `in_core[v]` is the highest k such that v lies in a
+    /// subgraph where every node has at least k blockers; `out_core` counts
+    /// dependents instead.
+    #[wasm_bindgen(js_name = dcore)]
+    pub fn dcore(&self) -> Result<JsValue, JsError> {
+        use crate::algorithms::kcore::dcore;
+        Ok(to_js(&dcore(self))?)
+    }
+
+    /// Members of the maximal (k,l)-D-core: every member has at least `k`
+    /// blockers and `l` dependents among the other members.
+    #[wasm_bindgen(js_name = dcoreMembers)]
+    pub fn dcore_members(&self, k: u32, l: u32) -> Vec<u32> {
+        to_u32(&crate::algorithms::kcore::dcore_members(self, k, l))
+    }
+
     /// Find articulation points (cut vertices) in the graph.
     /// These are nodes whose removal disconnects the graph.
     /// Returns array of node indices.
     #[wasm_bindgen(js_name = articulationPoints)]
     pub fn articulation_points(&self) -> JsValue {
+        self.try_articulation_points().unwrap_or(JsValue::NULL)
+    }
+
+    /// `articulationPoints`, failing with SERIALIZATION instead of returning null.
+    #[wasm_bindgen(js_name = tryArticulationPoints)]
+    pub fn try_articulation_points(&self) -> Result<JsValue, JsError> {
         use crate::algorithms::articulation::articulation_points;
         let ap = articulation_points(self);
-        serde_wasm_bindgen::to_value(&ap).unwrap_or(JsValue::NULL)
+        Ok(to_js(&ap)?)
     }
 
     /// Find bridges (cut edges) in the graph.
@@ -355,18 +861,95 @@ impl DiGraph {
     /// Returns array of [from, to] pairs.
     #[wasm_bindgen(js_name = bridges)]
     pub fn bridges(&self) -> JsValue {
+        self.try_bridges().unwrap_or(JsValue::NULL)
+    }
+
+    /// `bridges`, failing with SERIALIZATION instead of returning null.
+    #[wasm_bindgen(js_name = tryBridges)]
+    pub fn try_bridges(&self) -> Result<JsValue, JsError> {
         use crate::algorithms::articulation::bridges;
         let br = bridges(self);
-        serde_wasm_bindgen::to_value(&br).unwrap_or(JsValue::NULL)
+        Ok(to_js(&br)?)
+    }
+
+    /// Biconnected components (blocks) of the undirected view: arrays of node
+    /// indices, each ascending, ordered by smallest member.
+    #[wasm_bindgen(js_name = biconnectedComponents)]
+    pub fn biconnected_components(&self) -> Result<JsValue, JsError> {
+        use crate::algorithms::articulation::biconnected_components;
+        Ok(to_js(&biconnected_components(self))?)
+    }
+
+    /// Block-cut tree: `{blocks, cut_vertices, edges}`. Each cut vertex
+    /// lists the sizes of the pieces its removal leaves (`parts`, largest
+    /// first) and how many nodes are cut off from the largest (`disconnected`).
+    /// Edges are `[blockIndex, node]` pairs.
+    #[wasm_bindgen(js_name = blockCutTree)]
+    pub fn block_cut_tree(&self) -> Result<JsValue, JsError> {
+        use crate::algorithms::articulation::block_cut_tree;
+        Ok(to_js(&block_cut_tree(self))?)
+    }
+
+    /// Immediate dominator of every node as an Int32Array (-1 when only the
+    /// virtual root above all sources dominates it, or for removed slots).
+    #[wasm_bindgen(js_name = dominatorTree)]
+    pub fn dominator_tree(&self) -> Vec<i32> {
+        use crate::algorithms::dominators::dominator_tree;
+        idom_array(&dominator_tree(self).idom)
+    }
+
+    /// Immediate post-dominator of every node as an Int32Array (-1 when only
+    /// the virtual exit below all sinks post-dominates it).
+    #[wasm_bindgen(js_name = postDominatorTree)]
+    pub fn post_dominator_tree(&self) -> Vec<i32> {
+        use crate::algorithms::dominators::post_dominator_tree;
+        idom_array(&post_dominator_tree(self).idom)
+    }
+
+    /// Issues every blocking chain into `node` passes through (Uint32Array),
+    /// in blocking order, the immediate dominator last.
+    #[wasm_bindgen(js_name = dominators)]
+    pub fn dominators(&self, node: usize) -> Result<Vec<u32>, JsError> {
+        use crate::algorithms::dominators::dominators;
+        self.check_node(node)?;
+        Ok(to_u32(&dominators(self, node)))
+    }
+
+    /// Issues every blocking chain out of `node` passes through (Uint32Array),
+    /// in blocking order, the immediate post-dominator first.
+    #[wasm_bindgen(js_name = postDominators)]
+    pub fn post_dominators(&self, node: usize) -> Result<Vec<u32>, JsError> {
+        use crate::algorithms::dominators::post_dominators;
+        self.check_node(node)?;
+        Ok(to_u32(&post_dominators(self, node)))
+    }
+
+    /// Issues outside `targets` that every blocking chain into every target
+    /// passes through, e.g. the unconditional gates of a release (Uint32Array,
+    /// blocking order).
+    #[wasm_bindgen(js_name = gatekeepers)]
+    pub fn gatekeepers(&self, targets: &[u32]) -> Result<Vec<u32>, JsError> {
+        use crate::algorithms::dominators::gatekeepers;
+        let targets: Vec<usize> = targets.iter().map(|&t| t as usize).collect();
+        for &t in &targets {
+            self.check_node(t)?;
+        }
+        Ok(to_u32(&gatekeepers(self, &targets)))
     }
 
     /// Find strongly connected components using Tarjan's algorithm.
     /// Returns JSON: { components: number[][], has_cycles: bool, cycle_count: number }
     #[wasm_bindgen(js_name = tarjanScc)]
     pub fn tarjan_scc(&self) -> JsValue {
+        self.try_tarjan_scc().unwrap_or(JsValue::NULL)
+    }
+
+    /// `tarjanScc`, failing with SERIALIZATION instead of returning null.
+    #[wasm_bindgen(js_name = tryTarjanScc)]
+    pub fn try_tarjan_scc(&self) -> Result<JsValue, JsError> {
         use crate::algorithms::cycles::tarjan_scc;
         let result = tarjan_scc(self);
-        serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+        Ok(to_js(&result)?)
     }
 
     /// Check if graph has any cycles.
@@ -385,14 +968,28 @@ impl DiGraph {
         serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
     }
 
+    /// Enumerate cycles, failing with LIMIT_EXCEEDED if max_cycles is above the crate cap.
+    #[wasm_bindgen(js_name = tryEnumerateCycles)]
+    pub fn try_enumerate_cycles(&self, max_cycles: usize) -> Result<JsValue, JsError> {
+        use crate::algorithms::cycles::try_enumerate_cycles_with_info;
+        let result = try_enumerate_cycles_with_info(self, max_cycles)?;
+        Ok(to_js(&result)?)
+    }
+
     /// Suggest edges to remove to break cycles.
     /// Returns JSON: { suggestions: [{from, to, cycles_broken, collateral, from_id, to_id}], total_cycles, truncated }
     /// Suggestions are sorted by cycles_broken desc, then collateral asc.
     #[wasm_bindgen(js_name = cycleBreakSuggestions)]
     pub fn cycle_break_suggestions(&self, limit: usize, max_cycles_to_enumerate: usize) -> JsValue {
+        self.try_cycle_break_suggestions(limit, max_cycles_to_enumerate).unwrap_or(JsValue::NULL)
+    }
+
+    /// `cycleBreakSuggestions`, failing with SERIALIZATION instead of returning null.
+    #[wasm_bindgen(js_name = tryCycleBreakSuggestions)]
+    pub fn try_cycle_break_suggestions(&self, limit: usize, max_cycles_to_enumerate: usize) -> Result<JsValue, JsError> {
         use crate::algorithms::cycles::cycle_break_suggestions;
         let result = cycle_break_suggestions(self, limit, max_cycles_to_enumerate);
-        serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+        Ok(to_js(&result)?)
     }
 
     /// Quick cycle break suggestions (faster, less precise).
@@ -400,9 +997,42 @@ impl DiGraph {
     /// Returns JSON array of { from, to, collateral, from_id, to_id }.
     #[wasm_bindgen(js_name = quickCycleBreakEdges)]
     pub fn quick_cycle_break_edges(&self, limit: usize) -> JsValue {
+        self.try_quick_cycle_break_edges(limit).unwrap_or(JsValue::NULL)
+    }
+
+    /// `quickCycleBreakEdges`, failing with SERIALIZATION instead of returning null.
+    #[wasm_bindgen(js_name = tryQuickCycleBreakEdges)]
+    pub fn try_quick_cycle_break_edges(&self, limit: usize) -> Result<JsValue, JsError> {
         use crate::algorithms::cycles::quick_cycle_break_edges;
         let result = quick_cycle_break_edges(self, limit);
-        serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+        Ok(to_js(&result)?)
+    }
+
+    /// Blocking edges implied by a longer path (A -> C when A -> B -> C exists).
+    /// Returns JSON: { redundant: [{from, to, path, from_id, to_id}], kept_edges, cyclic_nodes }
+    /// Edges inside blocking cycles are never reported.
+    #[wasm_bindgen(js_name = redundantEdges)]
+    pub fn redundant_edges(&self) -> Result<JsValue, JsError> {
+        use crate::algorithms::transitive_reduction::transitive_reduction;
+        Ok(to_js(&transitive_reduction(self))?)
+    }
+
+    /// Copy of the graph with redundant blocking edges removed (transitive
+    /// reduction). Indices and attributes are unchanged, so any analysis can
+    /// run on the reduced graph and map straight back.
+    #[wasm_bindgen(js_name = transitiveReduction)]
+    pub fn transitive_reduction(&self) -> DiGraph {
+        crate::algorithms::transitive_reduction::reduced_graph(self)
+    }
+
+    /// Feedback arc set: a small set of blocking edges whose removal makes the
+    /// graph acyclic (Eades–Lin–Smyth plus local search, per SCC). Minimal
+    /// for components up to 2,000 nodes; `minimal` reports whether it is.
+    /// Returns JSON: { edges: [{from, to, cycles_broken, collateral, from_id, to_id}], acyclic, minimal, cyclic_components }
+    #[wasm_bindgen(js_name = feedbackArcSet)]
+    pub fn feedback_arc_set(&self) -> Result<JsValue, JsError> {
+        use crate::algorithms::feedback_arc::feedback_arc_set;
+        Ok(to_js(&feedback_arc_set(self))?)
     }
 
     /// Compute slack for each node in the DAG.
@@ -416,6 +1046,22 @@ impl DiGraph {
         serde_wasm_bindgen::to_value(&s).unwrap_or(JsValue::NULL)
     }
 
+    /// Slack per node, failing with CYCLIC_GRAPH instead of returning zeros.
+    #[wasm_bindgen(js_name = trySlack)]
+    pub fn try_slack(&self) -> Result<JsValue, JsError> {
+        use crate::algorithms::slack::try_slack;
+        let s = try_slack(self)?;
+        Ok(to_js(&s)?)
+    }
+
+    /// Slack on the SCC condensation; cycles count as one step.
+    /// Returns JSON: { values: number[], cyclic_nodes: number[] }
+    #[wasm_bindgen(js_name = slackCondensed)]
+    pub fn slack_condensed(&self) -> Result<JsValue, JsError> {
+        use crate::algorithms::slack::slack_condensed;
+        Ok(to_js(&slack_condensed(self))?)
+    }
+
     /// Get the total float (maximum slack) in the graph.
     #[wasm_bindgen(js_name = totalFloat)]
     pub fn total_float(&self) -> f64 {
@@ -423,47 +1069,160 @@ impl DiGraph {
         total_float(self)
     }
 
+    /// Duration-weighted CPM using each issue's `estimated_minutes`
+    /// (`defaultMinutes` where unset). Returns JSON: { early_start,
+    /// early_finish, late_start, late_finish, total_float, free_float,
+    /// makespan, critical_chain: {nodes, ids} }. Throws `CYCLIC_GRAPH`.
+    #[wasm_bindgen(js_name = cpm)]
+    pub fn cpm(&self, default_minutes: f64) -> Result<JsValue, JsError> {
+        use crate::algorithms::cpm::{cpm, estimated_durations};
+        let durations = estimated_durations(self, default_minutes);
+        Ok(to_js(&cpm(self, &durations)?)?)
+    }
+
+    /// Duration-weighted CPM with one duration per slot (negative and NaN
+    /// count as 0). Throws `LENGTH_MISMATCH` or `CYCLIC_GRAPH`.
+    #[wasm_bindgen(js_name = cpmWithDurations)]
+    pub fn cpm_with_durations(&self, durations: &[f64]) -> Result<JsValue, JsError> {
+        use crate::algorithms::cpm::cpm;
+        Ok(to_js(&cpm(self, durations)?)?)
+    }
+
+    /// Seeded Monte Carlo completion forecast from three-point estimates,
+    /// one Float64Array entry per slot each.
+    /// Options JSON (may be empty): { distribution?: "pert" | "triangular",
+    /// samples?, seed?, targets?, percentiles? }.
+    /// Returns JSON: { samples, percentiles, target_finish, target_mean,
+    /// node_finish, criticality }. Throws `CYCLIC_GRAPH`, `LENGTH_MISMATCH`
+    /// or `LIMIT_EXCEEDED`.
+    #[wasm_bindgen(js_name = forecast)]
+    pub fn forecast(
+        &self,
+        optimistic: &[f64],
+        likely: &[f64],
+        pessimistic: &[f64],
+        options_json: &str,
+    ) -> Result<JsValue, JsError> {
+        use crate::algorithms::monte_carlo::{forecast, ForecastOptions};
+        let options: ForecastOptions = parse_options(options_json)?;
+        Ok(to_js(&forecast(self, optimistic, likely, pessimistic, &options)?)?)
+    }
+
+    /// List-schedule the open issues onto `workers` parallel workers, with
+    /// one duration per slot. closed_set is an array of bytes where non-zero
+    /// means closed.
+    /// Options JSON (may be empty): { rule?: "critical_path" | "most_unblocks"
+    /// | "priority", team?: [{ name?, skills? }] }.
+    /// Returns JSON: { timeline: [{worker, node, node_id, start, end}],
+    /// makespan, workers, total_idle, utilization, unscheduled }, or throws
+    /// `INVALID_ARGUMENT` when there are no workers.
+    #[wasm_bindgen(js_name = schedule)]
+    pub fn schedule(
+        &self,
+        closed_set: &[u8],
+        durations: &[f64],
+        workers: usize,
+        options_json: &str,
+    ) -> Result<JsValue, JsError> {
+        use crate::algorithms::schedule::{schedule, ScheduleOptions};
+        let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
+        let options: ScheduleOptions = parse_options(options_json)?;
+        Ok(to_js(&schedule(self, &closed, durations, workers, &options)?)?)
+    }
+
+    /// List scheduling under a `WorkState`.
+    #[wasm_bindgen(js_name = scheduleWithState)]
+    pub fn schedule_with_state(
+        &self,
+        state: &WorkState,
+        durations: &[f64],
+        workers: usize,
+        options_json: &str,
+    ) -> Result<JsValue, JsError> {
+        use crate::algorithms::schedule::{schedule, ScheduleOptions};
+        let options: ScheduleOptions = parse_options(options_json)?;
+        Ok(to_js(&schedule(self, state.resolved(), durations, workers, &options)?)?)
+    }
+
     /// Compute coverage set (greedy vertex cover).
     /// Finds nodes that collectively "cover" all edges in the graph.
     /// Returns JSON: { items: [{node, edges_added}], edges_covered, total_edges, coverage_ratio }
     #[wasm_bindgen(js_name = coverageSet)]
     pub fn coverage_set(&self, limit: usize) -> JsValue {
+        self.try_coverage_set(limit).unwrap_or(JsValue::NULL)
+    }
+
+    /// `coverageSet`, failing with SERIALIZATION instead of returning null.
+    #[wasm_bindgen(js_name = tryCoverageSet)]
+    pub fn try_coverage_set(&self, limit: usize) -> Result<JsValue, JsError> {
         use crate::algorithms::coverage::coverage_set;
         let result = coverage_set(self, limit);
-        serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+        Ok(to_js(&result)?)
     }
 
     /// Compute coverage set with default limit of 10.
     #[wasm_bindgen(js_name = coverageSetDefault)]
     pub fn coverage_set_default(&self) -> JsValue {
+        self.try_coverage_set_default().unwrap_or(JsValue::NULL)
+    }
+
+    /// `coverageSetDefault`, failing with SERIALIZATION instead of returning null.
+    #[wasm_bindgen(js_name = tryCoverageSetDefault)]
+    pub fn try_coverage_set_default(&self) -> Result<JsValue, JsError> {
         use crate::algorithms::coverage::coverage_set_default;
         let result = coverage_set_default(self);
-        serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+        Ok(to_js(&result)?)
     }
 
     /// Get just the node indices from coverage set computation.
     #[wasm_bindgen(js_name = coverageNodes)]
     pub fn coverage_nodes(&self, limit: usize) -> JsValue {
+        self.try_coverage_nodes(limit).unwrap_or(JsValue::NULL)
+    }
+
+    /// `coverageNodes`, failing with SERIALIZATION instead of returning null.
+    #[wasm_bindgen(js_name = tryCoverageNodes)]
+    pub fn try_coverage_nodes(&self, limit: usize) -> Result<JsValue, JsError> {
         use crate::algorithms::coverage::coverage_nodes;
         let nodes = coverage_nodes(self, limit);
-        serde_wasm_bindgen::to_value(&nodes).unwrap_or(JsValue::NULL)
+        Ok(to_js(&nodes)?)
     }
 
     /// Find k longest paths through the DAG.
     /// Returns JSON: { paths: [{nodes, length}], total_nodes, max_length }
     #[wasm_bindgen(js_name = kCriticalPaths)]
     pub fn k_critical_paths(&self, k: usize) -> JsValue {
+        self.try_k_critical_paths(k).unwrap_or(JsValue::NULL)
+    }
+
+    /// `kCriticalPaths`, failing with SERIALIZATION instead of returning null.
+    #[wasm_bindgen(js_name = tryKCriticalPaths)]
+    pub fn try_k_critical_paths(&self, k: usize) -> Result<JsValue, JsError> {
         use crate::algorithms::k_paths::k_critical_paths;
         let result = k_critical_paths(self, k);
-        serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+        Ok(to_js(&result)?)
+    }
+
+    /// K longest paths on the SCC condensation; cycles count as one step.
+    /// Returns JSON: { paths: [{nodes, length}], total_nodes, max_length, cyclic_nodes }
+    #[wasm_bindgen(js_name = kCriticalPathsCondensed)]
+    pub fn k_critical_paths_condensed(&self, k: usize) -> Result<JsValue, JsError> {
+        use crate::algorithms::k_paths::k_critical_paths_condensed;
+        Ok(to_js(&k_critical_paths_condensed(self, k))?)
     }
 
     /// Find k longest paths with default k=5.
     #[wasm_bindgen(js_name = kCriticalPathsDefault)]
     pub fn k_critical_paths_default(&self) -> JsValue {
+        self.try_k_critical_paths_default().unwrap_or(JsValue::NULL)
+    }
+
+    /// `kCriticalPathsDefault`, failing with SERIALIZATION instead of returning null.
+    #[wasm_bindgen(js_name = tryKCriticalPathsDefault)]
+    pub fn try_k_critical_paths_default(&self) -> Result<JsValue, JsError> {
         use crate::algorithms::k_paths::k_critical_paths_default;
         let result = k_critical_paths_default(self);
-        serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+        Ok(to_js(&result)?)
     }
 
     /// Find nodes that increase parallelization when completed.
@@ -471,20 +1230,40 @@ impl DiGraph {
     /// closed_set is an array of bytes where non-zero means closed.
     #[wasm_bindgen(js_name = parallelCutSuggestions)]
     pub fn parallel_cut_suggestions(&self, closed_set: &[u8], limit: usize) -> JsValue {
+        self.try_parallel_cut_suggestions(closed_set, limit).unwrap_or(JsValue::NULL)
+    }
+
+    /// `parallelCutSuggestions`, failing with SERIALIZATION instead of returning null.
+    #[wasm_bindgen(js_name = tryParallelCutSuggestions)]
+    pub fn try_parallel_cut_suggestions(&self, closed_set: &[u8], limit: usize) -> Result<JsValue, JsError> {
         use crate::algorithms::parallel_cut::parallel_cut_suggestions;
         let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
         let result = parallel_cut_suggestions(self, &closed, limit);
-        serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+        Ok(to_js(&result)?)
+    }
+
+    /// Parallel cut suggestions under a `WorkState`.
+    #[wasm_bindgen(js_name = parallelCutSuggestionsWithState)]
+    pub fn parallel_cut_suggestions_with_state(&self, state: &WorkState, limit: usize) -> Result<JsValue, JsError> {
+        use crate::algorithms::parallel_cut::parallel_cut_suggestions;
+        let result = parallel_cut_suggestions(self, state.resolved(), limit);
+        Ok(to_js(&result)?)
     }
 
     /// Find parallel cut suggestions with default limit of 10.
     /// closed_set is an array of bytes where non-zero means closed.
     #[wasm_bindgen(js_name = parallelCutDefault)]
     pub fn parallel_cut_default(&self, closed_set: &[u8]) -> JsValue {
+        self.try_parallel_cut_default(closed_set).unwrap_or(JsValue::NULL)
+    }
+
+    /// `parallelCutDefault`, failing with SERIALIZATION instead of returning null.
+    #[wasm_bindgen(js_name = tryParallelCutDefault)]
+    pub fn try_parallel_cut_default(&self, closed_set: &[u8]) -> Result<JsValue, JsError> {
         use crate::algorithms::parallel_cut::parallel_cut_default;
         let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
         let result = parallel_cut_default(self, &closed);
-        serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+        Ok(to_js(&result)?)
     }
 
     /// Get nodes ranked by how many dependents they unblock.
@@ -492,10 +1271,24 @@ impl DiGraph {
     /// closed_set is an array of bytes where non-zero means closed.
     #[wasm_bindgen(js_name = unblockRanking)]
     pub fn unblock_ranking(&self, closed_set: &[u8], limit: usize) -> JsValue {
+        self.try_unblock_ranking(closed_set, limit).unwrap_or(JsValue::NULL)
+    }
+
+    /// `unblockRanking`, failing with SERIALIZATION instead of returning null.
+    #[wasm_bindgen(js_name = tryUnblockRanking)]
+    pub fn try_unblock_ranking(&self, closed_set: &[u8], limit: usize) -> Result<JsValue, JsError> {
         use crate::algorithms::parallel_cut::unblock_ranking;
         let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
         let result = unblock_ranking(self, &closed, limit);
-        serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+        Ok(to_js(&result)?)
+    }
+
+    /// Unblock ranking under a `WorkState`.
+    #[wasm_bindgen(js_name = unblockRankingWithState)]
+    pub fn unblock_ranking_with_state(&self, state: &WorkState, limit: usize) -> Result<JsValue, JsError> {
+        use crate::algorithms::parallel_cut::unblock_ranking;
+        let result = unblock_ranking(self, state.resolved(), limit);
+        Ok(to_js(&result)?)
     }
 
     /// Extract a subgraph containing only the specified node indices.
@@ -506,22 +1299,123 @@ impl DiGraph {
         extract_subgraph(self, indices)
     }
 
+    /// Weakly connected components (independent work islands), any edge kind.
+    /// Open/closed counts use the stored statuses.
+    /// Returns JSON: { component: number[] (-1 = removed), components: [{id, size, edges,
+    /// open, closed, depth, has_cycle}] }
+    #[wasm_bindgen(js_name = connectedComponents)]
+    pub fn connected_components(&self) -> Result<JsValue, JsError> {
+        use crate::algorithms::components::connected_components;
+        Ok(to_js(&connected_components(self, &self.attrs.resolved_mask()))?)
+    }
+
+    /// `connectedComponents` with open/closed counts taken from a `WorkState`.
+    #[wasm_bindgen(js_name = connectedComponentsWithState)]
+    pub fn connected_components_with_state(&self, state: &WorkState) -> Result<JsValue, JsError> {
+        use crate::algorithms::components::connected_components;
+        Ok(to_js(&connected_components(self, state.resolved()))?)
+    }
+
+    /// Maximum parallelism of the open issues (Dilworth width).
+    /// closed_set is an array of bytes where non-zero means closed.
+    /// Returns JSON: { width, antichain, chains: number[][], open_nodes,
+    /// cyclic_nodes }. Throws `LIMIT_EXCEEDED` above 10000 open components.
+    #[wasm_bindgen(js_name = parallelWidth)]
+    pub fn parallel_width(&self, closed_set: &[u8]) -> Result<JsValue, JsError> {
+        use crate::algorithms::width::parallel_width;
+        let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
+        Ok(to_js(&parallel_width(self, &closed)?)?)
+    }
+
+    /// `parallelWidth` under a `WorkState`.
+    #[wasm_bindgen(js_name = parallelWidthWithState)]
+    pub fn parallel_width_with_state(&self, state: &WorkState) -> Result<JsValue, JsError> {
+        use crate::algorithms::width::parallel_width;
+        Ok(to_js(&parallel_width(self, state.resolved())?)?)
+    }
+
+    /// One component as its own graph (renumbered like `subgraph`), so any
+    /// analysis can run per island. Empty for an unknown id.
+    #[wasm_bindgen(js_name = componentSubgraph)]
+    pub fn component_subgraph(&self, id: usize) -> DiGraph {
+        use crate::algorithms::components::connected_components;
+        use crate::algorithms::subgraph::extract_subgraph;
+        let members = connected_components(self, &[]).members(id);
+        extract_subgraph(self, &members)
+    }
+
     /// Get all node indices reachable from a source node (outgoing direction).
     #[wasm_bindgen(js_name = reachableFrom)]
     pub fn reachable_from(&self, source: usize) -> JsValue {
-        use crate::algorithms::subgraph::reachable_from;
+        use crate::reachability::reachable_from;
         let nodes = reachable_from(self, source);
         serde_wasm_bindgen::to_value(&nodes).unwrap_or(JsValue::NULL)
     }
 
+    /// Nodes reachable from source, failing with INVALID_NODE on a bad index.
+    #[wasm_bindgen(js_name = tryReachableFrom)]
+    pub fn try_reachable_from(&self, source: usize) -> Result<JsValue, JsError> {
+        use crate::reachability::reachable_from;
+        self.check_node(source)?;
+        Ok(to_js(&reachable_from(self, source))?)
+    }
+
     /// Get all node indices that can reach a target node (incoming direction).
     #[wasm_bindgen(js_name = reachableTo)]
     pub fn reachable_to(&self, target: usize) -> JsValue {
-        use crate::algorithms::subgraph::reachable_to;
+        use crate::reachability::reachable_to;
         let nodes = reachable_to(self, target);
         serde_wasm_bindgen::to_value(&nodes).unwrap_or(JsValue::NULL)
     }
 
+    /// Nodes that can reach target, failing with INVALID_NODE on a bad index.
+    #[wasm_bindgen(js_name = tryReachableTo)]
+    pub fn try_reachable_to(&self, target: usize) -> Result<JsValue, JsError> {
+        use crate::reachability::reachable_to;
+        self.check_node(target)?;
+        Ok(to_js(&reachable_to(self, target))?)
+    }
+
+    /// Shortest blocking path from -> ... -> to.
+    /// Returns JSON { nodes, ids } or null if `to` is unreachable.
+    #[wasm_bindgen(js_name = shortestPath)]
+    pub fn shortest_path(&self, from: usize, to: usize) -> Result<JsValue, JsError> {
+        use crate::reachability::{shortest_path, NodePath};
+        self.check_node(from)?;
+        self.check_node(to)?;
+        let path = shortest_path(self, from, to).map(|nodes| NodePath::new(self, nodes));
+        Ok(to_js(&path)?)
+    }
+
+    /// Up to `limit` simple blocking paths from -> ... -> to (at most MAX_PATHS).
+    /// Returns JSON: { paths: [{nodes, ids}], truncated }
+    #[wasm_bindgen(js_name = allPaths)]
+    pub fn all_paths(&self, from: usize, to: usize, limit: usize) -> Result<JsValue, JsError> {
+        use crate::reachability::try_all_paths;
+        self.check_node(from)?;
+        self.check_node(to)?;
+        Ok(to_js(&try_all_paths(self, from, to, limit)?)?)
+    }
+
+    /// Why is a node blocked? One chain of open issues per open root blocker.
+    /// closed_set is an array of bytes where non-zero means closed.
+    /// Returns JSON: { node, node_id, blocked, chains: [{nodes, ids}] }
+    #[wasm_bindgen(js_name = explainBlocked)]
+    pub fn explain_blocked(&self, node: usize, closed_set: &[u8]) -> Result<JsValue, JsError> {
+        use crate::reachability::explain_blocked;
+        self.check_node(node)?;
+        let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
+        Ok(to_js(&explain_blocked(self, node, &closed))?)
+    }
+
+    /// `explainBlocked` under a `WorkState`.
+    #[wasm_bindgen(js_name = explainBlockedWithState)]
+    pub fn explain_blocked_with_state(&self, node: usize, state: &WorkState) -> Result<JsValue, JsError> {
+        use crate::reachability::explain_blocked;
+        self.check_node(node)?;
+        Ok(to_js(&explain_blocked(self, node, state.resolved()))?)
+    }
+
     /// Get all nodes in the dependency cone (ancestors + node + descendants).
     #[wasm_bindgen(js_name = dependencyCone)]
     pub fn dependency_cone(&self, node: usize) -> JsValue {
@@ -530,6 +1424,15 @@ impl DiGraph {
         serde_wasm_bindgen::to_value(&nodes).unwrap_or(JsValue::NULL)
     }
 
+    /// `dependencyCone`, failing with INVALID_NODE or REMOVED_NODE on a bad index.
+    #[wasm_bindgen(js_name = tryDependencyCone)]
+    pub fn try_dependency_cone(&self, node: usize) -> Result<JsValue, JsError> {
+        use crate::algorithms::subgraph::dependency_cone;
+        self.check_node(node)?;
+        let nodes = dependency_cone(self, node);
+        Ok(to_js(&nodes)?)
+    }
+
     // ========================================================================
     // Actionable queries (work with closed_set to determine workable items)
     // ========================================================================
@@ -543,6 +1446,14 @@ impl DiGraph {
         serde_wasm_bindgen::to_value(&nodes).unwrap_or(JsValue::NULL)
     }
 
+    /// Direct blockers, failing with INVALID_NODE on a bad index.
+    #[wasm_bindgen(js_name = tryBlockers)]
+    pub fn try_blockers(&self, node: usize) -> Result<JsValue, JsError> {
+        use crate::reachability::blockers;
+        self.check_node(node)?;
+        Ok(to_js(&blockers(self, node))?)
+    }
+
     /// Get direct dependents (successors) of a node.
     /// These are issues that depend on this node being completed.
     #[wasm_bindgen(js_name = dependents)]
@@ -552,17 +1463,39 @@ impl DiGraph {
         serde_wasm_bindgen::to_value(&nodes).unwrap_or(JsValue::NULL)
     }
 
+    /// Direct dependents, failing with INVALID_NODE on a bad index.
+    #[wasm_bindgen(js_name = tryDependents)]
+    pub fn try_dependents(&self, node: usize) -> Result<JsValue, JsError> {
+        use crate::reachability::dependents;
+        self.check_node(node)?;
+        Ok(to_js(&dependents(self, node))?)
+    }
+
     /// Get all actionable nodes (nodes with all predecessors in closed_set).
     /// closed_set is an array of bytes where non-zero means closed.
     #[wasm_bindgen(js_name = actionableNodes)]
     pub fn actionable_nodes(&self, closed_set: &[u8]) -> JsValue {
+        self.try_actionable_nodes(closed_set).unwrap_or(JsValue::NULL)
+    }
+
+    /// `actionableNodes`, failing with SERIALIZATION instead of returning null.
+    #[wasm_bindgen(js_name = tryActionableNodes)]
+    pub fn try_actionable_nodes(&self, closed_set: &[u8]) -> Result<JsValue, JsError> {
         use crate::reachability::actionable_nodes;
         let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
         let nodes = actionable_nodes(self, &closed);
-        serde_wasm_bindgen::to_value(&nodes).unwrap_or(JsValue::NULL)
+        Ok(to_js(&nodes)?)
     }
 
-    /// Get open blockers for a node (predecessors not in closed_set).
+    /// Actionable nodes under a `WorkState` (closed and tombstoned issues are resolved).
+    #[wasm_bindgen(js_name = actionableNodesWithState)]
+    pub fn actionable_nodes_with_state(&self, state: &WorkState) -> Result<JsValue, JsError> {
+        use crate::reachability::actionable_nodes;
+        let nodes = actionable_nodes(self, state.resolved());
+        Ok(to_js(&nodes)?)
+    }
+
+    /// Get open blockers for a node (predecessors not in closed_set).
     /// closed_set is an array of bytes where non-zero means closed.
     #[wasm_bindgen(js_name = openBlockers)]
     pub fn open_blockers(&self, node: usize, closed_set: &[u8]) -> JsValue {
@@ -572,6 +1505,25 @@ impl DiGraph {
         serde_wasm_bindgen::to_value(&nodes).unwrap_or(JsValue::NULL)
     }
 
+    /// `openBlockers`, failing with INVALID_NODE or REMOVED_NODE on a bad index.
+    #[wasm_bindgen(js_name = tryOpenBlockers)]
+    pub fn try_open_blockers(&self, node: usize, closed_set: &[u8]) -> Result<JsValue, JsError> {
+        use crate::reachability::open_blockers;
+        self.check_node(node)?;
+        let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
+        let nodes = open_blockers(self, node, &closed);
+        Ok(to_js(&nodes)?)
+    }
+
+    /// Open blockers for a node under a `WorkState`, failing with INVALID_NODE on a bad index.
+    #[wasm_bindgen(js_name = openBlockersWithState)]
+    pub fn open_blockers_with_state(&self, node: usize, state: &WorkState) -> Result<JsValue, JsError> {
+        use crate::reachability::open_blockers;
+        self.check_node(node)?;
+        let nodes = open_blockers(self, node, state.resolved());
+        Ok(to_js(&nodes)?)
+    }
+
     /// Get count of open blockers for a node.
     /// closed_set is an array of bytes where non-zero means closed.
     #[wasm_bindgen(js_name = openBlockerCount)]
@@ -596,6 +1548,24 @@ impl DiGraph {
         serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
     }
 
+    /// What-if close under a `WorkState`, failing with INVALID_NODE on a bad index.
+    #[wasm_bindgen(js_name = whatIfCloseWithState)]
+    pub fn what_if_close_with_state(&self, node: usize, state: &WorkState) -> Result<JsValue, JsError> {
+        use crate::whatif::what_if_close;
+        self.check_node(node)?;
+        let result = what_if_close(self, node, state.resolved());
+        Ok(to_js(&result)?)
+    }
+
+    /// What-if close, failing with INVALID_NODE on a bad index.
+    #[wasm_bindgen(js_name = tryWhatIfClose)]
+    pub fn try_what_if_close(&self, node: usize, closed_set: &[u8]) -> Result<JsValue, JsError> {
+        use crate::whatif::what_if_close;
+        self.check_node(node)?;
+        let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
+        Ok(to_js(&what_if_close(self, node, &closed))?)
+    }
+
     /// Batch what-if: compute impact of closing multiple nodes together.
     /// Returns JSON with combined cascade impact.
     #[wasm_bindgen(js_name = whatIfCloseBatch)]
@@ -606,15 +1576,33 @@ impl DiGraph {
         serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
     }
 
+    /// `whatIfCloseBatch`, failing with INVALID_NODE or REMOVED_NODE on a bad index.
+    #[wasm_bindgen(js_name = tryWhatIfCloseBatch)]
+    pub fn try_what_if_close_batch(&self, nodes: &[usize], closed_set: &[u8]) -> Result<JsValue, JsError> {
+        use crate::whatif::what_if_close_batch;
+        for &node in nodes {
+            self.check_node(node)?;
+        }
+        let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
+        let result = what_if_close_batch(self, nodes, &closed);
+        Ok(to_js(&result)?)
+    }
+
     /// Top N issues by cascade impact.
     /// Only considers currently actionable nodes.
     /// Returns JSON array of {node, result} sorted by transitive_unblocks.
     #[wasm_bindgen(js_name = topWhatIf)]
     pub fn top_what_if(&self, closed_set: &[u8], limit: usize) -> JsValue {
+        self.try_top_what_if(closed_set, limit).unwrap_or(JsValue::NULL)
+    }
+
+    /// `topWhatIf`, failing with SERIALIZATION instead of returning null.
+    #[wasm_bindgen(js_name = tryTopWhatIf)]
+    pub fn try_top_what_if(&self, closed_set: &[u8], limit: usize) -> Result<JsValue, JsError> {
         use crate::whatif::top_what_if;
         let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
         let results = top_what_if(self, &closed, limit);
-        serde_wasm_bindgen::to_value(&results).unwrap_or(JsValue::NULL)
+        Ok(to_js(&results)?)
     }
 
     /// All issues with cascade impact, sorted by impact.
@@ -622,10 +1610,35 @@ impl DiGraph {
     /// Returns JSON array of {node, result} sorted by transitive_unblocks.
     #[wasm_bindgen(js_name = allWhatIf)]
     pub fn all_what_if(&self, closed_set: &[u8], limit: usize) -> JsValue {
+        self.try_all_what_if(closed_set, limit).unwrap_or(JsValue::NULL)
+    }
+
+    /// `allWhatIf`, failing with SERIALIZATION instead of returning null.
+    #[wasm_bindgen(js_name = tryAllWhatIf)]
+    pub fn try_all_what_if(&self, closed_set: &[u8], limit: usize) -> Result<JsValue, JsError> {
         use crate::whatif::all_what_if;
         let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
         let results = all_what_if(self, &closed, limit);
-        serde_wasm_bindgen::to_value(&results).unwrap_or(JsValue::NULL)
+        Ok(to_js(&results)?)
+    }
+
+    /// Open issues split into execution waves: wave 0 is `actionableNodes`,
+    /// wave k + 1 becomes actionable once wave k closes.
+    /// closed_set is an array of bytes where non-zero means closed.
+    /// Returns JSON: { waves: [{index, nodes, size, effort_minutes,
+    /// cumulative_minutes, unestimated}], unreachable, open_nodes }
+    #[wasm_bindgen(js_name = executionWaves)]
+    pub fn execution_waves(&self, closed_set: &[u8]) -> Result<JsValue, JsError> {
+        use crate::whatif::execution_waves;
+        let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
+        Ok(to_js(&execution_waves(self, &closed))?)
+    }
+
+    /// Execution waves under a `WorkState`.
+    #[wasm_bindgen(js_name = executionWavesWithState)]
+    pub fn execution_waves_with_state(&self, state: &WorkState) -> Result<JsValue, JsError> {
+        use crate::whatif::execution_waves;
+        Ok(to_js(&execution_waves(self, state.resolved()))?)
     }
 
     // ========================================================================
@@ -638,25 +1651,662 @@ impl DiGraph {
     /// closed_set is an array of bytes where non-zero means closed.
     #[wasm_bindgen(js_name = topkSet)]
     pub fn topk_set(&self, closed_set: &[u8], k: usize) -> JsValue {
+        self.try_topk_set(closed_set, k).unwrap_or(JsValue::NULL)
+    }
+
+    /// `topkSet`, failing with SERIALIZATION instead of returning null.
+    #[wasm_bindgen(js_name = tryTopkSet)]
+    pub fn try_topk_set(&self, closed_set: &[u8], k: usize) -> Result<JsValue, JsError> {
         use crate::algorithms::topk_set::topk_set;
         let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
         let result = topk_set(self, &closed, k);
-        serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+        Ok(to_js(&result)?)
+    }
+
+    /// TopK Set under a `WorkState`.
+    #[wasm_bindgen(js_name = topkSetWithState)]
+    pub fn topk_set_with_state(&self, state: &WorkState, k: usize) -> Result<JsValue, JsError> {
+        use crate::algorithms::topk_set::topk_set;
+        let result = topk_set(self, state.resolved(), k);
+        Ok(to_js(&result)?)
     }
 
     /// TopK Set with default k=5.
     /// closed_set is an array of bytes where non-zero means closed.
     #[wasm_bindgen(js_name = topkSetDefault)]
     pub fn topk_set_default(&self, closed_set: &[u8]) -> JsValue {
+        self.try_topk_set_default(closed_set).unwrap_or(JsValue::NULL)
+    }
+
+    /// `topkSetDefault`, failing with SERIALIZATION instead of returning null.
+    #[wasm_bindgen(js_name = tryTopkSetDefault)]
+    pub fn try_topk_set_default(&self, closed_set: &[u8]) -> Result<JsValue, JsError> {
         use crate::algorithms::topk_set::topk_set_default;
         let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
         let result = topk_set_default(self, &closed);
-        serde_wasm_bindgen::to_value(&result).unwrap_or(JsValue::NULL)
+        Ok(to_js(&result)?)
+    }
+
+    // ========================================================================
+    // Node attributes (columnar issue data for ranking and filtering)
+    // ========================================================================
+
+    /// Get a node's status.
+    #[wasm_bindgen(js_name = status)]
+    pub fn status(&self, node: usize) -> Option<IssueStatus> {
+        self.attrs.status(node)
+    }
+
+    /// Set a node's status.
+    #[wasm_bindgen(js_name = setStatus)]
+    pub fn set_status(&mut self, node: usize, status: IssueStatus) {
+        self.attrs.set_status(node, status);
+    }
+
+    /// Get a node's priority (lower number = more urgent).
+    #[wasm_bindgen(js_name = priority)]
+    pub fn priority(&self, node: usize) -> Option<i32> {
+        self.attrs.priority(node)
+    }
+
+    /// Set a node's priority.
+    #[wasm_bindgen(js_name = setPriority)]
+    pub fn set_priority(&mut self, node: usize, priority: i32) {
+        self.attrs.set_priority(node, priority);
+    }
+
+    /// Get a node's estimate in minutes, if known.
+    #[wasm_bindgen(js_name = estimatedMinutes)]
+    pub fn estimated_minutes(&self, node: usize) -> Option<u32> {
+        self.attrs.estimated_minutes(node)
+    }
+
+    /// Set a node's estimate in minutes (negative clears it).
+    #[wasm_bindgen(js_name = setEstimatedMinutes)]
+    pub fn set_estimated_minutes(&mut self, node: usize, minutes: i32) {
+        self.attrs.set_estimated_minutes(node, u32::try_from(minutes).ok());
+    }
+
+    /// Get a node's issue type.
+    #[wasm_bindgen(js_name = issueType)]
+    pub fn issue_type(&self, node: usize) -> Option<String> {
+        self.attrs.issue_type(node).map(str::to_string)
+    }
+
+    /// Set a node's issue type (empty string clears it).
+    #[wasm_bindgen(js_name = setIssueType)]
+    pub fn set_issue_type(&mut self, node: usize, issue_type: &str) {
+        self.attrs.set_issue_type(node, Some(issue_type));
+    }
+
+    /// Get a node's assignee.
+    #[wasm_bindgen(js_name = assignee)]
+    pub fn assignee(&self, node: usize) -> Option<String> {
+        self.attrs.assignee(node).map(str::to_string)
+    }
+
+    /// Set a node's assignee (empty string clears it).
+    #[wasm_bindgen(js_name = setAssignee)]
+    pub fn set_assignee(&mut self, node: usize, assignee: &str) {
+        self.attrs.set_assignee(node, Some(assignee));
+    }
+
+    /// Get a node's labels.
+    #[wasm_bindgen(js_name = labels)]
+    pub fn labels(&self, node: usize) -> Vec<String> {
+        self.attrs.labels(node).into_iter().map(str::to_string).collect()
+    }
+
+    /// Replace a node's labels.
+    #[wasm_bindgen(js_name = setLabels)]
+    pub fn set_labels(&mut self, node: usize, labels: Vec<String>) {
+        self.attrs.set_labels(node, &labels);
+    }
+
+    /// Get a node's due date as epoch milliseconds.
+    #[wasm_bindgen(js_name = dueDate)]
+    pub fn due_date(&self, node: usize) -> Option<f64> {
+        self.attrs.due_date(node)
+    }
+
+    /// Set a node's due date as epoch milliseconds (NaN clears it).
+    #[wasm_bindgen(js_name = setDueDate)]
+    pub fn set_due_date(&mut self, node: usize, epoch_ms: f64) {
+        self.attrs.set_due_date(node, Some(epoch_ms));
+    }
+
+    /// Bulk-set statuses from a Uint8Array of `IssueStatus` values in node index order.
+    /// Unknown values are skipped.
+    #[wasm_bindgen(js_name = setStatuses)]
+    pub fn set_statuses(&mut self, statuses: &[u8]) {
+        for (node, &raw) in statuses.iter().enumerate() {
+            if let Some(status) = IssueStatus::from_u8(raw) {
+                self.attrs.set_status(node, status);
+            }
+        }
+    }
+
+    /// Bulk-set priorities from an Int32Array in node index order.
+    #[wasm_bindgen(js_name = setPriorities)]
+    pub fn set_priorities(&mut self, priorities: &[i32]) {
+        for (node, &p) in priorities.iter().enumerate() {
+            self.attrs.set_priority(node, p);
+        }
+    }
+
+    /// Bulk-set estimates from an Int32Array in node index order (negative = unknown).
+    #[wasm_bindgen(js_name = setEstimates)]
+    pub fn set_estimates(&mut self, minutes: &[i32]) {
+        for (node, &m) in minutes.iter().enumerate() {
+            self.attrs.set_estimated_minutes(node, u32::try_from(m).ok());
+        }
+    }
+
+    /// Bulk-set due dates from a Float64Array of epoch milliseconds (NaN = none).
+    #[wasm_bindgen(js_name = setDueDates)]
+    pub fn set_due_dates(&mut self, epoch_ms: &[f64]) {
+        for (node, &ms) in epoch_ms.iter().enumerate() {
+            self.attrs.set_due_date(node, Some(ms));
+        }
+    }
+
+    /// Bulk-set issue types in node index order (empty string = none).
+    #[wasm_bindgen(js_name = setIssueTypes)]
+    pub fn set_issue_types(&mut self, issue_types: Vec<String>) {
+        for (node, t) in issue_types.iter().enumerate() {
+            self.attrs.set_issue_type(node, Some(t));
+        }
+    }
+
+    /// Bulk-set assignees in node index order (empty string = none).
+    #[wasm_bindgen(js_name = setAssignees)]
+    pub fn set_assignees(&mut self, assignees: Vec<String>) {
+        for (node, a) in assignees.iter().enumerate() {
+            self.attrs.set_assignee(node, Some(a));
+        }
+    }
+
+    /// Bulk-set attributes from a JSON array of beads-style issue objects:
+    /// [{id, status, priority, estimated_minutes, issue_type, assignee, labels, due_date}].
+    /// Records are matched by id; unknown ids are skipped and absent fields left unchanged.
+    /// Returns the number of records applied.
+    #[wasm_bindgen(js_name = setAttributesJson)]
+    pub fn set_attributes_json(&mut self, json: &str) -> Result<usize, JsError> {
+        let records: Vec<AttributeRecord> =
+            serde_json::from_str(json).map_err(GraphError::from)?;
+        let mut applied = 0;
+        for record in &records {
+            if let Some(node) = self.node_idx(&record.id) {
+                self.attrs.apply(node, record);
+                applied += 1;
+            }
+        }
+        Ok(applied)
+    }
+
+    /// All statuses as a Uint8Array of `IssueStatus` values.
+    #[wasm_bindgen(js_name = statuses)]
+    pub fn statuses(&self) -> Vec<u8> {
+        self.attrs.statuses().iter().map(|&s| s as u8).collect()
+    }
+
+    /// All priorities as an Int32Array.
+    #[wasm_bindgen(js_name = priorities)]
+    pub fn priorities(&self) -> Vec<i32> {
+        self.attrs.priorities().to_vec()
+    }
+
+    /// `WorkState` initialised from the stored node statuses.
+    #[wasm_bindgen(js_name = workState)]
+    pub fn work_state(&self) -> WorkState {
+        WorkState::from_graph(self)
+    }
+
+    /// Precomputed reachability index (`canReach`, ancestor/descendant counts,
+    /// intersections) for repeated queries. Rebuild it after editing the graph.
+    #[wasm_bindgen(js_name = reachIndex)]
+    pub fn reach_index(&self) -> Result<ReachIndex, JsError> {
+        ReachIndex::new(self)
+    }
+
+    /// Closed set derived from node statuses (closed and tombstoned issues are 1).
+    /// Can be passed directly as the `closed_set` argument of actionable queries.
+    #[wasm_bindgen(js_name = closedSetFromStatus)]
+    pub fn closed_set_from_status(&self) -> Vec<u8> {
+        self.attrs.resolved_mask().into_iter().map(u8::from).collect()
+    }
+
+    /// Node indices matching an attribute filter.
+    /// Filter JSON: { statuses?, min_priority?, max_priority?, issue_types?, assignees?,
+    /// labels_any?, labels_all?, has_estimate?, due_before? }
+    #[wasm_bindgen(js_name = filterNodes)]
+    pub fn filter_nodes(&self, filter_json: &str) -> Result<JsValue, JsError> {
+        let filter: NodeFilter =
+            serde_json::from_str(filter_json).map_err(GraphError::from)?;
+        let mut nodes = filter.apply(&self.attrs);
+        nodes.retain(|&v| self.is_live(v));
+        Ok(to_js(&nodes)?)
+    }
+
+    // ========================================================================
+    // Typed-array results (Float64Array / Uint32Array / Uint8Array)
+    //
+    // `*Array` variants return typed arrays copied straight out of WASM memory
+    // instead of boxed JS arrays. Variants taking a node index validate it like
+    // the `try*` methods.
+    // ========================================================================
+
+    /// PageRank scores as a Float64Array.
+    #[wasm_bindgen(js_name = pagerankArray)]
+    pub fn pagerank_array(&self, damping: f64, max_iterations: u32) -> Vec<f64> {
+        use crate::algorithms::pagerank::{pagerank, PageRankConfig};
+        let config = PageRankConfig {
+            damping,
+            max_iterations,
+            tolerance: 1e-6,
+        };
+        pagerank(self, &config)
+    }
+
+    /// Exact betweenness centrality as a Float64Array.
+    #[wasm_bindgen(js_name = betweennessArray)]
+    pub fn betweenness_array(&self) -> Vec<f64> {
+        crate::algorithms::betweenness::betweenness(self)
+    }
+
+    /// Approximate betweenness centrality as a Float64Array.
+    #[wasm_bindgen(js_name = betweennessApproxArray)]
+    pub fn betweenness_approx_array(&self, sample_size: usize) -> Vec<f64> {
+        crate::algorithms::betweenness::betweenness_approx(self, sample_size, None)
+    }
+
+    /// Eigenvector centrality as a Float64Array.
+    #[wasm_bindgen(js_name = eigenvectorArray)]
+    pub fn eigenvector_array(&self, iterations: u32) -> Vec<f64> {
+        use crate::algorithms::eigenvector::{eigenvector, EigenvectorConfig};
+        let config = EigenvectorConfig {
+            iterations,
+            tolerance: 1e-6,
+        };
+        eigenvector(self, &config)
+    }
+
+    /// HITS scores as one Float64Array of length `2 * slotCount()`: hub
+    /// scores first, then authority scores.
+    #[wasm_bindgen(js_name = hitsArray)]
+    pub fn hits_array(&self, tolerance: f64, max_iterations: u32) -> Vec<f64> {
+        use crate::algorithms::hits::{hits, HITSConfig};
+        let config = HITSConfig {
+            tolerance,
+            max_iterations,
+        };
+        let mut result = hits(self, &config);
+        result.hubs.append(&mut result.authorities);
+        result.hubs
+    }
+
+    /// Slack per node as a Float64Array (zeros for cyclic graphs).
+    #[wasm_bindgen(js_name = slackArray)]
+    pub fn slack_array(&self) -> Vec<f64> {
+        crate::algorithms::slack::slack(self)
+    }
+
+    /// Slack per node as a Float64Array, failing with CYCLIC_GRAPH.
+    #[wasm_bindgen(js_name = trySlackArray)]
+    pub fn try_slack_array(&self) -> Result<Vec<f64>, JsError> {
+        Ok(crate::algorithms::slack::try_slack(self)?)
+    }
+
+    /// Critical path heights as a Float64Array (zeros for cyclic graphs).
+    #[wasm_bindgen(js_name = criticalPathHeightsArray)]
+    pub fn critical_path_heights_array(&self) -> Vec<f64> {
+        crate::algorithms::critical_path::critical_path_heights(self)
+    }
+
+    /// Critical path heights as a Float64Array, failing with CYCLIC_GRAPH.
+    #[wasm_bindgen(js_name = tryCriticalPathHeightsArray)]
+    pub fn try_critical_path_heights_array(&self) -> Result<Vec<f64>, JsError> {
+        Ok(crate::algorithms::critical_path::try_critical_path_heights(self)?)
+    }
+
+    /// K-core numbers as a Uint32Array.
+    #[wasm_bindgen(js_name = kcoreArray)]
+    pub fn kcore_array(&self) -> Vec<u32> {
+        crate::algorithms::kcore::kcore(self)
+    }
+
+    /// Out-degree of every slot as a Uint32Array.
+    #[wasm_bindgen(js_name = outDegreesArray)]
+    pub fn out_degrees_array(&self) -> Vec<u32> {
+        self.adj.iter().map(|v| v.len() as u32).collect()
+    }
+
+    /// In-degree of every slot as a Uint32Array.
+    #[wasm_bindgen(js_name = inDegreesArray)]
+    pub fn in_degrees_array(&self) -> Vec<u32> {
+        self.rev_adj.iter().map(|v| v.len() as u32).collect()
+    }
+
+    /// Topological order as a Uint32Array, failing with CYCLIC_GRAPH.
+    #[wasm_bindgen(js_name = topologicalSortArray)]
+    pub fn topological_sort_array(&self) -> Result<Vec<u32>, JsError> {
+        let order = crate::algorithms::topo::try_topological_sort(self)?;
+        Ok(to_u32(&order))
+    }
+
+    /// Critical path nodes as a Uint32Array.
+    #[wasm_bindgen(js_name = criticalPathNodesArray)]
+    pub fn critical_path_nodes_array(&self) -> Vec<u32> {
+        to_u32(&crate::algorithms::critical_path::critical_path_nodes(self))
+    }
+
+    /// Articulation points as a Uint32Array.
+    #[wasm_bindgen(js_name = articulationPointsArray)]
+    pub fn articulation_points_array(&self) -> Vec<u32> {
+        to_u32(&crate::algorithms::articulation::articulation_points(self))
+    }
+
+    /// Successors of a node as a Uint32Array.
+    #[wasm_bindgen(js_name = successorsArray)]
+    pub fn successors_array(&self, node: usize) -> Result<Vec<u32>, JsError> {
+        self.check_node(node)?;
+        Ok(to_u32(self.successors_slice(node)))
+    }
+
+    /// Predecessors of a node as a Uint32Array.
+    #[wasm_bindgen(js_name = predecessorsArray)]
+    pub fn predecessors_array(&self, node: usize) -> Result<Vec<u32>, JsError> {
+        self.check_node(node)?;
+        Ok(to_u32(self.predecessors_slice(node)))
+    }
+
+    /// Nodes reachable from source as a Uint32Array.
+    #[wasm_bindgen(js_name = reachableFromArray)]
+    pub fn reachable_from_array(&self, source: usize) -> Result<Vec<u32>, JsError> {
+        self.check_node(source)?;
+        Ok(to_u32(&crate::reachability::reachable_from(self, source)))
+    }
+
+    /// Nodes that can reach target as a Uint32Array.
+    #[wasm_bindgen(js_name = reachableToArray)]
+    pub fn reachable_to_array(&self, target: usize) -> Result<Vec<u32>, JsError> {
+        self.check_node(target)?;
+        Ok(to_u32(&crate::reachability::reachable_to(self, target)))
+    }
+
+    /// Dependency cone (ancestors + node + descendants) as a Uint32Array.
+    #[wasm_bindgen(js_name = dependencyConeArray)]
+    pub fn dependency_cone_array(&self, node: usize) -> Result<Vec<u32>, JsError> {
+        self.check_node(node)?;
+        Ok(to_u32(&crate::algorithms::subgraph::dependency_cone(self, node)))
+    }
+
+    /// Direct blockers of a node as a Uint32Array.
+    #[wasm_bindgen(js_name = blockersArray)]
+    pub fn blockers_array(&self, node: usize) -> Result<Vec<u32>, JsError> {
+        self.check_node(node)?;
+        Ok(to_u32(&crate::reachability::blockers(self, node)))
+    }
+
+    /// Direct dependents of a node as a Uint32Array.
+    #[wasm_bindgen(js_name = dependentsArray)]
+    pub fn dependents_array(&self, node: usize) -> Result<Vec<u32>, JsError> {
+        self.check_node(node)?;
+        Ok(to_u32(&crate::reachability::dependents(self, node)))
+    }
+
+    /// Actionable nodes as a Uint32Array.
+    /// closed_set is an array of bytes where non-zero means closed.
+    #[wasm_bindgen(js_name = actionableNodesArray)]
+    pub fn actionable_nodes_array(&self, closed_set: &[u8]) -> Vec<u32> {
+        use crate::reachability::actionable_nodes;
+        let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
+        to_u32(&actionable_nodes(self, &closed))
+    }
+
+    /// Actionable nodes as a Uint8Array mask (1 = actionable), one byte per slot.
+    #[wasm_bindgen(js_name = actionableMask)]
+    pub fn actionable_mask(&self, closed_set: &[u8]) -> Vec<u8> {
+        let mut mask = vec![0u8; self.len()];
+        for v in self.actionable_nodes_array(closed_set) {
+            mask[v as usize] = 1;
+        }
+        mask
+    }
+
+    /// Open blockers of a node as a Uint32Array.
+    /// closed_set is an array of bytes where non-zero means closed.
+    #[wasm_bindgen(js_name = openBlockersArray)]
+    pub fn open_blockers_array(&self, node: usize, closed_set: &[u8]) -> Result<Vec<u32>, JsError> {
+        use crate::reachability::open_blockers;
+        self.check_node(node)?;
+        let closed: Vec<bool> = closed_set.iter().map(|&b| b != 0).collect();
+        Ok(to_u32(&open_blockers(self, node, &closed)))
     }
 }
 
 // Internal methods (not exposed to WASM)
 impl DiGraph {
+    /// Fail with `GraphError::InvalidNode` unless `node` is in range,
+    /// or `GraphError::RemovedNode` if it has been removed.
+    pub(crate) fn check_node(&self, node: usize) -> GraphResult<()> {
+        if node >= self.nodes.len() {
+            Err(GraphError::InvalidNode {
+                index: node,
+                node_count: self.nodes.len(),
+            })
+        } else if self.removed[node] {
+            Err(GraphError::RemovedNode { index: node })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// True if `node` is in range and not removed.
+    pub(crate) fn is_live(&self, node: usize) -> bool {
+        self.removed.get(node).is_some_and(|&r| !r)
+    }
+
+    /// Indices of all live (non-removed) nodes, ascending.
+    pub(crate) fn live_nodes(&self) -> impl Iterator<Item = usize> + '_ {
+        self.live_or_removed(false)
+    }
+
+    fn live_or_removed(&self, removed: bool) -> impl Iterator<Item = usize> + '_ {
+        self.removed
+            .iter()
+            .enumerate()
+            .filter(move |&(_, &r)| r == removed)
+            .map(|(i, _)| i)
+    }
+
+    /// Add an edge of the given kind, rejecting out-of-range indices.
+    pub fn add_edge_checked(&mut self, from: usize, to: usize, kind: EdgeKind) -> GraphResult<()> {
+        self.check_node(from)?;
+        self.check_node(to)?;
+        let blocking = kind.bit() & self.blocking_kinds != 0;
+        if blocking && self.edge_kinds(from, to) & self.blocking_kinds == 0 {
+            self.track_blocking_edge(from, to)?;
+        }
+        self.csr.take();
+
+        // Existing edge: merge the kind (the position scan only runs on duplicates)
+        if !self.edge_set.insert((from, to)) {
+            if let Some(pos) = self.adj[from].iter().position(|&w| w == to) {
+                self.adj_kinds[from][pos] |= kind.bit();
+            }
+            if let Some(rpos) = self.rev_adj[to].iter().position(|&u| u == from) {
+                self.rev_kinds[to][rpos] |= kind.bit();
+            }
+            return Ok(());
+        }
+
+        self.adj[from].push(to);
+        self.adj_kinds[from].push(kind.bit());
+        self.rev_adj[to].push(from);
+        self.rev_kinds[to].push(kind.bit());
+        self.edge_count += 1;
+        Ok(())
+    }
+
+    /// Add edges from[i] -> to[i] of one kind, validating every index up front.
+    pub fn add_edges_bulk_checked(&mut self, from: &[u32], to: &[u32], kind: EdgeKind) -> GraphResult<usize> {
+        if from.len() != to.len() {
+            return Err(GraphError::LengthMismatch {
+                what: "edge target array",
+                expected: from.len(),
+                actual: to.len(),
+            });
+        }
+        for &v in from.iter().chain(to) {
+            self.check_node(v as usize)?;
+        }
+        let before = self.edge_count;
+        self.edge_set.reserve(from.len());
+        // Strict mode can reject an edge midway; undo the earlier ones so the
+        // call stays atomic
+        let mut undo = Vec::new();
+        for (&u, &v) in from.iter().zip(to) {
+            let (u, v) = (u as usize, v as usize);
+            let prev = self.edge_kinds(u, v);
+            if let Err(err) = self.add_edge_checked(u, v, kind) {
+                for (u, v, prev) in undo.into_iter().rev() {
+                    self.set_edge_mask(u, v, prev);
+                }
+                return Err(err);
+            }
+            if self.strict_acyclic && prev | kind.bit() != prev {
+                undo.push((u, v, prev));
+            }
+        }
+        Ok(self.edge_count - before)
+    }
+
+    /// Overwrite the kind mask of an existing edge (0 removes it).
+    pub(crate) fn set_edge_mask(&mut self, from: usize, to: usize, mask: u8) {
+        if mask == 0 {
+            self.remove_edge(from, to);
+            return;
+        }
+        // Dropping kinds keeps a topological order valid; adding blocking ones may not
+        let added = mask & !self.edge_kinds(from, to);
+        if added & self.blocking_kinds != 0 || matches!(self.topo.get(), Some(None)) {
+            self.topo.take();
+        }
+        self.csr.take();
+        if let Some(pos) = self.adj[from].iter().position(|&w| w == to) {
+            self.adj_kinds[from][pos] = mask;
+        }
+        if let Some(rpos) = self.rev_adj[to].iter().position(|&u| u == from) {
+            self.rev_kinds[to][rpos] = mask;
+        }
+    }
+
+    /// Existing blocking path to -> ... -> from that the edge from -> to
+    /// would turn into a cycle, or `None` if the edge is safe.
+    pub fn cycle_path_checked(&self, from: usize, to: usize) -> GraphResult<Option<Vec<usize>>> {
+        self.check_node(from)?;
+        self.check_node(to)?;
+        Ok(cycle_path(self, self.topo_order(), from, to))
+    }
+
+    /// Enable or disable strict mode, failing with CYCLIC_GRAPH if the
+    /// blocking graph already has a cycle.
+    pub fn set_strict_acyclic_checked(&mut self, enabled: bool) -> GraphResult<()> {
+        if enabled && self.topo_order().is_none() {
+            return Err(crate::algorithms::cycles::cyclic_graph_error(self));
+        }
+        self.strict_acyclic = enabled;
+        Ok(())
+    }
+
+    /// Change the blocking kind mask. In strict mode a mask that closes a
+    /// blocking cycle fails with CYCLIC_GRAPH and leaves the mask unchanged.
+    pub fn set_blocking_kinds_checked(&mut self, mask: u8) -> GraphResult<()> {
+        let previous = self.blocking_kinds;
+        self.blocking_kinds = mask & ALL_KINDS;
+        self.csr.take();
+        self.topo.take();
+        if self.strict_acyclic && self.topo_order().is_none() {
+            let err = crate::algorithms::cycles::cyclic_graph_error(self);
+            self.blocking_kinds = previous;
+            self.csr.take();
+            self.topo.take();
+            return Err(err);
+        }
+        Ok(())
+    }
+
+    /// Incremental topological order, built on first use; `None` if cyclic.
+    fn topo_order(&self) -> Option<&TopoOrder> {
+        self.topo.get_or_init(|| TopoOrder::build(self)).as_ref()
+    }
+
+    /// Update the topological order for a new blocking edge from -> to before
+    /// it is inserted. In strict mode a cycle-closing edge is rejected.
+    fn track_blocking_edge(&mut self, from: usize, to: usize) -> GraphResult<()> {
+        if self.strict_acyclic {
+            self.topo_order();
+        }
+        // Not built yet: stay lazy
+        let Some(order) = self.topo.take() else {
+            return Ok(());
+        };
+        let (order, path) = match order {
+            Some(mut order) => match order.insert(self, from, to) {
+                Ok(()) => (Some(order), None),
+                Err(path) => (Some(order), Some(path)),
+            },
+            None if self.strict_acyclic => (None, cycle_path(self, None, from, to)),
+            None => (None, None),
+        };
+        match path {
+            Some(path) if self.strict_acyclic => {
+                self.topo = OnceCell::from(order);
+                Err(GraphError::WouldCreateCycle { from, to, path })
+            }
+            Some(_) => {
+                self.topo = OnceCell::from(None);
+                Ok(())
+            }
+            None => {
+                self.topo = OnceCell::from(order);
+                Ok(())
+            }
+        }
+    }
+
+    /// Build a graph from node IDs and parallel blocking-edge index arrays.
+    /// Repeated IDs keep their own slots; `node_idx` resolves to the first.
+    pub fn try_from_arrays<S: AsRef<str>>(ids: &[S], from: &[u32], to: &[u32]) -> GraphResult<DiGraph> {
+        let mut graph = DiGraph::with_capacity(ids.len(), from.len());
+        for id in ids {
+            graph.push_slot(id.as_ref());
+        }
+        graph.add_edges_bulk_checked(from, to, EdgeKind::Blocks)?;
+        Ok(graph)
+    }
+
+    /// Parse a `toJson` snapshot.
+    pub fn from_snapshot_json(json: &str) -> GraphResult<DiGraph> {
+        let snapshot: GraphSnapshot = serde_json::from_str(json)?;
+
+        // One slot per exported node, tombstones and repeated IDs included,
+        // so indices match the exporting graph
+        let mut graph = DiGraph::with_capacity(snapshot.nodes.len(), snapshot.edges.len());
+        let removed: HashSet<usize> = snapshot.removed.into_iter().collect();
+        for (i, id) in snapshot.nodes.iter().enumerate() {
+            let idx = graph.push_slot(id);
+            if removed.contains(&i) {
+                graph.remove_node(idx);
+            }
+        }
+        for (from, to) in snapshot.edges {
+            graph.add_edge_checked(from, to, EdgeKind::Blocks)?;
+        }
+        Ok(graph)
+    }
+
     /// Get successors slice (internal use).
     pub(crate) fn successors_slice(&self, node: usize) -> &[usize] {
         self.adj.get(node).map_or(&[], |v| v.as_slice())
@@ -667,6 +2317,86 @@ impl DiGraph {
         self.rev_adj.get(node).map_or(&[], |v| v.as_slice())
     }
 
+    /// Kind masks parallel to `successors_slice` (internal use).
+    pub(crate) fn successor_kinds(&self, node: usize) -> &[u8] {
+        self.adj_kinds.get(node).map_or(&[], |v| v.as_slice())
+    }
+
+    /// Kind masks parallel to `predecessors_slice` (internal use).
+    pub(crate) fn predecessor_kinds(&self, node: usize) -> &[u8] {
+        self.rev_kinds.get(node).map_or(&[], |v| v.as_slice())
+    }
+
+    /// Blocking successors read from the adjacency lists, bypassing the CSR
+    /// view (internal use by incremental structures).
+    pub(crate) fn raw_blocking_successors(&self, node: usize) -> impl Iterator<Item = usize> + '_ {
+        let mask = self.blocking_kinds;
+        self.successors_slice(node)
+            .iter()
+            .zip(self.successor_kinds(node))
+            .filter(move |&(_, &k)| k & mask != 0)
+            .map(|(&w, _)| w)
+    }
+
+    /// Blocking predecessors read from the adjacency lists (internal use).
+    pub(crate) fn raw_blocking_predecessors(&self, node: usize) -> impl Iterator<Item = usize> + '_ {
+        let mask = self.blocking_kinds;
+        self.predecessors_slice(node)
+            .iter()
+            .zip(self.predecessor_kinds(node))
+            .filter(move |&(_, &k)| k & mask != 0)
+            .map(|(&u, _)| u)
+    }
+
+    /// Frozen CSR view shared by all algorithms, built on first use (internal use).
+    pub(crate) fn csr(&self) -> &CsrGraph {
+        self.csr.get_or_init(|| CsrGraph::build(self))
+    }
+
+    /// Successors through blocking edges: the issues this node blocks (internal use).
+    pub(crate) fn blocking_successors(&self, node: usize) -> impl Iterator<Item = usize> + '_ {
+        self.csr().blocking_successors(node).iter().map(|&w| w as usize)
+    }
+
+    /// Predecessors through blocking edges: the issues blocking this node (internal use).
+    pub(crate) fn blocking_predecessors(&self, node: usize) -> impl Iterator<Item = usize> + '_ {
+        self.csr().blocking_predecessors(node).iter().map(|&u| u as usize)
+    }
+
+    /// Number of blocking predecessors (internal use).
+    pub(crate) fn blocking_in_degree(&self, node: usize) -> usize {
+        self.csr().blocking_predecessors(node).len()
+    }
+
+    /// Node attribute columns (internal use).
+    pub(crate) fn attrs(&self) -> &NodeAttributes {
+        &self.attrs
+    }
+
+    /// Mutable node attribute columns (internal use).
+    pub(crate) fn attrs_mut(&mut self) -> &mut NodeAttributes {
+        &mut self.attrs
+    }
+
+    /// Priority of a node for tie-breaking; unknown nodes sort last (internal use).
+    pub(crate) fn priority_or_default(&self, node: usize) -> i32 {
+        self.attrs.priority(node).unwrap_or(i32::MAX)
+    }
+
+    /// Add an edge with a raw kind mask (internal use, e.g. subgraph extraction).
+    pub(crate) fn add_edge_mask(&mut self, from: usize, to: usize, mask: u8) {
+        for kind in [
+            EdgeKind::Blocks,
+            EdgeKind::Related,
+            EdgeKind::ParentChild,
+            EdgeKind::DiscoveredFrom,
+        ] {
+            if mask & kind.bit() != 0 {
+                self.add_edge_kind(from, to, kind);
+            }
+        }
+    }
+
     /// Iterate over all edges (internal use).
     pub(crate) fn edges(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
         self.adj
@@ -675,12 +2405,36 @@ impl DiGraph {
             .flat_map(|(from, tos)| tos.iter().map(move |&to| (from, to)))
     }
 
+    /// ID stored in a slot, including removed ones (internal use).
+    pub(crate) fn slot_id(&self, idx: usize) -> &str {
+        &self.nodes[idx]
+    }
+
     /// Collect edges as vec (for serialization).
     fn edges_vec(&self) -> Vec<(usize, usize)> {
         self.edges().collect()
     }
 
-    /// Get node count (internal, non-WASM).
+    /// Append a slot without the ID dedup of `add_node` (snapshot restore).
+    pub(crate) fn push_slot(&mut self, id: &str) -> usize {
+        self.csr.take();
+        if let Some(Some(order)) = self.topo.get_mut() {
+            order.push();
+        }
+        let idx = self.nodes.len();
+        self.nodes.push(id.to_string());
+        self.node_index.entry(id.to_string()).or_insert(idx);
+        self.adj.push(Vec::new());
+        self.rev_adj.push(Vec::new());
+        self.adj_kinds.push(Vec::new());
+        self.rev_kinds.push(Vec::new());
+        self.attrs.push_default();
+        self.removed.push(false);
+        idx
+    }
+
+    /// Get slot count including removed nodes (internal, non-WASM).
+    /// Per-node vectors are sized by this; skip slots with `is_live`.
     pub(crate) fn len(&self) -> usize {
         self.nodes.len()
     }
@@ -692,6 +2446,20 @@ impl DiGraph {
     }
 }
 
+/// Parse an options JSON argument; an empty string means the defaults.
+fn parse_options<T: DeserializeOwned + Default>(options_json: &str) -> GraphResult<T> {
+    if options_json.trim().is_empty() {
+        Ok(T::default())
+    } else {
+        Ok(serde_json::from_str(options_json)?)
+    }
+}
+
+/// Immediate (post-)dominators as an Int32Array (-1 for none).
+fn idom_array(idom: &[Option<usize>]) -> Vec<i32> {
+    idom.iter().map(|d| d.map_or(-1, |d| d as i32)).collect()
+}
+
 impl Default for DiGraph {
     fn default() -> Self {
         Self::new()
@@ -728,6 +2496,197 @@ mod tests {
         assert_eq!(g.edge_count(), 1);
     }
 
+    #[test]
+    fn test_add_edge_checked_rejects_bad_index() {
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let err = g.add_edge_checked(a, 5, EdgeKind::Blocks).unwrap_err();
+        assert_eq!(
+            err,
+            GraphError::InvalidNode {
+                index: 5,
+                node_count: 1
+            }
+        );
+        assert_eq!(g.edge_count(), 0);
+
+        // Legacy addEdge still ignores it
+        g.add_edge(a, 5);
+        assert_eq!(g.edge_count(), 0);
+    }
+
+    #[test]
+    fn test_snapshot_json_errors() {
+        let err = DiGraph::from_snapshot_json(r#"{"nodes":["a"],"edges":[[0,3]]}"#)
+            .err()
+            .unwrap();
+        assert_eq!(err.code(), "INVALID_NODE");
+        let err = DiGraph::from_snapshot_json("{").err().unwrap();
+        assert_eq!(err.code(), "SERIALIZATION");
+    }
+
+    #[test]
+    fn test_remove_edge() {
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        g.add_edge_kind(a, b, EdgeKind::Related);
+        g.add_edge(a, b);
+
+        assert!(g.remove_edge(a, b));
+        assert!(!g.remove_edge(a, b));
+        assert_eq!(g.edge_count(), 0);
+        assert_eq!(g.edge_kinds(a, b), 0);
+        assert!(g.predecessors_slice(b).is_empty());
+    }
+
+    #[test]
+    fn test_remove_node_keeps_indices() {
+        // a -> b -> c, b -> b
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        g.add_edge(a, b);
+        g.add_edge(b, c);
+        g.add_edge(b, b);
+
+        assert!(g.remove_node(b));
+        assert!(!g.remove_node(b));
+        assert_eq!(g.node_count(), 2);
+        assert_eq!(g.slot_count(), 3);
+        assert_eq!(g.edge_count(), 0);
+        assert_eq!(g.node_id(c), Some("c".to_string()));
+        assert_eq!(g.node_id(b), None);
+        assert_eq!(g.node_idx("b"), None);
+        assert_eq!(g.attrs().status(b), Some(IssueStatus::Tombstone));
+        assert_eq!(
+            g.add_edge_checked(a, b, EdgeKind::Blocks),
+            Err(GraphError::RemovedNode { index: b })
+        );
+
+        // The ID can be re-added in a fresh slot
+        assert_eq!(g.add_node("b"), 3);
+        assert_eq!(g.live_nodes().collect::<Vec<_>>(), vec![a, c, 3]);
+    }
+
+    #[test]
+    fn test_parse_options() {
+        use crate::algorithms::schedule::{PriorityRule, ScheduleOptions};
+        let options: ScheduleOptions = parse_options("  ").unwrap();
+        assert!(options.team.is_empty());
+        let options: ScheduleOptions = parse_options(r#"{"rule": "priority"}"#).unwrap();
+        assert!(matches!(options.rule, PriorityRule::Priority));
+        let err = parse_options::<ScheduleOptions>("{").unwrap_err();
+        assert_eq!(err.code(), "SERIALIZATION");
+    }
+
+    #[test]
+    fn test_compact() {
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        let d = g.add_node("d");
+        g.add_edge(a, c);
+        g.add_edge_kind(c, d, EdgeKind::ParentChild);
+        g.set_priority(d, 0);
+        g.remove_node(b);
+
+        let map = g.compact();
+        assert_eq!(map, vec![0, -1, 1, 2]);
+        assert_eq!(g.slot_count(), 3);
+        assert_eq!(g.node_idx("d"), Some(2));
+        assert_eq!(g.successors_slice(0), &[1]);
+        assert_eq!(g.edge_kinds(1, 2), EdgeKind::ParentChild.bit());
+        assert_eq!(g.attrs().priority(2), Some(0));
+        assert_eq!(g.edge_count(), 2);
+    }
+
+    #[test]
+    fn test_compact_keeps_repeated_ids() {
+        let mut g = DiGraph::try_from_arrays(&["a", "a", "b"], &[0], &[1]).unwrap();
+        g.remove_node(2);
+
+        assert_eq!(g.compact(), vec![0, 1, -1]);
+        assert_eq!(g.slot_count(), 2);
+        assert_eq!(g.edge_count(), 1);
+        assert!(g.has_edge(0, 1));
+        assert_eq!(g.successors_slice(0), &[1]);
+        assert_eq!(g.node_idx("a"), Some(0));
+    }
+
+    #[test]
+    fn test_snapshot_keeps_removed_slots() {
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        g.add_edge(a, c);
+        g.remove_node(b);
+
+        let restored = DiGraph::from_snapshot_json(&g.to_json()).ok().unwrap();
+        assert_eq!(restored.slot_count(), 3);
+        assert!(restored.is_removed(b));
+        assert_eq!(restored.node_idx("c"), Some(c));
+        assert_eq!(restored.successors_slice(a), &[c]);
+    }
+
+    #[test]
+    fn test_snapshot_keeps_repeated_ids() {
+        let g = DiGraph::try_from_arrays(&["a", "a", "b"], &[0, 1], &[1, 2]).unwrap();
+
+        let restored = DiGraph::from_snapshot_json(&g.to_json()).unwrap();
+        assert_eq!(restored.slot_count(), 3);
+        assert_eq!(restored.edge_count(), 2);
+        assert_eq!(restored.successors_slice(1), &[2]);
+        assert_eq!(restored.node_idx("b"), Some(2));
+    }
+
+    #[test]
+    fn test_edge_kinds_merge() {
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        g.add_edge_kind(a, b, EdgeKind::Related);
+        g.add_edge_kind(a, b, EdgeKind::Blocks);
+        assert_eq!(g.edge_count(), 1);
+        assert_eq!(
+            g.edge_kinds(a, b),
+            EdgeKind::Related.bit() | EdgeKind::Blocks.bit()
+        );
+        assert_eq!(g.edge_kinds(b, a), 0);
+    }
+
+    #[test]
+    fn test_blocking_view() {
+        // a -blocks-> c, b -related-> c
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        g.add_edge(a, c);
+        g.add_edge_kind(b, c, EdgeKind::Related);
+
+        assert_eq!(g.in_degree(c), 2);
+        assert_eq!(g.blocking_predecessors(c).collect::<Vec<_>>(), vec![a]);
+        assert_eq!(g.blocking_successors(b).count(), 0);
+
+        g.set_blocking_kinds_checked(ALL_KINDS).unwrap();
+        assert_eq!(g.blocking_in_degree(c), 2);
+    }
+
+    #[test]
+    fn test_dependency_type_parsing() {
+        assert_eq!(EdgeKind::from_dependency_type(""), Some(EdgeKind::Blocks));
+        assert_eq!(
+            EdgeKind::from_dependency_type("parent-child"),
+            Some(EdgeKind::ParentChild)
+        );
+        assert_eq!(EdgeKind::from_dependency_type("bogus"), None);
+        assert_eq!(EdgeKind::DiscoveredFrom.as_str(), "discovered-from");
+    }
+
     #[test]
     fn test_degrees() {
         let mut g = DiGraph::new();
@@ -777,4 +2736,169 @@ mod tests {
         assert_eq!(g2.node_id(0), Some("a".to_string()));
         assert_eq!(g2.node_id(1), Some("b".to_string()));
     }
+
+    #[test]
+    fn test_typed_array_results() {
+        // a -> b -> c, d isolated
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        g.add_node("d");
+        g.add_edge(a, b);
+        g.add_edge(b, c);
+
+        assert_eq!(g.pagerank_array(0.85, 100).len(), 4);
+        assert_eq!(g.kcore_array(), vec![1, 1, 1, 0]);
+        let hits = g.hits_array(1e-6, 100);
+        assert_eq!(hits.len(), 8);
+        assert_eq!(hits[3], 0.0);
+        assert_eq!(hits[7], 0.0);
+
+        assert_eq!(g.critical_path_heights_array(), vec![1.0, 2.0, 3.0, 1.0]);
+        assert_eq!(g.topological_sort_array().unwrap().len(), 4);
+        assert_eq!(g.reachable_from_array(a).unwrap(), vec![0, 1, 2]);
+        assert_eq!(g.actionable_nodes_array(&[1, 0, 0, 0]), vec![1, 3]);
+        assert_eq!(g.actionable_mask(&[1, 0, 0, 0]), vec![0, 1, 0, 1]);
+    }
+
+    #[test]
+    fn test_from_arrays() {
+        let ids = ["a", "b", "c"];
+        let g = DiGraph::try_from_arrays(&ids, &[0, 1, 0], &[1, 2, 1]).unwrap();
+        assert_eq!(g.node_count(), 3);
+        assert_eq!(g.edge_count(), 2);
+        assert!(g.has_edge(0, 1));
+        assert!(!g.has_edge(1, 0));
+        assert_eq!(g.node_idx("c"), Some(2));
+
+        let err = DiGraph::try_from_arrays(&ids, &[0], &[]).err().unwrap();
+        assert_eq!(err.code(), "LENGTH_MISMATCH");
+        let err = DiGraph::try_from_arrays(&ids, &[0], &[3]).err().unwrap();
+        assert_eq!(err.code(), "INVALID_NODE");
+    }
+
+    #[test]
+    fn test_add_edges_bulk_is_atomic() {
+        let mut g = DiGraph::new();
+        g.add_node("a");
+        g.add_node("b");
+        g.add_node("c");
+        assert_eq!(g.add_edges_bulk_checked(&[0, 1, 0], &[1, 2, 1], EdgeKind::Blocks), Ok(2));
+
+        // A bad index anywhere rejects the whole batch
+        assert!(g.add_edges_bulk_checked(&[2, 0], &[0, 9], EdgeKind::Blocks).is_err());
+        assert!(!g.has_edge(2, 0));
+        assert_eq!(g.edge_count(), 2);
+
+        g.remove_edge(0, 1);
+        assert!(!g.has_edge(0, 1));
+        assert_eq!(g.add_edges_bulk_checked(&[0], &[1], EdgeKind::Blocks), Ok(1));
+    }
+
+    #[test]
+    fn test_add_edge_by_ids() {
+        let mut g = DiGraph::new();
+        g.add_edge_by_ids("a", "b");
+        g.add_edge_by_ids("b", "c");
+        g.add_edge_by_ids("a", "b");
+        assert_eq!(g.node_count(), 3);
+        assert_eq!(g.edge_count(), 2);
+        assert_eq!(g.successors_slice(g.node_idx("b").unwrap()), &[2]);
+    }
+
+    #[test]
+    fn test_would_create_cycle_tracks_edits() {
+        // Pseudo-random edits checked against a plain reachability search
+        let mut g = DiGraph::new();
+        for i in 0..12 {
+            g.add_node(&format!("n{}", i));
+        }
+        let mut seed = 7usize;
+        for step in 0..200 {
+            seed = seed.wrapping_mul(1103515245).wrapping_add(12345) % (1 << 31);
+            let (a, b) = (seed % 12, (seed / 12) % 12);
+            let expected = crate::reachability::reachable_from(&g, b).contains(&a);
+            let path = g.cycle_path_checked(a, b).unwrap();
+            assert_eq!(path.is_some(), expected, "step {}: {} -> {}", step, a, b);
+            if let Some(path) = path {
+                assert_eq!((path[0], path[path.len() - 1]), (b, a));
+                g.remove_edge(path[0], *path.get(1).unwrap_or(&a));
+            } else if step % 5 == 0 {
+                g.add_edge_kind(a, b, EdgeKind::Related);
+            } else {
+                g.add_edge(a, b);
+            }
+            assert!(g.is_dag());
+        }
+    }
+
+    #[test]
+    fn test_strict_acyclic_rejects_cycle_edges() {
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        g.add_edge(a, b);
+        g.add_edge(b, c);
+        g.set_strict_acyclic_checked(true).unwrap();
+
+        assert_eq!(
+            g.add_edge_checked(c, a, EdgeKind::Blocks),
+            Err(GraphError::WouldCreateCycle {
+                from: c,
+                to: a,
+                path: vec![a, b, c]
+            })
+        );
+        g.add_edge(c, a);
+        assert!(!g.has_edge(c, a));
+        // Non-blocking kinds cannot close a blocking cycle
+        g.add_edge_kind(c, a, EdgeKind::Related);
+        assert!(g.has_edge(c, a));
+
+        // Bulk insert rolls back the edges added before the failing one
+        let d = g.add_node("d");
+        let err = g.add_edges_bulk_checked(&[c as u32, c as u32], &[d as u32, a as u32], EdgeKind::Blocks);
+        assert!(matches!(err, Err(GraphError::WouldCreateCycle { .. })));
+        assert!(!g.has_edge(c, d));
+        assert_eq!(g.edge_kinds(c, a), EdgeKind::Related.bit());
+        assert!(g.is_dag());
+
+        g.set_strict_acyclic_checked(false).unwrap();
+        g.add_edge(c, a);
+        assert!(!g.is_dag());
+        assert!(matches!(
+            g.set_strict_acyclic_checked(true),
+            Err(GraphError::CyclicGraph { .. })
+        ));
+        g.remove_edge(c, a);
+        assert!(g.set_strict_acyclic_checked(true).is_ok());
+    }
+
+    #[test]
+    fn test_strict_acyclic_rejects_cyclic_blocking_kinds() {
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        g.add_edge(a, b);
+        g.add_edge(b, c);
+        g.set_strict_acyclic_checked(true).unwrap();
+        g.add_edge_kind(c, a, EdgeKind::Related);
+
+        let mask = EdgeKind::Blocks.bit() | EdgeKind::Related.bit();
+        assert!(matches!(
+            g.set_blocking_kinds_checked(mask),
+            Err(GraphError::CyclicGraph { .. })
+        ));
+        assert_eq!(g.blocking_kinds(), EdgeKind::Blocks.bit());
+        assert!(g.is_dag());
+        assert!(g.is_strict_acyclic());
+
+        // Outside strict mode the same mask is accepted
+        g.set_strict_acyclic_checked(false).unwrap();
+        g.set_blocking_kinds_checked(mask).unwrap();
+        assert!(!g.is_dag());
+    }
 }
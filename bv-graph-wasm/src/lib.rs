@@ -5,14 +5,34 @@
 
 use wasm_bindgen::prelude::*;
 
+mod error;
 mod graph;
+mod attributes;
+mod beads;
+mod csr;
+mod topo_order;
+mod snapshot;
 pub mod algorithms;
 mod advanced;
 mod whatif;
 mod subgraph;
 mod reachability;
+mod work_state;
+mod reach_index;
+#[cfg(test)]
+mod test_util;
 
-pub use graph::DiGraph;
+pub use error::{GraphError, GraphResult};
+pub use csr::CsrGraph;
+pub use work_state::WorkState;
+pub use reach_index::{ReachIndex, CLOSURE_MAX_COMPONENTS, INDEX_MAX_COMPONENTS};
+pub use snapshot::{
+    decode_any_snapshot, decode_snapshot, detect_snapshot_format, encode_snapshot, SnapshotFormat,
+    SNAPSHOT_VERSION,
+};
+pub use graph::{DiGraph, EdgeKind};
+pub use attributes::{IssueStatus, NodeAttributes, NodeFilter};
+pub use beads::{load_beads_jsonl, BeadsLoad, BeadsLoadOptions, BeadsLoadReport};
 
 // Re-export key algorithm functions for testing
 pub use algorithms::pagerank::{pagerank, pagerank_default, PageRankConfig};
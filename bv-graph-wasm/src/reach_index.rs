@@ -0,0 +1,502 @@
+//! Precomputed reachability index.
+//!
+//! `reachability::reachable_from`/`reachable_to` run a BFS per call. A
+//! `ReachIndex` answers the same questions from labels built once over the
+//! SCC condensation (see `algorithms::condensation`); nodes in one component
+//! reach each other, so only component pairs need answers.
+//!
+//! - Up to `CLOSURE_MAX_COMPONENTS` components: full transitive closure as
+//!   one descendant and one ancestor bitset per component. `canReach` is a
+//!   bit test; intersections are word-wise ANDs.
+//! - Larger graphs: 2-hop labels (pruned landmark labeling). Every
+//!   component stores the landmarks it reaches and the landmarks reaching
+//!   it; `a` reaches `b` iff the two lists share a landmark. Lists are short
+//!   in practice because landmarks are taken highest-degree first and
+//!   searches stop at components already covered.
+//!
+//! Ancestor and descendant counts are computed at build time for both, in
+//! O((components + edges) * components / 64) time. That quadratic term is
+//! why builds stop at `INDEX_MAX_COMPONENTS`. In 2-hop mode common
+//! ancestor/descendant counts walk the smaller of the two reach sets and
+//! test each member against the other node's labels.
+//!
+//! The index is a snapshot: rebuild it after editing the graph.
+
+use crate::algorithms::condensation::condense;
+use crate::algorithms::topo::topological_sort;
+use crate::error::{GraphError, GraphResult};
+use crate::graph::DiGraph;
+use std::collections::{HashSet, VecDeque};
+use wasm_bindgen::prelude::*;
+
+/// Largest condensation that gets a full bitset closure (2 x 2 MiB at the limit).
+pub const CLOSURE_MAX_COMPONENTS: usize = 4096;
+
+/// Largest condensation indexed at all; the reach counts take
+/// components² / 64 word operations.
+pub const INDEX_MAX_COMPONENTS: usize = 100_000;
+
+const REMOVED: u32 = u32::MAX;
+
+#[derive(Debug, Clone)]
+enum Labels {
+    /// Descendant and ancestor bitsets, `words` u64s per component
+    Closure {
+        words: usize,
+        descendants: Vec<u64>,
+        ancestors: Vec<u64>,
+    },
+    /// Landmark ranks (ascending) reachable from / reaching each component,
+    /// plus the condensation edges for walking reach sets
+    TwoHop {
+        out: Vec<Vec<u32>>,
+        into: Vec<Vec<u32>>,
+        succ: Vec<Vec<usize>>,
+        pred: Vec<Vec<usize>>,
+    },
+}
+
+/// Constant-time reachability queries over the blocking graph.
+///
+/// Every node reaches itself; counts exclude the node itself but include
+/// the other members of its cycle. Removed slots reach nothing.
+#[wasm_bindgen]
+#[derive(Debug, Clone)]
+pub struct ReachIndex {
+    /// Component of each slot (`REMOVED` for removed slots)
+    component: Vec<u32>,
+    /// Number of members per component
+    size: Vec<u32>,
+    /// Topological position per component, for an O(1) negative answer
+    position: Vec<u32>,
+    labels: Labels,
+    descendant_count: Vec<u32>,
+    ancestor_count: Vec<u32>,
+}
+
+#[wasm_bindgen]
+impl ReachIndex {
+    /// Build the index for a graph, failing with LIMIT_EXCEEDED above
+    /// `INDEX_MAX_COMPONENTS` components.
+    #[wasm_bindgen(constructor)]
+    pub fn new(graph: &DiGraph) -> Result<ReachIndex, JsError> {
+        Ok(ReachIndex::build(graph, CLOSURE_MAX_COMPONENTS)?)
+    }
+
+    /// "closure" or "two-hop".
+    pub fn strategy(&self) -> String {
+        match self.labels {
+            Labels::Closure { .. } => "closure".to_string(),
+            Labels::TwoHop { .. } => "two-hop".to_string(),
+        }
+    }
+
+    /// Whether a blocking path leads from `a` to `b` (true when a == b).
+    #[wasm_bindgen(js_name = canReach)]
+    pub fn can_reach(&self, a: usize, b: usize) -> bool {
+        match (self.comp(a), self.comp(b)) {
+            (Some(ca), Some(cb)) => self.comp_reaches(ca, cb),
+            _ => false,
+        }
+    }
+
+    /// Number of nodes `node` transitively blocks.
+    #[wasm_bindgen(js_name = descendantCount)]
+    pub fn descendant_count(&self, node: usize) -> u32 {
+        self.descendant_count.get(node).copied().unwrap_or(0)
+    }
+
+    /// Number of nodes transitively blocking `node`.
+    #[wasm_bindgen(js_name = ancestorCount)]
+    pub fn ancestor_count(&self, node: usize) -> u32 {
+        self.ancestor_count.get(node).copied().unwrap_or(0)
+    }
+
+    /// All descendant counts as a Uint32Array (0 for removed slots).
+    #[wasm_bindgen(js_name = descendantCounts)]
+    pub fn descendant_counts(&self) -> Vec<u32> {
+        self.descendant_count.clone()
+    }
+
+    /// All ancestor counts as a Uint32Array (0 for removed slots).
+    #[wasm_bindgen(js_name = ancestorCounts)]
+    pub fn ancestor_counts(&self) -> Vec<u32> {
+        self.ancestor_count.clone()
+    }
+
+    /// The nodes of `candidates` that `node` reaches (itself included), in input order.
+    #[wasm_bindgen(js_name = descendantsAmong)]
+    pub fn descendants_among(&self, node: usize, candidates: &[u32]) -> Vec<u32> {
+        candidates
+            .iter()
+            .copied()
+            .filter(|&c| self.can_reach(node, c as usize))
+            .collect()
+    }
+
+    /// The nodes of `candidates` that reach `node` (itself included), in input order.
+    #[wasm_bindgen(js_name = ancestorsAmong)]
+    pub fn ancestors_among(&self, node: usize, candidates: &[u32]) -> Vec<u32> {
+        candidates
+            .iter()
+            .copied()
+            .filter(|&c| self.can_reach(c as usize, node))
+            .collect()
+    }
+
+    /// Number of nodes other than `a` and `b` that both of them reach.
+    #[wasm_bindgen(js_name = commonDescendantCount)]
+    pub fn common_descendant_count(&self, a: usize, b: usize) -> u32 {
+        self.common_count(a, b, true)
+    }
+
+    /// Number of nodes other than `a` and `b` that reach both of them.
+    #[wasm_bindgen(js_name = commonAncestorCount)]
+    pub fn common_ancestor_count(&self, a: usize, b: usize) -> u32 {
+        self.common_count(a, b, false)
+    }
+}
+
+impl ReachIndex {
+    /// Build with a bitset closure if the condensation has at most
+    /// `closure_max` components, 2-hop labels otherwise.
+    pub fn build(graph: &DiGraph, closure_max: usize) -> GraphResult<ReachIndex> {
+        let cond = condense(graph);
+        let m = cond.len();
+        if m > INDEX_MAX_COMPONENTS {
+            return Err(GraphError::LimitExceeded {
+                what: "reach index components",
+                requested: m,
+                max: INDEX_MAX_COMPONENTS,
+            });
+        }
+        let order = topological_sort(&cond.dag).unwrap_or_default();
+        let mut position = vec![0u32; m];
+        for (p, &c) in order.iter().enumerate() {
+            position[c] = p as u32;
+        }
+        let mut succ: Vec<Vec<usize>> = vec![Vec::new(); m];
+        let mut pred: Vec<Vec<usize>> = vec![Vec::new(); m];
+        for &(c, d) in &cond.edges {
+            succ[c].push(d);
+            pred[d].push(c);
+        }
+        let size: Vec<u32> = cond.members.iter().map(|m| m.len() as u32).collect();
+        let reverse: Vec<usize> = order.iter().rev().copied().collect();
+        let weight_down = reach_weights(&reverse, &succ, &size);
+        let weight_up = reach_weights(&order, &pred, &size);
+
+        let labels = if m <= closure_max {
+            let words = m.div_ceil(64);
+            Labels::Closure {
+                words,
+                descendants: closure_bits(words, &reverse, &succ),
+                ancestors: closure_bits(words, &order, &pred),
+            }
+        } else {
+            two_hop_labels(succ, pred)
+        };
+
+        let per_node = |weights: &[u64]| -> Vec<u32> {
+            cond.component
+                .iter()
+                .map(|c| c.map_or(0, |c| (weights[c] - 1) as u32))
+                .collect()
+        };
+
+        Ok(ReachIndex {
+            component: cond
+                .component
+                .iter()
+                .map(|c| c.map_or(REMOVED, |c| c as u32))
+                .collect(),
+            descendant_count: per_node(&weight_down),
+            ancestor_count: per_node(&weight_up),
+            size,
+            position,
+            labels,
+        })
+    }
+
+    fn comp(&self, node: usize) -> Option<usize> {
+        match self.component.get(node) {
+            Some(&c) if c != REMOVED => Some(c as usize),
+            _ => None,
+        }
+    }
+
+    fn comp_reaches(&self, ca: usize, cb: usize) -> bool {
+        if ca == cb {
+            return true;
+        }
+        if self.position[ca] > self.position[cb] {
+            return false;
+        }
+        match &self.labels {
+            Labels::Closure {
+                words, descendants, ..
+            } => descendants[ca * words + cb / 64] & (1 << (cb % 64)) != 0,
+            Labels::TwoHop { out, into, .. } => intersects(&out[ca], &into[cb]),
+        }
+    }
+
+    fn common_count(&self, a: usize, b: usize, down: bool) -> u32 {
+        let (Some(ca), Some(cb)) = (self.comp(a), self.comp(b)) else {
+            return 0;
+        };
+        if a == b {
+            return if down {
+                self.descendant_count[a]
+            } else {
+                self.ancestor_count[a]
+            };
+        }
+        let reaches = |x: usize, y: usize| {
+            if down {
+                self.comp_reaches(x, y)
+            } else {
+                self.comp_reaches(y, x)
+            }
+        };
+
+        let total: u64 = match &self.labels {
+            Labels::Closure {
+                words,
+                descendants,
+                ancestors,
+            } => {
+                let bits = if down { descendants } else { ancestors };
+                let (ra, rb) = (&bits[ca * words..][..*words], &bits[cb * words..][..*words]);
+                let mut total = 0;
+                for (w, (&x, &y)) in ra.iter().zip(rb).enumerate() {
+                    let mut both = x & y;
+                    while both != 0 {
+                        total += u64::from(self.size[w * 64 + both.trailing_zeros() as usize]);
+                        both &= both - 1;
+                    }
+                }
+                total
+            }
+            Labels::TwoHop { succ, pred, .. } => {
+                // Walk the smaller reach set, test the other side's labels
+                let counts = if down {
+                    &self.descendant_count
+                } else {
+                    &self.ancestor_count
+                };
+                let (start, other) = if counts[a] <= counts[b] { (ca, cb) } else { (cb, ca) };
+                let next = if down { succ } else { pred };
+                let mut seen = HashSet::from([start]);
+                let mut stack = vec![start];
+                let mut total = 0;
+                while let Some(c) = stack.pop() {
+                    if reaches(other, c) {
+                        total += u64::from(self.size[c]);
+                    }
+                    for &d in &next[c] {
+                        if seen.insert(d) {
+                            stack.push(d);
+                        }
+                    }
+                }
+                total
+            }
+        };
+        // a and b themselves are not common descendants (ancestors)
+        let counted = u64::from(reaches(cb, ca)) + u64::from(reaches(ca, cb));
+        (total - counted) as u32
+    }
+}
+
+/// Reachable-component bitsets, filled in `order` (each component after
+/// all its `next` neighbors).
+fn closure_bits(words: usize, order: &[usize], next: &[Vec<usize>]) -> Vec<u64> {
+    let mut bits = vec![0u64; next.len() * words];
+    for &c in order {
+        bits[c * words + c / 64] |= 1 << (c % 64);
+        for &d in &next[c] {
+            for w in 0..words {
+                bits[c * words + w] |= bits[d * words + w];
+            }
+        }
+    }
+    bits
+}
+
+/// Total member count of the components reachable from each component
+/// (itself included). Targets are processed 64 at a time so memory stays
+/// linear: O((components + edges) * components / 64) time.
+fn reach_weights(order: &[usize], next: &[Vec<usize>], size: &[u32]) -> Vec<u64> {
+    let m = next.len();
+    let mut weights = vec![0u64; m];
+    let mut mask = vec![0u64; m];
+    for base in (0..m).step_by(64) {
+        for &c in order {
+            let mut bits = if (base..base + 64).contains(&c) {
+                1 << (c - base)
+            } else {
+                0
+            };
+            for &d in &next[c] {
+                bits |= mask[d];
+            }
+            mask[c] = bits;
+            while bits != 0 {
+                weights[c] += u64::from(size[base + bits.trailing_zeros() as usize]);
+                bits &= bits - 1;
+            }
+        }
+    }
+    weights
+}
+
+/// Pruned landmark labeling for reachability on a DAG.
+fn two_hop_labels(succ: Vec<Vec<usize>>, pred: Vec<Vec<usize>>) -> Labels {
+    let m = succ.len();
+    let mut landmarks: Vec<usize> = (0..m).collect();
+    landmarks.sort_by_key(|&c| (std::cmp::Reverse((succ[c].len() + 1) * (pred[c].len() + 1)), c));
+
+    let mut out: Vec<Vec<u32>> = vec![Vec::new(); m];
+    let mut into: Vec<Vec<u32>> = vec![Vec::new(); m];
+    let mut seen = vec![usize::MAX; 2 * m];
+    let mut queue = VecDeque::new();
+
+    for (rank, &root) in landmarks.iter().enumerate() {
+        let label = rank as u32;
+        for forward in [true, false] {
+            let stamp_base = if forward { 0 } else { m };
+            queue.push_back(root);
+            seen[stamp_base + root] = rank;
+            while let Some(x) = queue.pop_front() {
+                // Already answered by earlier landmarks: nothing new below x
+                let covered = x != root
+                    && if forward {
+                        intersects(&out[root], &into[x])
+                    } else {
+                        intersects(&out[x], &into[root])
+                    };
+                if covered {
+                    continue;
+                }
+                if forward {
+                    into[x].push(label);
+                } else {
+                    out[x].push(label);
+                }
+                let next = if forward { &succ[x] } else { &pred[x] };
+                for &y in next {
+                    if seen[stamp_base + y] != rank {
+                        seen[stamp_base + y] = rank;
+                        queue.push_back(y);
+                    }
+                }
+            }
+        }
+    }
+    Labels::TwoHop {
+        out,
+        into,
+        succ,
+        pred,
+    }
+}
+
+/// True if two ascending lists share an element.
+fn intersects(a: &[u32], b: &[u32]) -> bool {
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        match a[i].cmp(&b[j]) {
+            std::cmp::Ordering::Less => i += 1,
+            std::cmp::Ordering::Greater => j += 1,
+            std::cmp::Ordering::Equal => return true,
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_util;
+    use crate::reachability::reachable_from;
+
+    fn check_against_bfs(g: &DiGraph, index: &ReachIndex) {
+        let n = g.len();
+        let reach: Vec<Vec<bool>> = (0..n)
+            .map(|a| {
+                let mut row = vec![false; n];
+                for v in reachable_from(g, a) {
+                    row[v] = true;
+                }
+                row
+            })
+            .collect();
+        for a in 0..n {
+            for (b, &expected) in reach[a].iter().enumerate() {
+                assert_eq!(index.can_reach(a, b), expected, "{} -> {}", a, b);
+            }
+            let down = reach[a].iter().filter(|&&r| r).count().saturating_sub(1);
+            let up = (0..n).filter(|&x| reach[x][a]).count().saturating_sub(1);
+            assert_eq!(index.descendant_count(a) as usize, down, "descendants of {}", a);
+            assert_eq!(index.ancestor_count(a) as usize, up, "ancestors of {}", a);
+            for b in 0..n {
+                let common = (0..n)
+                    .filter(|&x| x != a && x != b && reach[a][x] && reach[b][x])
+                    .count();
+                let common = if a == b { down } else { common };
+                assert_eq!(index.common_descendant_count(a, b) as usize, common, "common {} {}", a, b);
+            }
+        }
+    }
+
+    fn sample_graph() -> DiGraph {
+        // Diamond 0 -> {1, 2} -> 3, cycle 3 <-> 4, tail 4 -> 5, side 6 -> 2, removed 7
+        let mut g = test_util::graph(
+            8,
+            &[(0, 1), (0, 2), (1, 3), (2, 3), (3, 4), (4, 3), (4, 5), (6, 2), (7, 0)],
+        );
+        g.remove_node(7);
+        g
+    }
+
+    #[test]
+    fn test_closure_matches_bfs() {
+        let g = sample_graph();
+        let index = ReachIndex::build(&g, CLOSURE_MAX_COMPONENTS).unwrap();
+        assert_eq!(index.strategy(), "closure");
+        check_against_bfs(&g, &index);
+        assert_eq!(index.descendants_among(0, &[5, 6, 3]), vec![5, 3]);
+        assert_eq!(index.ancestors_among(2, &[0, 1, 6]), vec![0, 6]);
+        assert_eq!(index.common_ancestor_count(1, 2), 1);
+    }
+
+    #[test]
+    fn test_two_hop_matches_bfs() {
+        let g = sample_graph();
+        let index = ReachIndex::build(&g, 0).unwrap();
+        assert_eq!(index.strategy(), "two-hop");
+        check_against_bfs(&g, &index);
+
+        // Layered graph with many long paths
+        let mut edges = Vec::new();
+        for layer in 0..5 {
+            for i in 0..4 {
+                for j in 0..4 {
+                    if (i + j + layer) % 3 != 0 {
+                        edges.push((layer * 4 + i, (layer + 1) * 4 + j));
+                    }
+                }
+            }
+        }
+        let g = test_util::graph(24, &edges);
+        check_against_bfs(&g, &ReachIndex::build(&g, 0).unwrap());
+        check_against_bfs(&g, &ReachIndex::build(&g, usize::MAX).unwrap());
+    }
+
+    #[test]
+    fn test_component_limit() {
+        let g = test_util::graph(INDEX_MAX_COMPONENTS + 1, &[]);
+        let err = ReachIndex::build(&g, 0).unwrap_err();
+        assert_eq!(err.code(), "LIMIT_EXCEEDED");
+    }
+}
@@ -1,16 +1,60 @@
 //! Reachability queries (from/to).
 //!
-//! Find all nodes reachable from or that can reach a given node.
+//! Find all nodes reachable from or that can reach a given node, and the
+//! paths that connect them ("blocked by Y through what?").
 //! Essential for impact analysis and dependency exploration.
+//!
+//! All queries follow blocking edges only (see `DiGraph::setBlockingKinds`),
+//! so `related` or `discovered-from` links never show up as blockers.
 
+use crate::error::{GraphError, GraphResult};
 use crate::graph::DiGraph;
+use serde::Serialize;
 use std::collections::VecDeque;
 
+/// Largest `limit` accepted by `try_all_paths`.
+pub const MAX_PATHS: usize = 10_000;
+
+/// A chain of blocking edges, as indices and IDs.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NodePath {
+    pub nodes: Vec<usize>,
+    pub ids: Vec<String>,
+}
+
+impl NodePath {
+    pub(crate) fn new(graph: &DiGraph, nodes: Vec<usize>) -> NodePath {
+        let ids = nodes.iter().map(|&v| graph.slot_id(v).to_string()).collect();
+        NodePath { nodes, ids }
+    }
+}
+
+/// Simple paths between two nodes.
+#[derive(Debug, Clone, Serialize)]
+pub struct AllPathsResult {
+    pub paths: Vec<NodePath>,
+    /// True if more paths exist than `limit`
+    pub truncated: bool,
+}
+
+/// Why a node is blocked: one chain per open root blocker.
+#[derive(Debug, Clone, Serialize)]
+pub struct BlockedExplanation {
+    pub node: usize,
+    pub node_id: String,
+    /// True if the node has at least one open blocker
+    pub blocked: bool,
+    /// Shortest chain of open issues from each open root blocker (an open
+    /// ancestor with no open blockers of its own) to the node, ordered by
+    /// root index. Empty while blocked means only a cycle blocks the node.
+    pub chains: Vec<NodePath>,
+}
+
 /// Find all nodes reachable from source (BFS forward).
 /// Returns all nodes in the forward closure, including the source.
 pub fn reachable_from(graph: &DiGraph, source: usize) -> Vec<usize> {
     let n = graph.len();
-    if source >= n {
+    if !graph.is_live(source) {
         return Vec::new();
     }
 
@@ -23,7 +67,7 @@ pub fn reachable_from(graph: &DiGraph, source: usize) -> Vec<usize> {
 
     while let Some(v) = queue.pop_front() {
         result.push(v);
-        for &w in graph.successors_slice(v) {
+        for w in graph.blocking_successors(v) {
             if !visited[w] {
                 visited[w] = true;
                 queue.push_back(w);
@@ -38,7 +82,7 @@ pub fn reachable_from(graph: &DiGraph, source: usize) -> Vec<usize> {
 /// Returns all nodes in the backward closure, including the target.
 pub fn reachable_to(graph: &DiGraph, target: usize) -> Vec<usize> {
     let n = graph.len();
-    if target >= n {
+    if !graph.is_live(target) {
         return Vec::new();
     }
 
@@ -51,7 +95,7 @@ pub fn reachable_to(graph: &DiGraph, target: usize) -> Vec<usize> {
 
     while let Some(v) = queue.pop_front() {
         result.push(v);
-        for &u in graph.predecessors_slice(v) {
+        for u in graph.blocking_predecessors(v) {
             if !visited[u] {
                 visited[u] = true;
                 queue.push_back(u);
@@ -62,31 +106,191 @@ pub fn reachable_to(graph: &DiGraph, target: usize) -> Vec<usize> {
     result
 }
 
+/// Shortest blocking path from -> ... -> to (BFS), or `None` if `to` is
+/// unreachable. A node's path to itself is just the node.
+pub fn shortest_path(graph: &DiGraph, from: usize, to: usize) -> Option<Vec<usize>> {
+    if !graph.is_live(from) || !graph.is_live(to) {
+        return None;
+    }
+
+    let n = graph.len();
+    let mut parent = vec![usize::MAX; n];
+    let mut queue = VecDeque::new();
+
+    queue.push_back(from);
+    parent[from] = from;
+
+    while let Some(v) = queue.pop_front() {
+        if v == to {
+            return Some(walk_parents(&parent, from, to));
+        }
+        for w in graph.blocking_successors(v) {
+            if parent[w] == usize::MAX {
+                parent[w] = v;
+                queue.push_back(w);
+            }
+        }
+    }
+
+    None
+}
+
+/// Follow BFS parents from `end` back to `start`; returns start -> ... -> end.
+fn walk_parents(parent: &[usize], start: usize, end: usize) -> Vec<usize> {
+    let mut path = vec![end];
+    let mut v = end;
+    while v != start {
+        v = parent[v];
+        path.push(v);
+    }
+    path.reverse();
+    path
+}
+
+/// Up to `limit` simple blocking paths from -> ... -> to, found depth-first.
+/// Branches that cannot reach `to` are never explored.
+pub fn all_paths(graph: &DiGraph, from: usize, to: usize, limit: usize) -> AllPathsResult {
+    let mut result = AllPathsResult {
+        paths: Vec::new(),
+        truncated: false,
+    };
+    if !graph.is_live(from) || !graph.is_live(to) {
+        return result;
+    }
+
+    let mut useful = vec![false; graph.len()];
+    for v in reachable_to(graph, to) {
+        useful[v] = true;
+    }
+    if !useful[from] {
+        return result;
+    }
+
+    // Iterative DFS: each frame holds a node and the index of its next successor
+    let mut on_path = vec![false; graph.len()];
+    let mut path = vec![from];
+    let mut frames: Vec<(usize, usize)> = vec![(from, 0)];
+    on_path[from] = true;
+
+    while let Some(&mut (v, ref mut next)) = frames.last_mut() {
+        if v == to {
+            if result.paths.len() == limit {
+                result.truncated = true;
+                break;
+            }
+            result.paths.push(NodePath::new(graph, path.clone()));
+        } else if let Some(w) = graph.blocking_successors(v).nth(*next) {
+            *next += 1;
+            if useful[w] && !on_path[w] {
+                on_path[w] = true;
+                path.push(w);
+                frames.push((w, 0));
+            }
+            continue;
+        }
+        frames.pop();
+        on_path[v] = false;
+        path.pop();
+    }
+
+    result
+}
+
+/// Like `all_paths`, but rejects limits above `MAX_PATHS`.
+pub fn try_all_paths(graph: &DiGraph, from: usize, to: usize, limit: usize) -> GraphResult<AllPathsResult> {
+    if limit > MAX_PATHS {
+        return Err(GraphError::LimitExceeded {
+            what: "path limit",
+            requested: limit,
+            max: MAX_PATHS,
+        });
+    }
+    Ok(all_paths(graph, from, to, limit))
+}
+
+/// Explain why `node` is blocked under `closed_set`.
+///
+/// Searches backwards from the node through open blockers only; every open
+/// ancestor without open blockers of its own is a root, and its BFS parent
+/// chain is the shortest chain of open issues leading to the node.
+pub fn explain_blocked(graph: &DiGraph, node: usize, closed_set: &[bool]) -> BlockedExplanation {
+    let is_open = |v: usize| !closed_set.get(v).copied().unwrap_or(false);
+    let mut explanation = BlockedExplanation {
+        node,
+        node_id: graph.node_id(node).unwrap_or_default(),
+        blocked: false,
+        chains: Vec::new(),
+    };
+    if !graph.is_live(node) {
+        return explanation;
+    }
+
+    let n = graph.len();
+    let mut parent = vec![usize::MAX; n];
+    let mut queue = VecDeque::new();
+    let mut roots = Vec::new();
+
+    queue.push_back(node);
+    parent[node] = node;
+
+    while let Some(v) = queue.pop_front() {
+        let mut has_open_blocker = false;
+        for u in graph.blocking_predecessors(v).filter(|&u| is_open(u)) {
+            has_open_blocker = true;
+            if parent[u] == usize::MAX {
+                parent[u] = v;
+                queue.push_back(u);
+            }
+        }
+        if v == node {
+            explanation.blocked = has_open_blocker;
+        } else if !has_open_blocker {
+            roots.push(v);
+        }
+    }
+
+    roots.sort_unstable();
+    explanation.chains = roots
+        .into_iter()
+        .map(|root| {
+            // Parents point towards `node`, so following them runs root -> node
+            let mut chain = vec![root];
+            let mut v = root;
+            while v != node {
+                v = parent[v];
+                chain.push(v);
+            }
+            NodePath::new(graph, chain)
+        })
+        .collect();
+    explanation
+}
+
 /// Get direct blockers (predecessors) of a node.
 /// These are issues that must be completed before this node can start.
 pub fn blockers(graph: &DiGraph, node: usize) -> Vec<usize> {
-    graph.predecessors_slice(node).to_vec()
+    graph.blocking_predecessors(node).collect()
 }
 
 /// Get direct dependents (successors) of a node.
 /// These are issues that depend on this node being completed.
 pub fn dependents(graph: &DiGraph, node: usize) -> Vec<usize> {
-    graph.successors_slice(node).to_vec()
+    graph.blocking_successors(node).collect()
 }
 
 /// Check if all predecessors of node are in the closed set.
 /// A node is actionable if all its blockers are closed.
 pub fn is_actionable(graph: &DiGraph, node: usize, closed_set: &[bool]) -> bool {
     graph
-        .predecessors_slice(node)
-        .iter()
-        .all(|&p| closed_set.get(p).copied().unwrap_or(false))
+        .blocking_predecessors(node)
+        .all(|p| closed_set.get(p).copied().unwrap_or(false))
 }
 
 /// Get all actionable nodes (no open blockers).
 /// An actionable node has all its predecessors in the closed set.
 pub fn actionable_nodes(graph: &DiGraph, closed_set: &[bool]) -> Vec<usize> {
-    (0..graph.len())
+    graph
+        .live_nodes()
         .filter(|&i| !closed_set.get(i).copied().unwrap_or(false))
         .filter(|&i| is_actionable(graph, i, closed_set))
         .collect()
@@ -95,25 +299,24 @@ pub fn actionable_nodes(graph: &DiGraph, closed_set: &[bool]) -> Vec<usize> {
 /// Get open blockers for a node (predecessors not in closed set).
 pub fn open_blockers(graph: &DiGraph, node: usize, closed_set: &[bool]) -> Vec<usize> {
     graph
-        .predecessors_slice(node)
-        .iter()
-        .filter(|&&p| !closed_set.get(p).copied().unwrap_or(false))
-        .copied()
+        .blocking_predecessors(node)
+        .filter(|&p| !closed_set.get(p).copied().unwrap_or(false))
         .collect()
 }
 
 /// Count of open blockers for a node.
 pub fn open_blocker_count(graph: &DiGraph, node: usize, closed_set: &[bool]) -> usize {
     graph
-        .predecessors_slice(node)
-        .iter()
-        .filter(|&&p| !closed_set.get(p).copied().unwrap_or(false))
+        .blocking_predecessors(node)
+        .filter(|&p| !closed_set.get(p).copied().unwrap_or(false))
         .count()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::test_util;
+    use crate::graph::EdgeKind;
 
     #[test]
     fn test_reachable_from_empty() {
@@ -319,6 +522,47 @@ mod tests {
         assert_eq!(actionable, vec![d]);
     }
 
+    #[test]
+    fn test_removed_nodes_not_actionable() {
+        // a -> b, remove a: b has no blockers left
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        graph.add_edge(a, b);
+        graph.remove_node(a);
+
+        assert_eq!(actionable_nodes(&graph, &[false, false]), vec![b]);
+        assert!(reachable_from(&graph, a).is_empty());
+    }
+
+    #[test]
+    fn test_non_blocking_kinds_ignored() {
+        // a -blocks-> c, b -related-> c, d -parent-child-> c
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(a, c);
+        graph.add_edge_kind(b, c, EdgeKind::Related);
+        graph.add_edge_kind(d, c, EdgeKind::ParentChild);
+
+        assert_eq!(blockers(&graph, c), vec![a]);
+
+        // Closing only the real blocker makes c actionable
+        let closed_a = vec![true, false, false, false];
+        assert!(is_actionable(&graph, c, &closed_a));
+        assert_eq!(open_blocker_count(&graph, c, &[false; 4]), 1);
+
+        // Opting parent-child into the blocking view brings d back
+        graph
+            .set_blocking_kinds_checked(EdgeKind::Blocks.bit() | EdgeKind::ParentChild.bit())
+            .unwrap();
+        assert!(!is_actionable(&graph, c, &closed_a));
+        assert_eq!(open_blockers(&graph, c, &closed_a), vec![d]);
+        assert_eq!(reachable_to(&graph, c).len(), 3);
+    }
+
     #[test]
     fn test_open_blockers() {
         // a -> c, b -> c
@@ -384,6 +628,62 @@ mod tests {
         assert_eq!(from_c, vec![c]);
     }
 
+    #[test]
+    fn test_shortest_path() {
+        // 0 -> 1 -> 2 -> 3 and shortcut 0 -> 2
+        let graph = test_util::graph(5, &[(0, 1), (1, 2), (2, 3), (0, 2)]);
+        assert_eq!(shortest_path(&graph, 0, 3), Some(vec![0, 2, 3]));
+        assert_eq!(shortest_path(&graph, 1, 1), Some(vec![1]));
+        assert_eq!(shortest_path(&graph, 3, 0), None);
+        assert_eq!(shortest_path(&graph, 0, 4), None);
+        assert_eq!(shortest_path(&graph, 0, 99), None);
+    }
+
+    #[test]
+    fn test_all_paths() {
+        // Diamond 0 -> {1, 2} -> 3 plus 0 -> 3, a dead end 1 -> 4 and a cycle 3 -> 0
+        let graph = test_util::graph(5, &[(0, 1), (0, 2), (1, 3), (2, 3), (0, 3), (1, 4), (3, 0)]);
+        let result = all_paths(&graph, 0, 3, 10);
+        let paths: Vec<Vec<usize>> = result.paths.iter().map(|p| p.nodes.clone()).collect();
+        assert_eq!(paths, vec![vec![0, 1, 3], vec![0, 2, 3], vec![0, 3]]);
+        assert!(!result.truncated);
+        assert_eq!(result.paths[0].ids, vec!["n0", "n1", "n3"]);
+
+        let limited = all_paths(&graph, 0, 3, 2);
+        assert_eq!(limited.paths.len(), 2);
+        assert!(limited.truncated);
+
+        assert!(all_paths(&graph, 4, 0, 10).paths.is_empty());
+        assert_eq!(try_all_paths(&graph, 0, 3, MAX_PATHS + 1).unwrap_err().code(), "LIMIT_EXCEEDED");
+    }
+
+    #[test]
+    fn test_explain_blocked() {
+        // 0 -> 1 -> 3, 2 -> 3, 4 -> 2 with 4 closed, 5 <-> 6 -> 3
+        let graph = test_util::graph(7, &[(0, 1), (1, 3), (2, 3), (4, 2), (5, 6), (6, 5), (6, 3)]);
+        let mut closed = vec![false; 7];
+        closed[4] = true;
+
+        let explanation = explain_blocked(&graph, 3, &closed);
+        assert!(explanation.blocked);
+        let chains: Vec<Vec<usize>> = explanation.chains.iter().map(|c| c.nodes.clone()).collect();
+        // 5 and 6 block each other, so neither is a root
+        assert_eq!(chains, vec![vec![0, 1, 3], vec![2, 3]]);
+        assert_eq!(explanation.chains[1].ids, vec!["n2", "n3"]);
+
+        // Closing 0 makes 1 the root of the first chain
+        closed[0] = true;
+        let chains: Vec<Vec<usize>> = explain_blocked(&graph, 3, &closed)
+            .chains
+            .into_iter()
+            .map(|c| c.nodes)
+            .collect();
+        assert_eq!(chains, vec![vec![1, 3], vec![2, 3]]);
+
+        let free = explain_blocked(&graph, 0, &closed);
+        assert!(!free.blocked && free.chains.is_empty());
+    }
+
     #[test]
     fn test_cycle() {
         // a -> b -> c -> a
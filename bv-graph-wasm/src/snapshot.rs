@@ -0,0 +1,622 @@
+//! Versioned binary graph snapshot.
+//!
+//! Layout (integers little-endian, `varint` = unsigned LEB128, `zigzag` =
+//! signed LEB128 via zigzag mapping):
+//!
+//! ```text
+//! magic "BVGS" | version u16 | flags u16 | blocking_kinds u8
+//! strings:    varint count, then (varint len, utf-8 bytes) per string
+//! nodes:      varint slot count, then varint string id per slot
+//! [removed]:  varint count, then ascending slot indices as varint deltas
+//! adjacency:  per slot: varint degree, then zigzag target deltas
+//!             (the first delta is relative to the slot itself)
+//! [kinds]:    one kind mask byte per edge, in adjacency order
+//! [attrs]:    per slot: status u8, zigzag priority, varint estimate+1,
+//!             varint type+1, varint assignee+1, varint label count and
+//!             label string ids, u8 has_due [f64 due epoch ms]
+//! checksum:   u32 CRC-32 (IEEE) of every preceding byte
+//! ```
+//!
+//! Node IDs, issue types, assignees and labels share one interned string
+//! table. Sections in brackets are present only when their flag is set; a
+//! plain blocking graph without attributes carries neither. The legacy
+//! `toJson` format is still readable and is detected by `detectSnapshotFormat`.
+
+use crate::attributes::{IssueStatus, DEFAULT_PRIORITY};
+use crate::error::{GraphError, GraphResult};
+use crate::graph::{DiGraph, ALL_KINDS, BLOCKING_KINDS};
+use std::collections::HashMap;
+use wasm_bindgen::prelude::*;
+
+/// Leading bytes of every binary snapshot.
+pub const SNAPSHOT_MAGIC: [u8; 4] = *b"BVGS";
+
+/// Version written by `encode_snapshot`; readers accept this and older.
+pub const SNAPSHOT_VERSION: u16 = 1;
+
+const FLAG_REMOVED: u16 = 1 << 0;
+const FLAG_EDGE_KINDS: u16 = 1 << 1;
+const FLAG_ATTRIBUTES: u16 = 1 << 2;
+const KNOWN_FLAGS: u16 = FLAG_REMOVED | FLAG_EDGE_KINDS | FLAG_ATTRIBUTES;
+
+/// magic + version + flags + blocking kinds
+const HEADER_LEN: usize = 9;
+const CHECKSUM_LEN: usize = 4;
+
+/// Encoding of a serialized graph.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SnapshotFormat {
+    /// Binary snapshot (`toBinary`)
+    Binary = 0,
+    /// Legacy JSON snapshot (`toJson`)
+    LegacyJson = 1,
+    /// Neither format
+    Unknown = 2,
+}
+
+/// Identify the format of serialized graph bytes.
+#[wasm_bindgen(js_name = detectSnapshotFormat)]
+pub fn detect_snapshot_format(bytes: &[u8]) -> SnapshotFormat {
+    if bytes.starts_with(&SNAPSHOT_MAGIC) {
+        return SnapshotFormat::Binary;
+    }
+    match bytes.iter().find(|b| !b.is_ascii_whitespace()) {
+        Some(b'{') => SnapshotFormat::LegacyJson,
+        _ => SnapshotFormat::Unknown,
+    }
+}
+
+/// Version of a binary snapshot, or `None` if `bytes` is not one.
+#[wasm_bindgen(js_name = snapshotVersion)]
+pub fn snapshot_version(bytes: &[u8]) -> Option<u16> {
+    if detect_snapshot_format(bytes) != SnapshotFormat::Binary || bytes.len() < 6 {
+        return None;
+    }
+    Some(u16::from_le_bytes([bytes[4], bytes[5]]))
+}
+
+/// Serialize a graph, including removed slots, edge kinds and attributes.
+pub fn encode_snapshot(graph: &DiGraph) -> Vec<u8> {
+    let n = graph.len();
+    let attrs = graph.attrs();
+
+    let mut strings = Interner::default();
+    let node_ids: Vec<u32> = (0..n).map(|v| strings.intern(graph.slot_id(v))).collect();
+
+    let removed: Vec<usize> = (0..n).filter(|&v| !graph.is_live(v)).collect();
+    let has_kinds = (0..n).any(|v| graph.successor_kinds(v).iter().any(|&k| k != BLOCKING_KINDS));
+    let has_attrs = (0..n).any(|v| !is_default_row(graph, v));
+
+    let mut flags = 0;
+    if !removed.is_empty() {
+        flags |= FLAG_REMOVED;
+    }
+    if has_kinds {
+        flags |= FLAG_EDGE_KINDS;
+    }
+    if has_attrs {
+        flags |= FLAG_ATTRIBUTES;
+    }
+
+    // Intern attribute strings before the table is written
+    let mut rows = Vec::new();
+    if has_attrs {
+        rows = (0..n)
+            .map(|v| AttrRow {
+                issue_type: attrs.issue_type(v).map(|s| strings.intern(s)),
+                assignee: attrs.assignee(v).map(|s| strings.intern(s)),
+                labels: attrs.labels(v).into_iter().map(|s| strings.intern(s)).collect(),
+            })
+            .collect();
+    }
+
+    let mut w = Writer::default();
+    w.bytes(&SNAPSHOT_MAGIC);
+    w.bytes(&SNAPSHOT_VERSION.to_le_bytes());
+    w.bytes(&flags.to_le_bytes());
+    w.u8(graph.blocking_kinds());
+
+    w.varint(strings.strings.len() as u64);
+    for s in &strings.strings {
+        w.varint(s.len() as u64);
+        w.bytes(s.as_bytes());
+    }
+
+    w.varint(n as u64);
+    for &id in &node_ids {
+        w.varint(id as u64);
+    }
+
+    if !removed.is_empty() {
+        w.varint(removed.len() as u64);
+        let mut prev = 0;
+        for &v in &removed {
+            w.varint((v - prev) as u64);
+            prev = v;
+        }
+    }
+
+    for v in 0..n {
+        let targets = graph.successors_slice(v);
+        w.varint(targets.len() as u64);
+        let mut prev = v as i64;
+        for &t in targets {
+            w.zigzag(t as i64 - prev);
+            prev = t as i64;
+        }
+    }
+
+    if has_kinds {
+        for v in 0..n {
+            w.bytes(graph.successor_kinds(v));
+        }
+    }
+
+    if has_attrs {
+        for (v, row) in rows.iter().enumerate() {
+            w.u8(attrs.status(v).unwrap_or(IssueStatus::Open) as u8);
+            w.zigzag(attrs.priority(v).unwrap_or(DEFAULT_PRIORITY) as i64);
+            w.varint(attrs.estimated_minutes(v).map_or(0, |m| m as u64 + 1));
+            w.varint(row.issue_type.map_or(0, |id| id as u64 + 1));
+            w.varint(row.assignee.map_or(0, |id| id as u64 + 1));
+            w.varint(row.labels.len() as u64);
+            for &id in &row.labels {
+                w.varint(id as u64);
+            }
+            match attrs.due_date(v) {
+                Some(ms) => {
+                    w.u8(1);
+                    w.bytes(&ms.to_le_bytes());
+                }
+                None => w.u8(0),
+            }
+        }
+    }
+
+    let checksum = crc32(&w.buf);
+    w.bytes(&checksum.to_le_bytes());
+    w.buf
+}
+
+/// Parse a binary snapshot, verifying its checksum and every index.
+pub fn decode_snapshot(bytes: &[u8]) -> GraphResult<DiGraph> {
+    if detect_snapshot_format(bytes) != SnapshotFormat::Binary {
+        return Err(invalid("missing BVGS magic"));
+    }
+    if bytes.len() < HEADER_LEN + CHECKSUM_LEN {
+        return Err(invalid("truncated header"));
+    }
+    let (body, tail) = bytes.split_at(bytes.len() - CHECKSUM_LEN);
+    let expected = u32::from_le_bytes([tail[0], tail[1], tail[2], tail[3]]);
+    if crc32(body) != expected {
+        return Err(invalid("checksum mismatch"));
+    }
+
+    let mut r = Reader::new(&body[SNAPSHOT_MAGIC.len()..]);
+    let version = r.u16()?;
+    if version == 0 || version > SNAPSHOT_VERSION {
+        return Err(invalid(&format!(
+            "unsupported version {} (this build reads up to {})",
+            version, SNAPSHOT_VERSION
+        )));
+    }
+    let flags = r.u16()?;
+    if flags & !KNOWN_FLAGS != 0 {
+        return Err(invalid(&format!("unknown flags {:#06x}", flags)));
+    }
+    let blocking_kinds = r.u8()?;
+
+    let string_count = r.len()?;
+    let mut strings = Vec::with_capacity(string_count.min(body.len()));
+    for _ in 0..string_count {
+        let len = r.len()?;
+        let raw = r.take(len)?;
+        let s = std::str::from_utf8(raw).map_err(|_| invalid("string is not valid UTF-8"))?;
+        strings.push(s);
+    }
+    let string = |id: u64| -> GraphResult<&str> {
+        usize::try_from(id)
+            .ok()
+            .and_then(|i| strings.get(i).copied())
+            .ok_or_else(|| invalid(&format!("string id {} out of range", id)))
+    };
+
+    let n = r.len()?;
+    let mut ids = Vec::with_capacity(n.min(body.len()));
+    for _ in 0..n {
+        ids.push(string(r.varint()?)?);
+    }
+
+    let mut removed = vec![false; n];
+    if flags & FLAG_REMOVED != 0 {
+        let count = r.len()?;
+        let mut v = 0usize;
+        for i in 0..count {
+            let delta = r.len()?;
+            v = v.checked_add(delta).filter(|&v| v < n && (i == 0 || delta > 0)).ok_or_else(|| {
+                invalid("removed slot list is not ascending or out of range")
+            })?;
+            removed[v] = true;
+        }
+    }
+
+    let mut graph = DiGraph::with_capacity(n, 0);
+    for (v, id) in ids.iter().enumerate() {
+        let idx = graph.push_slot(id);
+        if removed[v] {
+            graph.remove_node(idx);
+        }
+    }
+
+    let mut edges = Vec::new();
+    for v in 0..n {
+        let degree = r.len()?;
+        let mut prev = v as i64;
+        for _ in 0..degree {
+            let t = prev
+                .checked_add(r.zigzag()?)
+                .filter(|&t| t >= 0 && (t as usize) < n && !removed[t as usize])
+                .ok_or_else(|| invalid(&format!("edge from {} has an invalid target", v)))?;
+            if removed[v] {
+                return Err(invalid(&format!("removed slot {} has edges", v)));
+            }
+            edges.push((v, t as usize));
+            prev = t;
+        }
+    }
+
+    let kinds = if flags & FLAG_EDGE_KINDS != 0 {
+        let kinds = r.take(edges.len())?;
+        if let Some(&bad) = kinds.iter().find(|&&k| k == 0 || k & !ALL_KINDS != 0) {
+            return Err(invalid(&format!("invalid edge kind mask {:#x}", bad)));
+        }
+        kinds.to_vec()
+    } else {
+        vec![BLOCKING_KINDS; edges.len()]
+    };
+    for (&(from, to), &mask) in edges.iter().zip(&kinds) {
+        graph.add_edge_mask(from, to, mask);
+    }
+    graph.set_blocking_kinds_checked(blocking_kinds)?;
+
+    if flags & FLAG_ATTRIBUTES != 0 {
+        for v in 0..n {
+            let status = r.u8()?;
+            let status = IssueStatus::from_u8(status)
+                .ok_or_else(|| invalid(&format!("invalid status {}", status)))?;
+            let priority = i32::try_from(r.zigzag()?).map_err(|_| invalid("priority out of range"))?;
+            let estimate = match r.varint()? {
+                0 => None,
+                m => Some(u32::try_from(m - 1).map_err(|_| invalid("estimate out of range"))?),
+            };
+            let issue_type = match r.varint()? {
+                0 => None,
+                id => Some(string(id - 1)?),
+            };
+            let assignee = match r.varint()? {
+                0 => None,
+                id => Some(string(id - 1)?),
+            };
+            let label_count = r.len()?;
+            let mut labels = Vec::with_capacity(label_count.min(strings.len()));
+            for _ in 0..label_count {
+                labels.push(string(r.varint()?)?);
+            }
+            let due = match r.u8()? {
+                0 => None,
+                _ => Some(f64::from_le_bytes(r.array()?)),
+            };
+
+            let attrs = graph.attrs_mut();
+            attrs.set_status(v, status);
+            attrs.set_priority(v, priority);
+            attrs.set_estimated_minutes(v, estimate);
+            attrs.set_issue_type(v, issue_type);
+            attrs.set_assignee(v, assignee);
+            attrs.set_labels(v, &labels);
+            attrs.set_due_date(v, due);
+        }
+    }
+
+    if !r.is_empty() {
+        return Err(invalid("trailing bytes before checksum"));
+    }
+    Ok(graph)
+}
+
+/// Parse either snapshot format, so legacy JSON exports can be migrated.
+pub fn decode_any_snapshot(bytes: &[u8]) -> GraphResult<DiGraph> {
+    match detect_snapshot_format(bytes) {
+        SnapshotFormat::Binary => decode_snapshot(bytes),
+        SnapshotFormat::LegacyJson => {
+            let json = std::str::from_utf8(bytes).map_err(|_| invalid("JSON is not valid UTF-8"))?;
+            DiGraph::from_snapshot_json(json)
+        }
+        SnapshotFormat::Unknown => Err(invalid("unrecognized snapshot format")),
+    }
+}
+
+fn invalid(reason: &str) -> GraphError {
+    GraphError::InvalidSnapshot(reason.to_string())
+}
+
+/// True if node `v` carries only default attributes.
+fn is_default_row(graph: &DiGraph, v: usize) -> bool {
+    let attrs = graph.attrs();
+    let expected_status = if graph.is_live(v) {
+        IssueStatus::Open
+    } else {
+        IssueStatus::Tombstone
+    };
+    attrs.status(v).is_none_or(|s| s == expected_status)
+        && attrs.priority(v).is_none_or(|p| p == DEFAULT_PRIORITY)
+        && attrs.estimated_minutes(v).is_none()
+        && attrs.issue_type(v).is_none()
+        && attrs.assignee(v).is_none()
+        && attrs.labels(v).is_empty()
+        && attrs.due_date(v).is_none()
+}
+
+/// Interned string ids of one node's attributes.
+struct AttrRow {
+    issue_type: Option<u32>,
+    assignee: Option<u32>,
+    labels: Vec<u32>,
+}
+
+/// Snapshot string table under construction.
+#[derive(Default)]
+struct Interner<'a> {
+    strings: Vec<&'a str>,
+    index: HashMap<&'a str, u32>,
+}
+
+impl<'a> Interner<'a> {
+    fn intern(&mut self, s: &'a str) -> u32 {
+        *self.index.entry(s).or_insert_with(|| {
+            self.strings.push(s);
+            (self.strings.len() - 1) as u32
+        })
+    }
+}
+
+#[derive(Default)]
+struct Writer {
+    buf: Vec<u8>,
+}
+
+impl Writer {
+    fn u8(&mut self, b: u8) {
+        self.buf.push(b);
+    }
+
+    fn bytes(&mut self, b: &[u8]) {
+        self.buf.extend_from_slice(b);
+    }
+
+    fn varint(&mut self, mut v: u64) {
+        while v >= 0x80 {
+            self.buf.push((v as u8) | 0x80);
+            v >>= 7;
+        }
+        self.buf.push(v as u8);
+    }
+
+    fn zigzag(&mut self, v: i64) {
+        self.varint(((v << 1) ^ (v >> 63)) as u64);
+    }
+}
+
+struct Reader<'a> {
+    buf: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn new(buf: &'a [u8]) -> Self {
+        Reader { buf, pos: 0 }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.pos == self.buf.len()
+    }
+
+    fn take(&mut self, len: usize) -> GraphResult<&'a [u8]> {
+        let end = self
+            .pos
+            .checked_add(len)
+            .filter(|&end| end <= self.buf.len())
+            .ok_or_else(|| invalid("unexpected end of data"))?;
+        let bytes = &self.buf[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn array<const N: usize>(&mut self) -> GraphResult<[u8; N]> {
+        let mut out = [0u8; N];
+        out.copy_from_slice(self.take(N)?);
+        Ok(out)
+    }
+
+    fn u8(&mut self) -> GraphResult<u8> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> GraphResult<u16> {
+        Ok(u16::from_le_bytes(self.array()?))
+    }
+
+    fn varint(&mut self) -> GraphResult<u64> {
+        let mut value = 0u64;
+        for shift in (0..64).step_by(7) {
+            let b = self.u8()?;
+            value |= u64::from(b & 0x7f) << shift;
+            if b & 0x80 == 0 {
+                return Ok(value);
+            }
+        }
+        Err(invalid("varint too long"))
+    }
+
+    fn zigzag(&mut self) -> GraphResult<i64> {
+        let v = self.varint()?;
+        Ok((v >> 1) as i64 ^ -((v & 1) as i64))
+    }
+
+    /// A count or length, bounded by the remaining input so corrupt data
+    /// cannot trigger huge allocations.
+    fn len(&mut self) -> GraphResult<usize> {
+        let v = self.varint()?;
+        usize::try_from(v)
+            .ok()
+            .filter(|&v| v <= self.buf.len())
+            .ok_or_else(|| invalid("length out of range"))
+    }
+}
+
+/// CRC-32 (IEEE 802.3, reflected polynomial 0xEDB88320).
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for &b in data {
+        crc ^= u32::from(b);
+        for _ in 0..8 {
+            crc = (crc >> 1) ^ (0xEDB8_8320 & (crc & 1).wrapping_neg());
+        }
+    }
+    !crc
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::EdgeKind;
+
+    fn sample() -> DiGraph {
+        let mut g = DiGraph::new();
+        let a = g.add_node("bv-1");
+        let b = g.add_node("bv-2");
+        let c = g.add_node("bv-3");
+        let d = g.add_node("bv-4");
+        g.add_edge(a, b);
+        g.add_edge(c, a);
+        g.add_edge_kind(a, d, EdgeKind::Related);
+        g.add_edge(b, d);
+        g
+    }
+
+    #[test]
+    fn test_crc32_known_value() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn test_varint_roundtrip() {
+        let mut w = Writer::default();
+        for v in [0u64, 1, 127, 128, 300, u32::MAX as u64, u64::MAX] {
+            w.varint(v);
+        }
+        for v in [0i64, -1, 1, -64, 64, i64::MIN, i64::MAX] {
+            w.zigzag(v);
+        }
+        let mut r = Reader::new(&w.buf);
+        for v in [0u64, 1, 127, 128, 300, u32::MAX as u64, u64::MAX] {
+            assert_eq!(r.varint().unwrap(), v);
+        }
+        for v in [0i64, -1, 1, -64, 64, i64::MIN, i64::MAX] {
+            assert_eq!(r.zigzag().unwrap(), v);
+        }
+        assert!(r.is_empty());
+    }
+
+    #[test]
+    fn test_roundtrip_structure_and_kinds() {
+        let mut g = sample();
+        g.set_blocking_kinds_checked(EdgeKind::Blocks.bit() | EdgeKind::Related.bit()).unwrap();
+        let bytes = encode_snapshot(&g);
+        assert_eq!(snapshot_version(&bytes), Some(SNAPSHOT_VERSION));
+
+        let back = decode_snapshot(&bytes).unwrap();
+        assert_eq!(back.node_count(), 4);
+        assert_eq!(back.edge_count(), 4);
+        assert_eq!(back.node_idx("bv-3"), Some(2));
+        for v in 0..4 {
+            assert_eq!(back.successors_slice(v), g.successors_slice(v));
+            assert_eq!(back.successor_kinds(v), g.successor_kinds(v));
+        }
+        assert_eq!(back.blocking_kinds(), g.blocking_kinds());
+    }
+
+    #[test]
+    fn test_roundtrip_attributes_and_removed() {
+        let mut g = sample();
+        g.set_status(1, IssueStatus::InProgress);
+        g.set_priority(1, 0);
+        let attrs = g.attrs_mut();
+        attrs.set_estimated_minutes(1, Some(90));
+        attrs.set_issue_type(1, Some("bug"));
+        attrs.set_assignee(0, Some("alice"));
+        attrs.set_labels(0, &["ui", "bug"]);
+        attrs.set_due_date(3, Some(1.7e12));
+        g.remove_node(2);
+
+        let back = decode_snapshot(&encode_snapshot(&g)).unwrap();
+        assert!(back.is_removed(2));
+        assert_eq!(back.node_idx("bv-3"), None);
+        assert_eq!(back.status(1), Some(IssueStatus::InProgress));
+        assert_eq!(back.status(2), Some(IssueStatus::Tombstone));
+        assert_eq!(back.priority(1), Some(0));
+        let attrs = back.attrs();
+        assert_eq!(attrs.estimated_minutes(1), Some(90));
+        assert_eq!(attrs.issue_type(1), Some("bug"));
+        assert_eq!(attrs.assignee(0), Some("alice"));
+        assert!(attrs.has_label(0, "ui") && attrs.has_label(0, "bug"));
+        assert_eq!(attrs.due_date(3), Some(1.7e12));
+    }
+
+    #[test]
+    fn test_plain_graph_omits_optional_sections() {
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        g.add_edge(a, b);
+        let bytes = encode_snapshot(&g);
+        assert_eq!(u16::from_le_bytes([bytes[6], bytes[7]]), 0);
+        assert!(bytes.len() < g.to_json().len());
+    }
+
+    #[test]
+    fn test_rejects_corruption() {
+        let mut bytes = encode_snapshot(&sample());
+        let last = bytes.len() - 5;
+        bytes[last] ^= 0xff;
+        let err = decode_snapshot(&bytes).err().unwrap();
+        assert_eq!(err.code(), "INVALID_SNAPSHOT");
+
+        let bytes = encode_snapshot(&sample());
+        assert!(decode_snapshot(&bytes[..bytes.len() - 1]).is_err());
+
+        // Future version with a valid checksum
+        let mut bytes = encode_snapshot(&sample());
+        bytes.truncate(bytes.len() - CHECKSUM_LEN);
+        bytes[4] = 99;
+        let crc = crc32(&bytes);
+        bytes.extend_from_slice(&crc.to_le_bytes());
+        let err = decode_snapshot(&bytes).err().unwrap();
+        assert!(err.to_string().contains("unsupported version 99"));
+    }
+
+    #[test]
+    fn test_detect_and_migrate_legacy_json() {
+        let g = sample();
+        let json = g.to_json();
+        assert_eq!(detect_snapshot_format(json.as_bytes()), SnapshotFormat::LegacyJson);
+        assert_eq!(detect_snapshot_format(b"garbage"), SnapshotFormat::Unknown);
+        assert_eq!(snapshot_version(json.as_bytes()), None);
+
+        let migrated = decode_any_snapshot(json.as_bytes()).unwrap();
+        let bytes = encode_snapshot(&migrated);
+        assert_eq!(detect_snapshot_format(&bytes), SnapshotFormat::Binary);
+        assert_eq!(decode_any_snapshot(&bytes).unwrap().edge_count(), 4);
+    }
+}
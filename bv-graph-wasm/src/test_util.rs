@@ -0,0 +1,15 @@
+//! Shared fixtures for unit tests.
+
+use crate::graph::DiGraph;
+
+/// Graph with nodes `n0..n{n-1}` and the given blocking edges.
+pub(crate) fn graph(n: usize, edges: &[(usize, usize)]) -> DiGraph {
+    let mut g = DiGraph::new();
+    for i in 0..n {
+        g.add_node(&format!("n{}", i));
+    }
+    for &(a, b) in edges {
+        g.add_edge(a, b);
+    }
+    g
+}
@@ -0,0 +1,206 @@
+//! Incrementally maintained topological order (Pearce–Kelly).
+//!
+//! Every live node carries a rank such that each blocking edge u -> v has
+//! `rank[u] < rank[v]`. Inserting an edge that already respects the ranks is
+//! O(1). Otherwise only the nodes whose ranks lie between the two endpoints
+//! are searched: a forward search from the target finds the nodes that must
+//! move after the source (hitting the source means a cycle) and a backward
+//! search from the source finds the nodes that must move before the target.
+//! The two sets then swap into the ranks they held between them.
+//!
+//! Removing edges or nodes never breaks the order, so only inserts and
+//! changes to the blocking kinds need attention. Searches read `DiGraph`'s
+//! adjacency lists directly, because the CSR view is discarded on every edit.
+
+use crate::graph::DiGraph;
+use std::collections::HashMap;
+
+/// Topological ranks of every slot; removed slots keep a stale rank.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct TopoOrder {
+    rank: Vec<usize>,
+    next_rank: usize,
+}
+
+impl TopoOrder {
+    /// Rank the blocking graph with Kahn's algorithm. `None` if it is cyclic.
+    pub(crate) fn build(graph: &DiGraph) -> Option<TopoOrder> {
+        let n = graph.len();
+        let mut indeg = vec![0usize; n];
+        for v in 0..n {
+            for w in graph.raw_blocking_successors(v) {
+                indeg[w] += 1;
+            }
+        }
+        let mut stack: Vec<usize> = (0..n).filter(|&v| indeg[v] == 0).collect();
+        let mut rank = vec![0; n];
+        let mut next_rank = 0;
+        while let Some(v) = stack.pop() {
+            rank[v] = next_rank;
+            next_rank += 1;
+            for w in graph.raw_blocking_successors(v) {
+                indeg[w] -= 1;
+                if indeg[w] == 0 {
+                    stack.push(w);
+                }
+            }
+        }
+        (next_rank == n).then_some(TopoOrder { rank, next_rank })
+    }
+
+    /// Give a new slot the highest rank.
+    pub(crate) fn push(&mut self) {
+        self.rank.push(self.next_rank);
+        self.next_rank += 1;
+    }
+
+    /// Rank of a slot.
+    pub(crate) fn rank(&self, node: usize) -> usize {
+        self.rank[node]
+    }
+
+    /// Update the ranks for a new blocking edge from -> to, which must not be
+    /// in the graph yet. On a cycle the ranks are left untouched and the
+    /// existing path to -> ... -> from is returned.
+    pub(crate) fn insert(&mut self, graph: &DiGraph, from: usize, to: usize) -> Result<(), Vec<usize>> {
+        if from == to {
+            return Err(vec![from]);
+        }
+        let (lower, upper) = (self.rank[to], self.rank[from]);
+        if lower > upper {
+            return Ok(());
+        }
+
+        let forward = match search(graph, to, from, |v| self.rank[v] <= upper, true) {
+            Search::Found(path) => return Err(path),
+            Search::Visited(nodes) => nodes,
+        };
+        let backward = match search(graph, from, to, |v| self.rank[v] >= lower, false) {
+            Search::Found(_) => unreachable!("from cannot reach to without a cycle"),
+            Search::Visited(nodes) => nodes,
+        };
+
+        // Nodes that must precede `to` take the lowest freed ranks, in their
+        // current relative order, followed by the nodes reachable from `to`.
+        let mut moved: Vec<usize> = backward;
+        moved.sort_unstable_by_key(|&v| self.rank[v]);
+        let mut after = forward;
+        after.sort_unstable_by_key(|&v| self.rank[v]);
+        moved.extend(after);
+
+        let mut ranks: Vec<usize> = moved.iter().map(|&v| self.rank[v]).collect();
+        ranks.sort_unstable();
+        for (v, r) in moved.into_iter().zip(ranks) {
+            self.rank[v] = r;
+        }
+        Ok(())
+    }
+}
+
+enum Search {
+    /// Path from the start to the goal
+    Found(Vec<usize>),
+    /// Every node reached inside the window, including the start
+    Visited(Vec<usize>),
+}
+
+/// DFS over blocking edges (forward or backward) from `start`, staying on
+/// nodes accepted by `within`.
+fn search(graph: &DiGraph, start: usize, goal: usize, within: impl Fn(usize) -> bool, forward: bool) -> Search {
+    let mut parent: HashMap<usize, usize> = HashMap::from([(start, start)]);
+    let mut stack = vec![start];
+    while let Some(v) = stack.pop() {
+        let next: Vec<usize> = if forward {
+            graph.raw_blocking_successors(v).collect()
+        } else {
+            graph.raw_blocking_predecessors(v).collect()
+        };
+        for w in next {
+            if parent.contains_key(&w) || !within(w) {
+                continue;
+            }
+            parent.insert(w, v);
+            if w == goal {
+                let mut path = vec![w];
+                let mut x = w;
+                while x != start {
+                    x = parent[&x];
+                    path.push(x);
+                }
+                path.reverse();
+                return Search::Found(path);
+            }
+            stack.push(w);
+        }
+    }
+    Search::Visited(parent.into_keys().collect())
+}
+
+/// Existing blocking path to -> ... -> from, i.e. the cycle that the edge
+/// from -> to would close. Uses `order` to prune the search when available.
+pub(crate) fn cycle_path(graph: &DiGraph, order: Option<&TopoOrder>, from: usize, to: usize) -> Option<Vec<usize>> {
+    if from == to {
+        return Some(vec![from]);
+    }
+    let upper = match order {
+        Some(order) if order.rank(to) > order.rank(from) => return None,
+        Some(order) => order.rank(from),
+        None => usize::MAX,
+    };
+    let within = |v: usize| order.is_none_or(|o| o.rank(v) <= upper);
+    match search(graph, to, from, within, true) {
+        Search::Found(path) => Some(path),
+        Search::Visited(_) => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_valid(graph: &DiGraph, order: &TopoOrder) {
+        for v in graph.live_nodes() {
+            for w in graph.raw_blocking_successors(v) {
+                assert!(order.rank(v) < order.rank(w), "{} -> {} out of order", v, w);
+            }
+        }
+    }
+
+    #[test]
+    fn test_insert_reorders_window() {
+        // Build ranks for 0..5 with no edges, then add edges against the order
+        let mut g = DiGraph::new();
+        for i in 0..5 {
+            g.add_node(&format!("n{}", i));
+        }
+        let mut order = TopoOrder::build(&g).unwrap();
+        for (from, to) in [(4, 3), (3, 1), (1, 0), (4, 2), (2, 0)] {
+            order.insert(&g, from, to).unwrap();
+            g.add_edge(from, to);
+            assert_valid(&g, &order);
+        }
+        // 0 -> 4 would close a cycle; ranks stay untouched
+        let before = order.clone();
+        let path = order.insert(&g, 0, 4).unwrap_err();
+        assert_eq!((path[0], path[path.len() - 1]), (4, 0));
+        assert_eq!(order.rank, before.rank);
+    }
+
+    #[test]
+    fn test_cycle_path_with_and_without_order() {
+        let mut g = DiGraph::new();
+        for i in 0..4 {
+            g.add_node(&format!("n{}", i));
+        }
+        g.add_edge(1, 2);
+        g.add_edge(2, 3);
+        let order = TopoOrder::build(&g).unwrap();
+        assert_eq!(cycle_path(&g, Some(&order), 3, 1), Some(vec![1, 2, 3]));
+        assert_eq!(cycle_path(&g, None, 3, 1), Some(vec![1, 2, 3]));
+        assert_eq!(cycle_path(&g, Some(&order), 1, 3), None);
+        assert_eq!(cycle_path(&g, Some(&order), 0, 0), Some(vec![0]));
+
+        g.add_edge(3, 1);
+        assert!(TopoOrder::build(&g).is_none());
+    }
+}
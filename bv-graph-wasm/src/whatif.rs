@@ -2,6 +2,8 @@
 //!
 //! What-If analysis answers "If I close issue X, what happens?"
 //! It computes direct unblocks, transitive cascades, and impact metrics.
+//! Only blocking edges propagate unblocks. Execution waves run the same
+//! cascade from every actionable issue at once, one layer at a time.
 
 use crate::graph::DiGraph;
 use crate::reachability::{actionable_nodes, is_actionable};
@@ -47,7 +49,7 @@ impl WhatIfResult {
 /// WhatIfResult with direct unblocks, transitive cascade, and impact metrics.
 pub fn what_if_close(graph: &DiGraph, node: usize, closed_set: &[bool]) -> WhatIfResult {
     let n = graph.len();
-    if node >= n || closed_set.get(node).copied().unwrap_or(false) {
+    if !graph.is_live(node) || closed_set.get(node).copied().unwrap_or(false) {
         // Node doesn't exist or is already closed
         return WhatIfResult::empty();
     }
@@ -61,7 +63,7 @@ pub fn what_if_close(graph: &DiGraph, node: usize, closed_set: &[bool]) -> WhatI
     // These are successors of node that had all other blockers already closed
     let mut direct_unblocks = Vec::new();
 
-    for &successor in graph.successors_slice(node) {
+    for successor in graph.blocking_successors(node) {
         if new_closed[successor] {
             continue;
         }
@@ -125,16 +127,15 @@ fn count_cascade(graph: &DiGraph, roots: &[usize], initial_closed: &[bool]) -> V
         closed[v] = true;
 
         // Check successors
-        for &w in graph.successors_slice(v) {
+        for w in graph.blocking_successors(v) {
             if visited[w] || closed[w] {
                 continue;
             }
 
             // Check if all predecessors of w are now resolved
             let all_resolved = graph
-                .predecessors_slice(w)
-                .iter()
-                .all(|&p| closed[p] || visited[p]);
+                .blocking_predecessors(w)
+                .all(|p| closed[p] || visited[p]);
 
             if all_resolved {
                 visited[w] = true;
@@ -164,7 +165,8 @@ pub struct TopWhatIfEntry {
 /// * `limit` - Maximum number of results to return
 ///
 /// # Returns
-/// Vector of (node, WhatIfResult) sorted by transitive_unblocks descending.
+/// Vector of (node, WhatIfResult) sorted by transitive_unblocks descending,
+/// ties broken by node priority (more urgent first).
 pub fn top_what_if(graph: &DiGraph, closed_set: &[bool], limit: usize) -> Vec<TopWhatIfEntry> {
     let n = graph.len();
     if n == 0 {
@@ -188,6 +190,11 @@ pub fn top_what_if(graph: &DiGraph, closed_set: &[bool], limit: usize) -> Vec<To
         b.result
             .transitive_unblocks
             .cmp(&a.result.transitive_unblocks)
+            .then_with(|| {
+                graph
+                    .priority_or_default(a.node)
+                    .cmp(&graph.priority_or_default(b.node))
+            })
     });
 
     results.truncate(limit);
@@ -207,7 +214,8 @@ pub fn all_what_if(graph: &DiGraph, closed_set: &[bool], limit: usize) -> Vec<To
     let mut closed = closed_set.to_vec();
     closed.resize(n, false);
 
-    let mut results: Vec<TopWhatIfEntry> = (0..n)
+    let mut results: Vec<TopWhatIfEntry> = graph
+        .live_nodes()
         .filter(|&i| !closed[i])
         .map(|node| {
             let result = what_if_close(graph, node, &closed);
@@ -220,6 +228,11 @@ pub fn all_what_if(graph: &DiGraph, closed_set: &[bool], limit: usize) -> Vec<To
         b.result
             .transitive_unblocks
             .cmp(&a.result.transitive_unblocks)
+            .then_with(|| {
+                graph
+                    .priority_or_default(a.node)
+                    .cmp(&graph.priority_or_default(b.node))
+            })
     });
 
     results.truncate(limit);
@@ -259,10 +272,10 @@ pub fn what_if_close_batch(
     let mut seen = vec![false; n];
 
     for &node in nodes {
-        if node >= n {
+        if !graph.is_live(node) {
             continue;
         }
-        for &successor in graph.successors_slice(node) {
+        for successor in graph.blocking_successors(node) {
             if seen[successor] || new_closed[successor] {
                 continue;
             }
@@ -290,9 +303,91 @@ pub fn what_if_close_batch(
     }
 }
 
+/// One layer of the execution plan.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Wave {
+    pub index: usize,
+    /// Issues that become actionable in this wave, ascending
+    pub nodes: Vec<usize>,
+    pub size: usize,
+    /// Sum of `estimated_minutes` over this wave
+    pub effort_minutes: u64,
+    /// Sum of `estimated_minutes` over this wave and all earlier ones
+    pub cumulative_minutes: u64,
+    /// Issues in this wave without an estimate
+    pub unestimated: usize,
+}
+
+/// Open backlog split into execution waves.
+#[derive(Debug, Clone, Serialize)]
+pub struct WavesResult {
+    pub waves: Vec<Wave>,
+    /// Open issues no wave reaches: on a blocking cycle or behind one, ascending
+    pub unreachable: Vec<usize>,
+    pub open_nodes: usize,
+}
+
+/// Split the open issues into execution waves.
+///
+/// Wave 0 is `actionable_nodes`. Wave k + 1 holds the issues whose last open
+/// blocker is in wave k, i.e. what becomes actionable once every earlier
+/// wave is closed.
+pub fn execution_waves(graph: &DiGraph, closed_set: &[bool]) -> WavesResult {
+    let n = graph.len();
+    let open: Vec<bool> = (0..n)
+        .map(|v| graph.is_live(v) && !closed_set.get(v).copied().unwrap_or(false))
+        .collect();
+    let mut waiting: Vec<usize> = (0..n)
+        .map(|v| graph.blocking_predecessors(v).filter(|&u| open[u]).count())
+        .collect();
+
+    let attrs = graph.attrs();
+    let mut waves: Vec<Wave> = Vec::new();
+    let mut placed = vec![false; n];
+    let mut current = actionable_nodes(graph, closed_set);
+    let mut cumulative = 0;
+    while !current.is_empty() {
+        let mut next = Vec::new();
+        let mut effort = 0;
+        let mut unestimated = 0;
+        for &v in &current {
+            placed[v] = true;
+            match attrs.estimated_minutes(v) {
+                Some(m) => effort += u64::from(m),
+                None => unestimated += 1,
+            }
+            for w in graph.blocking_successors(v) {
+                if open[w] {
+                    waiting[w] -= 1;
+                    if waiting[w] == 0 {
+                        next.push(w);
+                    }
+                }
+            }
+        }
+        cumulative += effort;
+        next.sort_unstable();
+        waves.push(Wave {
+            index: waves.len(),
+            size: current.len(),
+            nodes: std::mem::replace(&mut current, next),
+            effort_minutes: effort,
+            cumulative_minutes: cumulative,
+            unestimated,
+        });
+    }
+
+    WavesResult {
+        waves,
+        unreachable: (0..n).filter(|&v| open[v] && !placed[v]).collect(),
+        open_nodes: open.iter().filter(|&&o| o).count(),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::graph::EdgeKind;
 
     #[test]
     fn test_what_if_empty() {
@@ -358,6 +453,26 @@ mod tests {
         assert_eq!(result.parallel_gain, 1); // 2 - 1 = 1
     }
 
+    #[test]
+    fn test_what_if_ignores_related() {
+        // a -blocks-> b -related-> c
+        // Closing a unblocks b, but c was never blocked by b
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        graph.add_edge(a, b);
+        graph.add_edge_kind(b, c, EdgeKind::Related);
+
+        let closed = vec![false, false, false];
+        let result = what_if_close(&graph, a, &closed);
+        assert_eq!(result.cascade_ids, vec![b]);
+
+        graph.set_blocking_kinds_checked(crate::graph::ALL_KINDS).unwrap();
+        let result = what_if_close(&graph, a, &closed);
+        assert_eq!(result.cascade_ids, vec![b, c]);
+    }
+
     #[test]
     fn test_what_if_partial_close() {
         // a -> c, b -> c
@@ -477,6 +592,23 @@ mod tests {
         assert_eq!(top[1].result.transitive_unblocks, 1);
     }
 
+    #[test]
+    fn test_top_what_if_priority_tiebreak() {
+        // a -> b, c -> d: equal impact, c is more urgent
+        let mut graph = DiGraph::new();
+        let a = graph.add_node("a");
+        let b = graph.add_node("b");
+        let c = graph.add_node("c");
+        let d = graph.add_node("d");
+        graph.add_edge(a, b);
+        graph.add_edge(c, d);
+        graph.set_priority(c, 0);
+
+        let top = top_what_if(&graph, &[false; 4], 10);
+        assert_eq!(top[0].node, c);
+        assert_eq!(top[1].node, a);
+    }
+
     #[test]
     fn test_top_what_if_limit() {
         let mut graph = DiGraph::new();
@@ -621,4 +753,35 @@ mod tests {
         assert_eq!(result.cascade_ids[1], c);
         assert_eq!(result.cascade_ids[2], d);
     }
+
+    #[test]
+    fn test_execution_waves() {
+        // a -> c, b -> c -> d; e is closed and blocked f; g <-> h -> i is stuck
+        let mut graph = DiGraph::new();
+        let ids: Vec<usize> = ["a", "b", "c", "d", "e", "f", "g", "h", "i"]
+            .iter()
+            .map(|id| graph.add_node(id))
+            .collect();
+        for (x, y) in [(0, 2), (1, 2), (2, 3), (4, 5), (6, 7), (7, 6), (7, 8)] {
+            graph.add_edge(ids[x], ids[y]);
+        }
+        graph.set_estimated_minutes(ids[0], 30);
+        graph.set_estimated_minutes(ids[1], 15);
+        graph.set_estimated_minutes(ids[3], 60);
+
+        let mut closed = vec![false; 9];
+        closed[4] = true;
+        let result = execution_waves(&graph, &closed);
+        let layers: Vec<Vec<usize>> = result.waves.iter().map(|w| w.nodes.clone()).collect();
+        assert_eq!(layers, vec![vec![0, 1, 5], vec![2], vec![3]]);
+        assert_eq!(layers[0], actionable_nodes(&graph, &closed));
+        let effort: Vec<(u64, u64, usize)> = result
+            .waves
+            .iter()
+            .map(|w| (w.effort_minutes, w.cumulative_minutes, w.unestimated))
+            .collect();
+        assert_eq!(effort, vec![(45, 45, 1), (0, 45, 1), (60, 105, 0)]);
+        assert_eq!(result.unreachable, vec![6, 7, 8]);
+        assert_eq!(result.open_nodes, 8);
+    }
 }
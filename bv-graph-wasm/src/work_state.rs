@@ -0,0 +1,194 @@
+//! Per-node work status held alongside a graph.
+//!
+//! Actionable-style queries historically took a `closed_set` byte array and
+//! rebuilt a `Vec<bool>` on every call. `WorkState` keeps the status of each
+//! node plus a maintained "resolved" mask (closed or tombstoned), so
+//! `close`/`reopen` are O(1) and queries borrow the mask without copying.
+//! A tombstoned issue is resolved: it never counts as an open blocker and
+//! is never offered as work, which a plain closed/open flag cannot express.
+
+use crate::attributes::IssueStatus;
+use crate::graph::DiGraph;
+use wasm_bindgen::prelude::*;
+
+/// Mutable status vector indexed by node.
+///
+/// Nodes beyond the stored length are treated as open, so a state created
+/// before nodes were added stays usable; updates grow it on demand.
+#[wasm_bindgen]
+#[derive(Debug, Clone, Default)]
+pub struct WorkState {
+    status: Vec<IssueStatus>,
+    /// resolved[v] == status[v].is_resolved()
+    resolved: Vec<bool>,
+    resolved_count: usize,
+}
+
+#[wasm_bindgen]
+impl WorkState {
+    /// Create a state with `len` open nodes.
+    #[wasm_bindgen(constructor)]
+    pub fn new(len: usize) -> WorkState {
+        WorkState {
+            status: vec![IssueStatus::Open; len],
+            resolved: vec![false; len],
+            resolved_count: 0,
+        }
+    }
+
+    /// Snapshot the statuses stored on a graph (removed slots are tombstones).
+    #[wasm_bindgen(js_name = fromGraph)]
+    pub fn from_graph(graph: &DiGraph) -> WorkState {
+        let mut state = WorkState::new(graph.len());
+        for v in 0..graph.len() {
+            let status = match graph.attrs().status(v) {
+                _ if !graph.is_live(v) => IssueStatus::Tombstone,
+                Some(status) => status,
+                None => IssueStatus::Open,
+            };
+            state.set_status(v, status);
+        }
+        state
+    }
+
+    /// Build from a legacy closed-set byte array (non-zero = closed).
+    #[wasm_bindgen(js_name = fromClosedSet)]
+    pub fn from_closed_set(closed_set: &[u8]) -> WorkState {
+        let mut state = WorkState::new(closed_set.len());
+        for (v, &b) in closed_set.iter().enumerate() {
+            if b != 0 {
+                state.close(v);
+            }
+        }
+        state
+    }
+
+    /// Number of stored node slots.
+    pub fn len(&self) -> usize {
+        self.status.len()
+    }
+
+    /// True if no slots are stored.
+    #[wasm_bindgen(js_name = isEmpty)]
+    pub fn is_empty(&self) -> bool {
+        self.status.is_empty()
+    }
+
+    /// Status of a node (open if never set).
+    pub fn status(&self, node: usize) -> IssueStatus {
+        self.status.get(node).copied().unwrap_or(IssueStatus::Open)
+    }
+
+    /// Set a node's status, growing the state if needed.
+    #[wasm_bindgen(js_name = setStatus)]
+    pub fn set_status(&mut self, node: usize, status: IssueStatus) {
+        if node >= self.status.len() {
+            self.status.resize(node + 1, IssueStatus::Open);
+            self.resolved.resize(node + 1, false);
+        }
+        let resolved = status.is_resolved();
+        match (self.resolved[node], resolved) {
+            (false, true) => self.resolved_count += 1,
+            (true, false) => self.resolved_count -= 1,
+            _ => {}
+        }
+        self.status[node] = status;
+        self.resolved[node] = resolved;
+    }
+
+    /// Mark a node closed.
+    pub fn close(&mut self, node: usize) {
+        self.set_status(node, IssueStatus::Closed);
+    }
+
+    /// Mark a node open again.
+    pub fn reopen(&mut self, node: usize) {
+        self.set_status(node, IssueStatus::Open);
+    }
+
+    /// True if the node is closed or tombstoned (no longer blocks anything).
+    #[wasm_bindgen(js_name = isResolved)]
+    pub fn is_resolved(&self, node: usize) -> bool {
+        self.resolved.get(node).copied().unwrap_or(false)
+    }
+
+    /// Number of closed or tombstoned nodes.
+    #[wasm_bindgen(js_name = resolvedCount)]
+    pub fn resolved_count(&self) -> usize {
+        self.resolved_count
+    }
+
+    /// All statuses as a Uint8Array of `IssueStatus` values.
+    pub fn statuses(&self) -> Vec<u8> {
+        self.status.iter().map(|&s| s as u8).collect()
+    }
+
+    /// Resolved mask as a legacy closed-set byte array.
+    #[wasm_bindgen(js_name = toClosedSet)]
+    pub fn to_closed_set(&self) -> Vec<u8> {
+        self.resolved.iter().map(|&r| u8::from(r)).collect()
+    }
+}
+
+impl WorkState {
+    /// Resolved mask borrowed by the closed-set algorithms.
+    pub fn resolved(&self) -> &[bool] {
+        &self.resolved
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::reachability::{actionable_nodes, open_blocker_count};
+
+    #[test]
+    fn test_close_reopen_counts() {
+        let mut state = WorkState::new(3);
+        state.close(1);
+        state.close(1);
+        assert_eq!(state.resolved_count(), 1);
+        state.set_status(2, IssueStatus::Tombstone);
+        assert_eq!(state.resolved_count(), 2);
+        state.reopen(1);
+        assert_eq!(state.resolved_count(), 1);
+        assert_eq!(state.to_closed_set(), vec![0, 0, 1]);
+
+        // Updates past the end grow the state
+        state.close(5);
+        assert_eq!(state.len(), 6);
+        assert_eq!(state.status(4), IssueStatus::Open);
+        assert!(state.is_resolved(5));
+    }
+
+    #[test]
+    fn test_tombstone_is_not_an_open_blocker() {
+        // a -> c, b -> c; a is tombstoned, b in progress
+        let mut g = DiGraph::new();
+        let a = g.add_node("a");
+        let b = g.add_node("b");
+        let c = g.add_node("c");
+        g.add_edge(a, c);
+        g.add_edge(b, c);
+        g.set_status(a, IssueStatus::Tombstone);
+        g.set_status(b, IssueStatus::InProgress);
+
+        let mut state = WorkState::from_graph(&g);
+        assert_eq!(open_blocker_count(&g, c, state.resolved()), 1);
+        assert_eq!(actionable_nodes(&g, state.resolved()), vec![b]);
+
+        state.close(b);
+        assert_eq!(actionable_nodes(&g, state.resolved()), vec![c]);
+    }
+
+    #[test]
+    fn test_from_graph_marks_removed_slots() {
+        let mut g = DiGraph::new();
+        g.add_node("a");
+        let b = g.add_node("b");
+        g.remove_node(b);
+        let state = WorkState::from_graph(&g);
+        assert_eq!(state.status(b), IssueStatus::Tombstone);
+        assert_eq!(WorkState::from_closed_set(&[0, 2]).resolved(), &[false, true]);
+    }
+}